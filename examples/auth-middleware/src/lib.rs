@@ -1,8 +1,8 @@
 use std::collections::btree_map::Entry::Vacant;
 
-use color_eyre::eyre::{self, Result, eyre};
+use color_eyre::eyre::Result;
 
-use axum::{Extension, response::{IntoResponse, Response}, routing::get};
+use axum::{Extension, response::IntoResponse, routing::get};
 use axum::middleware::from_fn;
 use groom::router::GroomRouterValid;
 use groom_macros::Controller;
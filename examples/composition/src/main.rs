@@ -7,7 +7,7 @@ use utoipa::OpenApi;
 #[Controller()]
 mod controller_a {
     use axum::response::IntoResponse;
-    use groom::{extract::GroomExtractor, response::Response};
+    use groom::response::Response;
     use groom_macros::{DTO, Response};
 
     #[DTO(response)]
@@ -33,7 +33,7 @@ mod controller_a {
 #[Controller()]
 mod controller_b {
     use axum::response::IntoResponse;
-    use groom::{extract::GroomExtractor, response::Response};
+    use groom::response::Response;
     use groom_macros::{DTO, Response};
 
     #[DTO(response)]
@@ -67,7 +67,7 @@ fn main() {
     let router_b = controller_b::into_router();
 
     let router_a_2 = router_a.clone();
-    let router_a_3 = router_a.clone();
+    let _router_a_3 = router_a.clone();
 
     let composed = GroomRouter::new()
         .merge(router_a)
@@ -57,7 +57,7 @@ pub fn make_axum_router() -> axum::Router {
     make_router().to_axum_router()
 }
 
-fn make_openapi(r: &GroomRouterValid) -> utoipa::openapi::OpenApi {
+pub fn make_openapi(r: &GroomRouterValid) -> utoipa::openapi::OpenApi {
     #[derive(OpenApi)]
     #[openapi(info(title = "My API", version = "0.1.0"))]
     struct ApiDoc;
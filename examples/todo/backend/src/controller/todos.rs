@@ -59,7 +59,7 @@ mod controller {
         match task_service.list_tasks(req.into()).await {
             Ok(l) => {
                 let tasks: Result<Vec<Task>, ()> = l.iter()
-                    .map(|t| Task::try_from(t))
+                    .map(Task::try_from)
                     .collect()
                 ;
 
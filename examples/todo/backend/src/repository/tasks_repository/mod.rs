@@ -141,7 +141,7 @@ impl Store {
             let by_id = a.id().cmp(&b.id());
             let ordering = match query.sort_by {
                 TaskSortField::Id     => by_id,
-                TaskSortField::Title  => a.title().cmp(&b.title()).then(by_id),
+                TaskSortField::Title  => a.title().cmp(b.title()).then(by_id),
                 TaskSortField::Status => a.status().cmp(&b.status()).then(by_id),
             };
 
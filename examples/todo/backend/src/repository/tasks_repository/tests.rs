@@ -99,7 +99,7 @@ async fn should_update_tasks() {
         Task::new("don't do this", Status::Cancelled),
         Task::new("already done that", Status::Done),
     ];
-    let new_statuses = vec![Status::Done, Status::Cancelled, Status::Pending, Status::Done];
+    let new_statuses = [Status::Done, Status::Cancelled, Status::Pending, Status::Done];
     let new_titles: Vec<String> =
         (0..tasks.len()).map(|v| format!("Task #{v}: do something.")).collect();
 
@@ -109,17 +109,17 @@ pub struct ListTasksRequest {
     pub order:   SortOrder,
 }
 
-impl Into<repository::GetTasksQuery> for ListTasksRequest {
-    fn into(self) -> repository::GetTasksQuery {
+impl From<ListTasksRequest> for repository::GetTasksQuery {
+    fn from(val: ListTasksRequest) -> Self {
         repository::GetTasksQuery {
-            title:   self.title,
-            status:  self.status,
-            sort_by: match self.sort_by {
+            title:   val.title,
+            status:  val.status,
+            sort_by: match val.sort_by {
                 TaskSortField::Id     => repository::TaskSortField::Id,
                 TaskSortField::Title  => repository::TaskSortField::Title,
                 TaskSortField::Status => repository::TaskSortField::Status,
             },
-            order: match self.order {
+            order: match val.order {
                 SortOrder::Ascending  => repository::SortOrder::Ascending,
                 SortOrder::Descending => repository::SortOrder::Descending,
             },
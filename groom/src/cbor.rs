@@ -0,0 +1,106 @@
+//! CBOR request and response bodies for `format(cbor)` (`cbor` cargo feature).
+//!
+//! [`Cbor`] works like `axum::Json`: it is both an extractor and a response.
+//! (De)serialization goes through `serde` and `ciborium`.
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request, rejection::BytesRejection},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// CBOR body extractor and response.
+///
+/// The extractor does not check `Content-Type` — `#[RequestBody(format(cbor))]` only calls it
+/// after the content type was negotiated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cbor<T>(pub T);
+
+/// Rejection of the [`Cbor`] extractor.
+#[derive(Debug, Error)]
+pub enum CborRejection {
+    #[error(transparent)]
+    BytesRejection(#[from] BytesRejection),
+
+    /// The body is not well-formed CBOR.
+    #[error("Failed to parse the CBOR body: {0}")]
+    Syntax(String),
+
+    /// The body is valid CBOR that does not fit the target type.
+    #[error("Failed to deserialize the CBOR body into the target type: {0}")]
+    Deserialize(String),
+}
+
+impl From<ciborium::de::Error<std::io::Error>> for CborRejection {
+    fn from(e: ciborium::de::Error<std::io::Error>) -> Self {
+        match e {
+            ciborium::de::Error::Semantic(..) => CborRejection::Deserialize(e.to_string()),
+            _ => CborRejection::Syntax(e.to_string()),
+        }
+    }
+}
+
+impl IntoResponse for CborRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            CborRejection::BytesRejection(r) => r.into_response(),
+            CborRejection::Syntax(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            CborRejection::Deserialize(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for Cbor<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = CborRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state).await?;
+
+        Ok(Cbor(ciborium::from_reader(bytes.as_ref())?))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Cbor<T> {
+    fn into_response(self) -> axum::response::Response {
+        let mut body = Vec::new();
+
+        match ciborium::into_writer(&self.0, &mut body) {
+            Ok(()) => ([(CONTENT_TYPE, "application/cbor")], body).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Person {
+        name: String,
+    }
+
+    #[test]
+    fn type_mismatch_is_a_deserialize_error() {
+        let mut body = Vec::new();
+        ciborium::into_writer(&42u8, &mut body).unwrap();
+
+        let e: CborRejection = ciborium::from_reader::<Person, _>(body.as_slice()).unwrap_err().into();
+        assert!(matches!(e, CborRejection::Deserialize(_)), "{e:?}");
+    }
+
+    #[test]
+    fn truncated_body_is_a_syntax_error() {
+        let e: CborRejection = ciborium::from_reader::<Person, _>([0xa1u8].as_slice()).unwrap_err().into();
+        assert!(matches!(e, CborRejection::Syntax(_)), "{e:?}");
+    }
+}
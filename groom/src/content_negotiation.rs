@@ -1,10 +1,36 @@
 use accept_header::Accept;
 use ::axum::http::header::HeaderMap;
-use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::header::{ACCEPT, ACCEPT_LANGUAGE, CONTENT_TYPE};
 use http::HeaderName;
 use mime::Mime;
+use std::sync::LazyLock;
 use thiserror::Error;
 
+/// `application/xml` — the `mime` crate only has a constant for `text/xml`.
+pub static APPLICATION_XML: LazyLock<Mime> = LazyLock::new(|| {
+    "application/xml".parse().expect("application/xml is a valid mime")
+});
+
+/// `application/cbor` — not provided by the `mime` crate.
+pub static APPLICATION_CBOR: LazyLock<Mime> = LazyLock::new(|| {
+    "application/cbor".parse().expect("application/cbor is a valid mime")
+});
+
+/// `application/x-ndjson` — not provided by the `mime` crate.
+pub static APPLICATION_NDJSON: LazyLock<Mime> = LazyLock::new(|| {
+    "application/x-ndjson".parse().expect("application/x-ndjson is a valid mime")
+});
+
+/// `application/ndjson`, the unprefixed spelling of [`APPLICATION_NDJSON`].
+pub static APPLICATION_NDJSON_UNPREFIXED: LazyLock<Mime> = LazyLock::new(|| {
+    "application/ndjson".parse().expect("application/ndjson is a valid mime")
+});
+
+/// `application/x-msgpack`, the legacy spelling of [`mime::APPLICATION_MSGPACK`].
+pub static APPLICATION_X_MSGPACK: LazyLock<Mime> = LazyLock::new(|| {
+    "application/x-msgpack".parse().expect("application/x-msgpack is a valid mime")
+});
+
 #[derive(Debug, Clone, Error)]
 pub enum HeaderParseError {
     #[error("Failed to parse header `{0}` as a valid header value.")]
@@ -27,6 +53,11 @@ pub fn parse_accept_header(headers: &HeaderMap) -> Result<Option<Accept>, Header
 pub enum BodyContentType {
     Json,
     FormUrlEncoded,
+    Multipart,
+    Xml,
+    Cbor,
+    MsgPack,
+    PlainText,
 }
 
 pub fn parse_content_type_header(headers: &HeaderMap) -> Result<Option<Mime>, HeaderParseError> {
@@ -47,6 +78,16 @@ pub fn get_body_content_type(mime: Option<Mime>) -> Option<BodyContentType> {
         Some(BodyContentType::FormUrlEncoded)
     } else if is_json(&mime) {
         Some(BodyContentType::Json)
+    } else if is_multipart_form_data(&mime) {
+        Some(BodyContentType::Multipart)
+    } else if is_xml(&mime) {
+        Some(BodyContentType::Xml)
+    } else if is_cbor(&mime) {
+        Some(BodyContentType::Cbor)
+    } else if is_msgpack(&mime) {
+        Some(BodyContentType::MsgPack)
+    } else if is_plain_text(&mime) {
+        Some(BodyContentType::PlainText)
     } else {
         None
     }
@@ -80,7 +121,9 @@ fn is_refused_weight(weight: Option<f32>) -> bool {
 /// others). `accept-header`'s `negotiate()` compares full `Mime`s including
 /// parameters, so a `text/plain; charset=utf-8` supported list would otherwise 406 a
 /// plain `Accept: text/plain`. The parser pre-sorts `accept.types` by q-weight, so
-/// iterating in order preserves priority. Media types (and `*/*`) with weight
+/// iterating in order preserves priority. A media type with a structured syntax suffix
+/// (`application/vnd.partner+xml`) falls back to the supported mime named after its
+/// suffix (`application/xml`) when there is no exact match. Media types (and `*/*`) with weight
 /// `Some(w)` where `w <= 0.0` are skipped as explicit refusals. When only an
 /// acceptable `*/*` remains (stored in `accept.wildcard`), this uses `default`
 /// when that mime is in `supported` (type_/subtype_ match); otherwise it uses
@@ -100,6 +143,13 @@ pub fn negotiate_parameter_insensitive<'a>(
         }) {
             return Some(supported);
         }
+        if let Some(suffix) = media_type.mime.suffix()
+            && let Some(supported) = supported.iter().find(|mime| {
+                mime.type_() == media_type.mime.type_() && mime.subtype() == suffix.as_str()
+            })
+        {
+            return Some(supported);
+        }
     }
     if accept
         .wildcard
@@ -117,11 +167,114 @@ pub fn negotiate_parameter_insensitive<'a>(
     None
 }
 
+/// Language range of an `Accept-Language` header (`de-CH`, `en`, `*`) with its weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    pub range: String,
+    pub weight: f32,
+}
+
+/// Parses `Accept-Language` into language ranges sorted by weight, highest first; ranges of the
+/// same weight keep their order. Malformed entries are ignored instead of failing the request,
+/// since a response in the default language is still useful.
+pub fn parse_accept_language_header(headers: &HeaderMap) -> Vec<LanguageRange> {
+    let is_range = |range: &str| range == "*" || (
+        !range.is_empty() && range.split('-').all(|tag| {
+            (1..=8).contains(&tag.len()) && tag.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+    );
+
+    let mut ranges: Vec<LanguageRange> = headers.get_all(ACCEPT_LANGUAGE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|entry| {
+            let mut params = entry.split(';');
+            let range = params.next()?.trim();
+
+            let weight = match params.find_map(|p| p.trim().strip_prefix("q=")) {
+                Some(q) => q.trim().parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))?,
+                None => 1.0,
+            };
+
+            is_range(range).then(|| LanguageRange { range: range.to_owned(), weight })
+        })
+        .collect();
+
+    ranges.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    ranges
+}
+
+/// Picks the supported language tag for the first acceptable range (RFC 4647 lookup): a range
+/// matches a tag equal to it or starting with it (`en` matches `en-US`), and is shortened one
+/// subtag at a time until it matches (`de-CH-1996` falls back to `de`). `*` matches the first
+/// supported tag. Ranges with weight `0` are skipped; `None` when nothing matches.
+pub fn negotiate_language(ranges: &[LanguageRange], supported: &'static [&'static str]) -> Option<&'static str> {
+    for range in ranges {
+        if is_refused_weight(Some(range.weight)) {
+            continue;
+        }
+
+        if range.range == "*" {
+            return supported.first().copied();
+        }
+
+        let mut candidate = range.range.as_str();
+
+        loop {
+            let found = supported.iter().find(|tag| tag.eq_ignore_ascii_case(candidate))
+                .or_else(|| supported.iter().find(|tag| {
+                    tag.len() > candidate.len()
+                        && tag.as_bytes()[candidate.len()] == b'-'
+                        && tag[..candidate.len()].eq_ignore_ascii_case(candidate)
+                }));
+
+            if let Some(tag) = found {
+                return Some(tag);
+            }
+
+            match candidate.rsplit_once('-') {
+                Some((shorter, _)) => candidate = shorter,
+                None => break,
+            }
+        }
+    }
+
+    None
+}
+
 fn is_form_url_encoded(mime: &Mime) -> bool {
     mime.type_() == mime::APPLICATION
         && mime.subtype() == mime::WWW_FORM_URLENCODED
 }
 
+fn is_multipart_form_data(mime: &Mime) -> bool {
+    mime.type_() == mime::MULTIPART
+        && mime.subtype() == mime::FORM_DATA
+}
+
+fn is_xml(mime: &Mime) -> bool {
+    (mime.type_() == mime::APPLICATION || mime.type_() == mime::TEXT)
+        && (mime.subtype() == mime::XML || mime.suffix().is_some_and(|name| name == mime::XML))
+}
+
+fn is_cbor(mime: &Mime) -> bool {
+    mime.type_() == mime::APPLICATION
+        && (mime.subtype() == "cbor" || mime.suffix().is_some_and(|name| name == "cbor"))
+}
+
+fn is_msgpack(mime: &Mime) -> bool {
+    mime.type_() == mime::APPLICATION
+        && (mime.subtype() == mime::MSGPACK
+            || mime.subtype() == "x-msgpack"
+            || mime.suffix().is_some_and(|name| name == mime::MSGPACK))
+}
+
+fn is_plain_text(mime: &Mime) -> bool {
+    mime.type_() == mime::TEXT
+        && mime.subtype() == mime::PLAIN
+}
+
 fn is_json(mime: &Mime) -> bool {
     // shamelessly taken from axum::json because their function is private
     
@@ -154,6 +307,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_body_content_type_accepts_multipart_with_boundary() {
+        let mime: Mime = "multipart/form-data; boundary=X-GROOM-BOUNDARY"
+            .parse()
+            .unwrap();
+        let result = get_body_content_type(Some(mime));
+        assert!(
+            matches!(result, Some(BodyContentType::Multipart)),
+            "multipart/form-data with boundary must classify as Multipart"
+        );
+    }
+
+    #[test]
+    fn get_body_content_type_accepts_xml_variants() {
+        for content_type in ["application/xml", "text/xml; charset=utf-8", "application/atom+xml"] {
+            let mime: Mime = content_type.parse().unwrap();
+            assert!(
+                matches!(get_body_content_type(Some(mime)), Some(BodyContentType::Xml)),
+                "{content_type} must classify as Xml"
+            );
+        }
+    }
+
+    #[test]
+    fn get_body_content_type_accepts_cbor() {
+        let mime: Mime = "application/cbor".parse().unwrap();
+        assert!(matches!(get_body_content_type(Some(mime)), Some(BodyContentType::Cbor)));
+    }
+
+    #[test]
+    fn get_body_content_type_accepts_msgpack_variants() {
+        for content_type in ["application/msgpack", "application/x-msgpack"] {
+            let mime: Mime = content_type.parse().unwrap();
+            assert!(
+                matches!(get_body_content_type(Some(mime)), Some(BodyContentType::MsgPack)),
+                "{content_type} must classify as MsgPack"
+            );
+        }
+    }
+
+    #[test]
+    fn get_body_content_type_accepts_plain_text_with_charset() {
+        let mime: Mime = "text/plain; charset=iso-8859-1".parse().unwrap();
+        assert!(matches!(get_body_content_type(Some(mime)), Some(BodyContentType::PlainText)));
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_suffix() {
+        let accept: Accept = "application/vnd.partner+xml".parse().unwrap();
+        let supported = [mime::APPLICATION_JSON, APPLICATION_XML.clone()];
+        let chosen = negotiate_parameter_insensitive(&accept, &supported, None);
+        assert_eq!(chosen, Some(&supported[1]));
+    }
+
     #[test]
     fn parse_accept_header_returns_some_for_valid_accept() {
         let mut headers = HeaderMap::new();
@@ -199,6 +406,30 @@ mod tests {
         );
     }
 
+    fn accept_language(value: &'static str) -> Vec<LanguageRange> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static(value));
+        parse_accept_language_header(&headers)
+    }
+
+    #[test]
+    fn parse_accept_language_sorts_by_weight_and_skips_malformed() {
+        let ranges = accept_language("fr;q=0.5, de-CH, en;q=2, not a tag, *;q=0.1, en-GB");
+        let ranges: Vec<_> = ranges.iter().map(|r| (r.range.as_str(), r.weight)).collect();
+        assert_eq!(ranges, [("de-CH", 1.0), ("en-GB", 1.0), ("fr", 0.5), ("*", 0.1)]);
+    }
+
+    #[test]
+    fn negotiate_language_looks_up_shorter_and_longer_tags() {
+        const SUPPORTED: &[&str] = &["en-US", "de"];
+
+        assert_eq!(negotiate_language(&accept_language("de-CH-1996"), SUPPORTED), Some("de"));
+        assert_eq!(negotiate_language(&accept_language("EN"), SUPPORTED), Some("en-US"));
+        assert_eq!(negotiate_language(&accept_language("fr, de;q=0.5"), SUPPORTED), Some("de"));
+        assert_eq!(negotiate_language(&accept_language("fr, *;q=0.1"), SUPPORTED), Some("en-US"));
+        assert_eq!(negotiate_language(&accept_language("de;q=0, fr"), SUPPORTED), None);
+    }
+
     fn supported_json_html() -> [Mime; 2] {
         [
             "application/json".parse().unwrap(),
@@ -185,7 +185,7 @@ impl ComponentsRegistry {
 
 #[cfg(test)]
 mod tests {
-    use utoipa::openapi::{Ref, RefOr, Schema};
+    use utoipa::openapi::Ref;
 
     fn reference_to(ref_location: &str) -> Ref {
         Ref::new(ref_location)
@@ -197,31 +197,31 @@ mod tests {
         use crate::extract::{ComponentsRegistry, components_registry::tests::reference_to};
 
         mod submod1 {
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct SubStruct {
                 pub v: i32,
             }
         }
 
         mod submod2{
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct SubStruct {
                 pub v: String,
             }
         }
 
-        #[derive(utoipa::ToSchema)]
+        #[derive(serde::Serialize, utoipa::ToSchema)]
         struct Struct1 {
             pub a: submod1::SubStruct,
             pub b: submod2::SubStruct,
         }
 
-        #[derive(utoipa::ToSchema)]
+        #[derive(serde::Serialize, utoipa::ToSchema)]
         struct StructWithSub1 {
             pub a: submod1::SubStruct,
         }
 
-        #[derive(utoipa::ToSchema)]
+        #[derive(serde::Serialize, utoipa::ToSchema)]
         struct StructWithSub2 {
             pub a: submod2::SubStruct,
         }
@@ -259,7 +259,7 @@ mod tests {
 
             use crate::extract::{ComponentsRegistry, components_registry::tests::reference_to};
 
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct Struct1 {
                 pub v: i32,
             }
@@ -274,7 +274,7 @@ mod tests {
 
                 let c = ComponentsBuilder::new().build();
                 let c = reg1.into_components(c);
-                let c = reg2.into_components(c);
+                let _c = reg2.into_components(c);
             }
         }
 
@@ -284,14 +284,14 @@ mod tests {
             use crate::extract::{ComponentsRegistry, components_registry::tests::reference_to};
 
             mod sub1 {
-                #[derive(utoipa::ToSchema)]
+                #[derive(serde::Serialize, utoipa::ToSchema)]
                 pub struct Struct1 {
                     pub v: i32,
                 }
             }
 
             mod sub2 {
-                #[derive(utoipa::ToSchema)]
+                #[derive(serde::Serialize, utoipa::ToSchema)]
                 pub struct Struct1 {
                     pub v: String,
                 }
@@ -317,13 +317,13 @@ mod tests {
 
             use crate::extract::{ComponentsRegistry, components_registry::tests::reference_to};
 
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct Struct1 {
                 pub v: i32,
             }
             
             mod submod {
-                #[derive(utoipa::ToSchema)]
+                #[derive(serde::Serialize, utoipa::ToSchema)]
                 pub struct Struct1 {
                     pub v: i32,
                 }
@@ -337,7 +337,7 @@ mod tests {
                 assert_eq!(reg.add_components::<submod::Struct1>().reference.unwrap(), reference_to("#/components/schemas/Struct1"));
 
                 let c = ComponentsBuilder::new().build();
-                let c = reg.into_components(c);
+                let _c = reg.into_components(c);
             }
         }
     }
@@ -346,14 +346,14 @@ mod tests {
         use crate::extract::{ComponentsRegistry, SchemaMergeError};
 
         mod sub1 {
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct ConflictStruct {
                 pub v: i32,
             }
         }
 
         mod sub2 {
-            #[derive(utoipa::ToSchema)]
+            #[derive(serde::Serialize, utoipa::ToSchema)]
             pub struct ConflictStruct {
                 pub v: String,
             }
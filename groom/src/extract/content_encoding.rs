@@ -0,0 +1,236 @@
+//! `Content-Encoding` of request bodies handled by `#[RequestBody]`.
+//!
+//! Each coding is behind a cargo feature: `gzip`, `deflate`, `br` and `zstd`. An encoded body is
+//! buffered and decoded before the format extractor sees it; the decoded body is bounded by the
+//! same limit as an unencoded one, so a small compressed body cannot expand without bound.
+
+use std::sync::LazyLock;
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{
+        HeaderMap, StatusCode,
+        header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, HeaderValue},
+    },
+    response::IntoResponse,
+};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use thiserror::Error;
+use utoipa::openapi::{
+    extensions::{Extensions, ExtensionsBuilder},
+    path::OperationBuilder,
+};
+
+use crate::extract::{ComponentsRegistry, rejection::document_rejections};
+
+/// Limit of a decoded body when its `#[RequestBody]` sets no `max_size`; same as axum's default
+/// body limit.
+pub const DEFAULT_DECODED_LIMIT: usize = 2 * 1024 * 1024;
+
+/// [`DEFAULT_DECODED_LIMIT`] as shown in the 413 response.
+pub const DEFAULT_DECODED_LIMIT_DISPLAY: &str = "2MiB";
+
+const SUPPORTED_ENCODINGS: &[&str] = &[
+    #[cfg(feature = "gzip")]
+    "gzip",
+    #[cfg(feature = "deflate")]
+    "deflate",
+    #[cfg(feature = "br")]
+    "br",
+    #[cfg(feature = "zstd")]
+    "zstd",
+];
+
+static ACCEPT_ENCODING_VALUE: LazyLock<String> = LazyLock::new(|| {
+    match SUPPORTED_ENCODINGS.is_empty() {
+        true => "identity".to_owned(),
+        false => SUPPORTED_ENCODINGS.join(", "),
+    }
+});
+
+/// Content codings enabled by cargo features, in the order they are listed in `Accept-Encoding`.
+pub fn supported_encodings() -> &'static [&'static str] {
+    SUPPORTED_ENCODINGS
+}
+
+/// Failure of [`decode_body`].
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("Unsupported Content-Encoding `{0}`")]
+    Unsupported(String),
+
+    #[error("The decoded request body is too large")]
+    TooLarge,
+
+    #[error("Failed to decode the request body: {0}")]
+    Invalid(std::io::Error),
+
+    #[error("Failed to read the request body: {0}")]
+    Read(axum::Error),
+}
+
+/// Decodes the body according to `Content-Encoding`; requests without one are returned as is.
+///
+/// Both the encoded and the decoded body are limited to `max_size` bytes. The returned request
+/// has no `Content-Encoding` and `Content-Length` headers.
+pub async fn decode_body(req: Request, max_size: usize) -> Result<Request, DecodeError> {
+    let codings = content_codings(req.headers())?;
+
+    if codings.is_empty() {
+        return Ok(req);
+    }
+
+    if let Some(unsupported) = codings.iter().find(|c| !SUPPORTED_ENCODINGS.contains(&c.as_str())) {
+        return Err(DecodeError::Unsupported(unsupported.clone()));
+    }
+
+    let (mut parts, body) = req.into_parts();
+
+    let bytes = match Limited::new(body, max_size).collect().await {
+        Ok(collected) => collected.to_bytes().to_vec(),
+        Err(e) if e.is::<LengthLimitError>() => return Err(DecodeError::TooLarge),
+        Err(e) => return Err(DecodeError::Read(axum::Error::new(e))),
+    };
+
+    // decompression is CPU-bound, so it runs on the blocking pool instead of an async worker
+    let decoded = tokio::task::spawn_blocking(move || {
+        // codings are listed in the order they were applied
+        codings.iter().rev().try_fold(bytes, |bytes, coding| decode(coding, &bytes, max_size))
+    }).await;
+
+    let bytes = match decoded {
+        Ok(decoded) => decoded?,
+        Err(e) => std::panic::resume_unwind(e.into_panic()),
+    };
+
+    parts.headers.remove(CONTENT_ENCODING);
+    parts.headers.remove(CONTENT_LENGTH);
+
+    Ok(Request::from_parts(parts, Body::from(bytes)))
+}
+
+/// Lowercased codings of `Content-Encoding`, without `identity`.
+fn content_codings(headers: &HeaderMap) -> Result<Vec<String>, DecodeError> {
+    let mut codings = Vec::new();
+
+    for value in headers.get_all(CONTENT_ENCODING) {
+        let value = value.to_str()
+            .map_err(|_| DecodeError::Unsupported(String::from_utf8_lossy(value.as_bytes()).into_owned()))?;
+
+        codings.extend(
+            value.split(',')
+                .map(|c| c.trim().to_ascii_lowercase())
+                .filter(|c| !c.is_empty() && c != "identity")
+        );
+    }
+
+    Ok(codings)
+}
+
+#[cfg_attr(not(any(feature = "gzip", feature = "deflate", feature = "br", feature = "zstd")), allow(unused_variables))]
+fn decode(coding: &str, input: &[u8], max_size: usize) -> Result<Vec<u8>, DecodeError> {
+    match coding {
+        #[cfg(feature = "gzip")]
+        "gzip" => read_limited(flate2::read::MultiGzDecoder::new(input), max_size),
+
+        // `deflate` in HTTP is the zlib format (RFC 9110, section 8.4.1.2)
+        #[cfg(feature = "deflate")]
+        "deflate" => read_limited(flate2::read::ZlibDecoder::new(input), max_size),
+
+        #[cfg(feature = "br")]
+        "br" => read_limited(brotli::Decompressor::new(input, 4096), max_size),
+
+        #[cfg(feature = "zstd")]
+        "zstd" => read_limited(zstd::stream::read::Decoder::new(input).map_err(DecodeError::Invalid)?, max_size),
+
+        _ => Err(DecodeError::Unsupported(coding.to_owned())),
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "br", feature = "zstd"))]
+fn read_limited(reader: impl std::io::Read, max_size: usize) -> Result<Vec<u8>, DecodeError> {
+    use std::io::Read;
+
+    let mut output = Vec::new();
+
+    reader.take(max_size as u64 + 1)
+        .read_to_end(&mut output)
+        .map_err(DecodeError::Invalid)?;
+
+    match output.len() > max_size {
+        true => Err(DecodeError::TooLarge),
+        false => Ok(output),
+    }
+}
+
+/// Builds the 415 response for an unsupported `Content-Encoding`, listing the supported codings
+/// in `Accept-Encoding` (RFC 7694).
+pub fn unsupported_content_encoding(coding: &str) -> axum::response::Response {
+    (
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        [(ACCEPT_ENCODING, HeaderValue::from_str(&ACCEPT_ENCODING_VALUE).expect("codings are valid header values"))],
+        format!("Unsupported Content-Encoding `{coding}`"),
+    ).into_response()
+}
+
+/// Builds the 400 response for a body that is not valid in its `Content-Encoding`.
+pub fn invalid_encoded_body(error: std::io::Error) -> axum::response::Response {
+    (
+        StatusCode::BAD_REQUEST,
+        format!("Failed to decode the request body: {error}"),
+    ).into_response()
+}
+
+/// Documents the `413` of a decoded body larger than [`DEFAULT_DECODED_LIMIT`] for a
+/// `#[RequestBody]` without `max_size`; a no-op when no coding is enabled.
+pub fn document_decoded_limit(op: OperationBuilder, components: &mut ComponentsRegistry) -> OperationBuilder {
+    match SUPPORTED_ENCODINGS.is_empty() {
+        true => op,
+        false => document_rejections(op, components, &[
+            ("413", &format!("The decoded request body is larger than {DEFAULT_DECODED_LIMIT_DISPLAY}")),
+        ]),
+    }
+}
+
+/// `x-content-encoding` extension of a request body listing the supported codings; `None` when
+/// no coding is enabled.
+pub fn request_body_extensions() -> Option<Extensions> {
+    (!SUPPORTED_ENCODINGS.is_empty()).then(|| {
+        ExtensionsBuilder::new()
+            .add("x-content-encoding", SUPPORTED_ENCODINGS)
+            .build()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codings_are_listed_in_order_without_identity() {
+        let mut headers = HeaderMap::new();
+        headers.append(CONTENT_ENCODING, HeaderValue::from_static("gzip, Identity"));
+        headers.append(CONTENT_ENCODING, HeaderValue::from_static("BR"));
+
+        assert_eq!(content_codings(&headers).unwrap(), vec!["gzip", "br"]);
+    }
+
+    #[test]
+    fn unknown_coding_is_unsupported() {
+        assert!(matches!(decode("compress", b"", 10), Err(DecodeError::Unsupported(c)) if c == "compress"));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decoded_size_is_limited() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&[b'a'; 1000]).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        assert_eq!(decode("gzip", &encoded, 1000).unwrap().len(), 1000);
+        assert!(matches!(decode("gzip", &encoded, 999), Err(DecodeError::TooLarge)));
+    }
+}
@@ -0,0 +1,114 @@
+//! Request cookies read by `#[DTO(cookies)]` structs.
+//!
+//! ```ignore
+//! #[DTO(cookies)]
+//! pub struct Session {
+//!     pub session: String,
+//!     pub theme: Option<String>,
+//! }
+//!
+//! #[Route(method = "get", path = "/admin")]
+//! async fn admin(session: Session) -> AdminResponse { ... }
+//! ```
+//!
+//! Fields are cookie names; values are parsed the same way `Query` parses query parameters.
+
+use axum::{
+    http::{HeaderMap, StatusCode, header::COOKIE},
+    response::IntoResponse,
+};
+use serde::de::DeserializeOwned;
+use utoipa::{IntoParams, openapi::path::{OperationBuilder, ParameterIn}};
+
+use crate::{DTO, extract::{ComponentsRegistry, parameters::{document_validation, fold_parameter, get_schemas}}};
+
+/// Rejection of `#[DTO(cookies)]` extractors.
+#[derive(Debug)]
+pub enum CookiesRejection {
+    /// A required cookie is missing or a value does not fit its field.
+    Invalid(serde_urlencoded::de::Error),
+}
+
+impl IntoResponse for CookiesRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            CookiesRejection::Invalid(error) =>
+                (StatusCode::BAD_REQUEST, format!("Failed to deserialize cookies: {error}")).into_response(),
+        }
+    }
+}
+
+/// Deserializes `T` from the `Cookie` headers; used by `#[DTO(cookies)]` extractors.
+///
+/// Only the first of several cookies with the same name is used: browsers send the one with
+/// the most specific path first (RFC 6265, section 5.4).
+pub fn from_cookie_headers<T: DeserializeOwned>(headers: &HeaderMap) -> Result<T, CookiesRejection> {
+    let mut pairs: Vec<(&str, &str)> = Vec::new();
+
+    let cookies = headers.get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.split_once('='));
+
+    for (name, value) in cookies {
+        let name = name.trim();
+        let value = value.trim();
+        let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+
+        if !pairs.iter().any(|(n, _)| *n == name) {
+            pairs.push((name, value));
+        }
+    }
+
+    // cookie values are re-encoded as a query string to reuse its typed parsing
+    let query = serde_urlencoded::to_string(&pairs).expect("pairs of strings are serializable");
+
+    serde_urlencoded::from_str(&query).map_err(CookiesRejection::Invalid)
+}
+
+/// Documents the fields of `T` as `in: cookie` parameters; used by `#[DTO(cookies)]` extractors.
+pub fn openapi_modify_operation<T: DTO + IntoParams>(op: OperationBuilder, registry: &mut ComponentsRegistry) -> OperationBuilder {
+    let schemas = get_schemas::<T>(registry);
+
+    let op = T::into_params(|| Some(ParameterIn::Cookie))
+        .into_iter()
+        .fold(op, |op, p| {
+            fold_parameter(op, p, &schemas)
+        });
+
+    document_validation::<T>(op, registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Session {
+        session: String,
+        page_size: Option<u32>,
+    }
+
+    #[test]
+    fn cookies_are_deserialized() {
+        let mut headers = HeaderMap::new();
+        headers.append(COOKIE, HeaderValue::from_static("theme=dark; session=\"a+b%20c\"; session=older"));
+        headers.append(COOKIE, HeaderValue::from_static("page_size=20"));
+
+        assert_eq!(
+            from_cookie_headers::<Session>(&headers).unwrap(),
+            Session { session: "a+b%20c".into(), page_size: Some(20) },
+        );
+    }
+
+    #[test]
+    fn missing_cookie_is_rejected() {
+        let headers = HeaderMap::new();
+
+        assert!(matches!(from_cookie_headers::<Session>(&headers), Err(CookiesRejection::Invalid(_))));
+    }
+}
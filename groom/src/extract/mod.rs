@@ -3,12 +3,30 @@ use utoipa::openapi::path::OperationBuilder;
 mod parameters;
 mod std_types;
 mod components_registry;
+pub mod content_encoding;
+pub mod cookies;
+pub mod multipart;
+pub mod ndjson;
+pub mod rejection;
 
 pub use components_registry::{ComponentsRegistry, SchemaMergeError};
+pub use multipart::Upload;
+pub use ndjson::{LineError, NdJsonStream};
 
 /// GroomExtractor is the trait that enables types to describe themselves into openapi spec.
 pub trait GroomExtractor {
     fn __openapi_modify_operation(op: OperationBuilder, components: &mut ComponentsRegistry) -> OperationBuilder;
+
+    /// Checks the extracted value before the handler runs. Failures are answered with `422`.
+    fn __groom_validate(&self) -> Result<(), crate::validation::ValidationErrors> {
+        Ok(())
+    }
+
+    /// Names of the path parameters the extractor reads, checked against the route template by
+    /// `GroomRouter::validate`; `None` when it does not read the path.
+    fn __groom_path_parameters() -> Option<Vec<String>> {
+        None
+    }
 }
 
 /// Creates a newtype for axum::body::Bytes with custom content type specified in the openapi spec.
@@ -0,0 +1,88 @@
+//! Runtime support for `#[RequestBody(format(multipart))]`.
+//!
+//! The macro maps each named field of the request body onto a `multipart/form-data` part:
+//! [`Upload`] and `axum::body::Bytes` fields become file parts, everything else is read as
+//! text and parsed through `FromStr`.
+
+use std::str::FromStr;
+
+use axum::body::Bytes;
+use axum::extract::multipart::{Field, MultipartError};
+use utoipa::openapi::{KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, schema::{SchemaType, Type}};
+use utoipa::{PartialSchema, ToSchema};
+
+/// A file part of a `multipart/form-data` request body.
+///
+/// Carries the part's bytes together with the filename and content type the client sent.
+#[derive(Debug, Clone)]
+pub struct Upload {
+    /// `filename` parameter of the part's `Content-Disposition` header.
+    pub file_name: Option<String>,
+
+    /// `Content-Type` header of the part.
+    pub content_type: Option<String>,
+
+    /// Contents of the part.
+    pub bytes: Bytes,
+}
+
+impl PartialSchema for Upload {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Type(Type::String))
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)))
+            .into()
+    }
+}
+
+impl ToSchema for Upload {}
+
+/// Failure to read a single multipart part into a request body field.
+#[derive(Debug)]
+pub enum MultipartPartError {
+    /// The part could not be read from the request body.
+    Read(MultipartError),
+
+    /// The text of the part could not be parsed into the field type.
+    Parse(String),
+}
+
+/// macro-internal — not public API
+#[doc(hidden)]
+pub async fn read_upload(field: Field<'_>) -> Result<Upload, MultipartPartError> {
+    let file_name = field.file_name().map(str::to_owned);
+    let content_type = field.content_type().map(str::to_owned);
+    let bytes = field.bytes().await.map_err(MultipartPartError::Read)?;
+
+    Ok(Upload { file_name, content_type, bytes })
+}
+
+/// macro-internal — not public API
+#[doc(hidden)]
+pub async fn read_bytes(field: Field<'_>) -> Result<Bytes, MultipartPartError> {
+    field.bytes().await.map_err(MultipartPartError::Read)
+}
+
+/// macro-internal — not public API
+#[doc(hidden)]
+pub async fn read_text<T>(field: Field<'_>) -> Result<T, MultipartPartError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let text = field.text().await.map_err(MultipartPartError::Read)?;
+
+    text.parse::<T>()
+        .map_err(|e| MultipartPartError::Parse(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upload_schema_is_binary_string() {
+        let json = serde_json::to_value(Upload::schema()).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "string", "format": "binary" }));
+    }
+}
@@ -0,0 +1,335 @@
+//! Streaming `application/x-ndjson` request bodies.
+//!
+//! [`NdJsonStream`] yields one item per line while the body is still arriving, so bulk uploads
+//! are never buffered as a whole. Only the current line is held in memory, up to a maximum
+//! length.
+
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use axum::{
+    body::BodyDataStream,
+    extract::{FromRequest, Request},
+    http::Method,
+    response::IntoResponse,
+};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use utoipa::openapi::{ContentBuilder, RefOr, Required, path::OperationBuilder, request_body::RequestBodyBuilder};
+
+use crate::{
+    DTO, DTO_Request,
+    content_negotiation::parse_content_type_header,
+    extract::{ComponentsRegistry, GroomExtractor, rejection::{document_rejections, unsupported_media_type}},
+    validation::ValidationErrors,
+};
+
+const NDJSON: &str = "application/x-ndjson";
+
+/// Default of [`NdJsonStream::with_max_line_length`]: 1 MiB.
+pub const DEFAULT_MAX_LINE_LENGTH: usize = 1 << 20;
+
+/// Newline-delimited JSON request body, read as a stream of `T`.
+///
+/// Accepts `application/x-ndjson` and `application/ndjson`. Empty lines are skipped and a
+/// trailing `\r` is ignored. Items are deserialized and checked against their
+/// `#[validate(...)]` constraints one by one; a bad line yields an `Err` and the stream goes on
+/// with the next one. So does a line longer than [`DEFAULT_MAX_LINE_LENGTH`], or the limit given
+/// to [`NdJsonStream::with_max_line_length`], whose rest is skipped unread. A failure to read the
+/// body ends the stream.
+///
+/// ```ignore
+/// async fn import(mut tasks: NdJsonStream<Task>) -> ImportResponse {
+///     while let Some(task) = tasks.next().await {
+///         // ...
+///     }
+/// }
+/// ```
+pub struct NdJsonStream<T> {
+    body: BodyDataStream,
+    buffer: Vec<u8>,
+    /// Start of the current line in `buffer`
+    start: usize,
+    /// Length of the current line searched for `\n` so far
+    scanned: usize,
+    max_line_length: usize,
+    /// The current line is too long; its bytes are dropped until its end
+    skipping: bool,
+    line: usize,
+    done: bool,
+    _item: PhantomData<fn() -> T>,
+}
+
+/// Failure of a single line of an [`NdJsonStream`].
+#[derive(Debug, Error)]
+pub enum LineError {
+    #[error("Failed to read the request body: {0}")]
+    Read(axum::Error),
+
+    #[error("Line {line}: {source}")]
+    Deserialize { line: usize, source: serde_json::Error },
+
+    #[error("Line {line}: the item does not satisfy its constraints")]
+    Invalid { line: usize, errors: ValidationErrors },
+
+    #[error("Line {line}: longer than {max_length} bytes")]
+    TooLong { line: usize, max_length: usize },
+}
+
+/// Rejection of the [`NdJsonStream`] extractor.
+#[derive(Debug)]
+pub enum NdJsonRejection {
+    /// `Content-Type` is missing, malformed or not NDJSON
+    BadContentType(Method),
+}
+
+impl IntoResponse for NdJsonRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            NdJsonRejection::BadContentType(method) => unsupported_media_type(&method, NDJSON),
+        }
+    }
+}
+
+impl<T> NdJsonStream<T> {
+    /// Sets the maximum length of a line in bytes, [`DEFAULT_MAX_LINE_LENGTH`] by default.
+    pub fn with_max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = max_line_length;
+        self
+    }
+}
+
+impl<T: DTO + DeserializeOwned> NdJsonStream<T> {
+    /// Next item of the body, `None` once the body is over.
+    pub async fn next(&mut self) -> Option<Result<T, LineError>> {
+        std::future::poll_fn(|cx| self.poll_item(cx)).await
+    }
+
+    fn poll_item(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<T, LineError>>> {
+        loop {
+            let from = self.start + self.scanned;
+
+            if let Some(end) = self.buffer[from..].iter().position(|b| *b == b'\n').map(|i| from + i) {
+                let (start, skipping) = (self.start, self.skipping);
+                self.start = end + 1;
+                self.scanned = 0;
+                self.skipping = false;
+
+                if skipping {
+                    continue;
+                }
+
+                if let Some(item) = self.complete_line(start, end) {
+                    return Poll::Ready(Some(item));
+                }
+
+                continue;
+            }
+
+            self.scanned = self.buffer.len() - self.start;
+
+            if self.scanned > self.max_line_length && !self.skipping {
+                self.skipping = true;
+                self.line += 1;
+
+                return Poll::Ready(Some(Err(LineError::TooLong { line: self.line, max_length: self.max_line_length })));
+            }
+
+            if self.skipping {
+                self.buffer.clear();
+                self.start = 0;
+                self.scanned = 0;
+            }
+
+            if self.done {
+                let (start, end) = (self.start, self.buffer.len());
+                self.start = end;
+                self.scanned = 0;
+
+                return Poll::Ready(match self.skipping || start == end {
+                    true => None,
+                    false => self.complete_line(start, end),
+                });
+            }
+
+            // only the current line is moved to the front, at most once per chunk
+            self.buffer.drain(..self.start);
+            self.start = 0;
+
+            match ready!(Pin::new(&mut self.body).poll_next(cx)) {
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.skipping = true;
+                    self.buffer.clear();
+                    self.scanned = 0;
+
+                    return Poll::Ready(Some(Err(LineError::Read(e))));
+                },
+                None => self.done = true,
+            }
+        }
+    }
+
+    /// Parses `buffer[start..end]`; `None` for blank lines.
+    fn complete_line(&mut self, start: usize, end: usize) -> Option<Result<T, LineError>> {
+        self.line += 1;
+
+        if end - start > self.max_line_length {
+            return Some(Err(LineError::TooLong { line: self.line, max_length: self.max_line_length }));
+        }
+
+        let line = &self.buffer[start..end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return None;
+        }
+
+        let item = match serde_json::from_slice::<T>(line) {
+            Ok(item) => item,
+            Err(source) => return Some(Err(LineError::Deserialize { line: self.line, source })),
+        };
+
+        Some(match item.__groom_validate() {
+            Ok(()) => Ok(item),
+            Err(errors) => Err(LineError::Invalid { line: self.line, errors }),
+        })
+    }
+}
+
+impl<T: DTO + DeserializeOwned> Stream for NdJsonStream<T> {
+    type Item = Result<T, LineError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_item(cx)
+    }
+}
+
+impl<T, S> FromRequest<S> for NdJsonStream<T>
+where
+    T: DTO + DTO_Request + DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = NdJsonRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let is_ndjson = match parse_content_type_header(req.headers()) {
+            Ok(Some(mime)) => mime.type_() == mime::APPLICATION
+                && (mime.subtype() == "x-ndjson" || mime.subtype() == "ndjson"),
+            _ => false,
+        };
+
+        if !is_ndjson {
+            return Err(NdJsonRejection::BadContentType(req.method().clone()));
+        }
+
+        Ok(NdJsonStream {
+            body: req.into_body().into_data_stream(),
+            buffer: Vec::new(),
+            start: 0,
+            scanned: 0,
+            max_line_length: DEFAULT_MAX_LINE_LENGTH,
+            skipping: false,
+            line: 0,
+            done: false,
+            _item: PhantomData,
+        })
+    }
+}
+
+impl<T: DTO + DTO_Request> GroomExtractor for NdJsonStream<T> {
+    fn __openapi_modify_operation(op: OperationBuilder, components: &mut ComponentsRegistry) -> OperationBuilder {
+        let item_schema = RefOr::from(components.add_components::<T>());
+
+        let op = op.request_body(Some(
+            RequestBodyBuilder::new()
+                .content(NDJSON, ContentBuilder::new().schema(Some(item_schema)).build())
+                .description(Some(format!("Newline-delimited JSON, one `{}` per line", T::name())))
+                .required(Some(Required::True))
+                .build()
+        ));
+
+        document_rejections(op, components, &[
+            ("415", "The request body content type is not supported"),
+        ])
+    }
+}
+
+impl<T> std::fmt::Debug for NdJsonStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NdJsonStream")
+            .field("line", &self.line)
+            .field("buffered", &(self.buffer.len() - self.start))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use axum::body::{Body, Bytes};
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, utoipa::ToSchema)]
+    struct Item {
+        n: u32,
+    }
+
+    impl DTO for Item {}
+    impl DTO_Request for Item {}
+
+    /// Body arriving in the given chunks.
+    struct Chunks(VecDeque<&'static str>);
+
+    impl Stream for Chunks {
+        type Item = Result<Bytes, std::io::Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.pop_front().map(|chunk| Ok(Bytes::from_static(chunk.as_bytes()))))
+        }
+    }
+
+    async fn read(chunks: &[&'static str], max_line_length: usize) -> Vec<String> {
+        let req = Request::builder()
+            .header("content-type", NDJSON)
+            .body(Body::from_stream(Chunks(chunks.iter().copied().collect())))
+            .unwrap();
+
+        let mut stream = NdJsonStream::<Item>::from_request(req, &()).await.unwrap()
+            .with_max_line_length(max_line_length);
+
+        let mut items = Vec::new();
+        while let Some(item) = stream.next().await {
+            items.push(match item {
+                Ok(item) => item.n.to_string(),
+                Err(e) => e.to_string(),
+            });
+        }
+        items
+    }
+
+    #[tokio::test]
+    async fn lines_span_chunks() {
+        assert_eq!(read(&["{\"n\"", ": 1}\n{\"n\": 2", "}\n\n{\"n\":", " 3}"], 16).await, ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn too_long_lines_are_skipped() {
+        let items = read(&["{\"n\": 1}\n{\"n\": ", "         ", "         ", "2}\n{\"n\": 3}\n"], 16).await;
+        assert_eq!(items, ["1", "Line 2: longer than 16 bytes", "3"]);
+
+        let items = read(&["{\"n\": 1}\n{\"n\":                  2}\n{\"n\": 3}"], 16).await;
+        assert_eq!(items, ["1", "Line 2: longer than 16 bytes", "3"]);
+
+        let items = read(&["{\"n\": 1}\n{\"n\": ", "                   "], 16).await;
+        assert_eq!(items, ["1", "Line 2: longer than 16 bytes"]);
+    }
+}
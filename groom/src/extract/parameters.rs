@@ -1,9 +1,9 @@
 use axum::extract::{Path, Query};
 use utoipa::{IntoParams, openapi::{RefOr, Schema, path::{OperationBuilder, Parameter, ParameterIn}}};
 
-use crate::{DTO, extract::{ComponentsRegistry, GroomExtractor, components_registry::ComponentEntry}};
+use crate::{DTO, extract::{ComponentsRegistry, GroomExtractor, components_registry::ComponentEntry}, validation::{ValidationErrors, document_validation_errors}};
 
-fn get_schemas<T: DTO>(c: &mut ComponentsRegistry) -> Vec<(String, ComponentEntry)> {
+pub(super) fn get_schemas<T: DTO>(c: &mut ComponentsRegistry) -> Vec<(String, ComponentEntry)> {
     c.add_subcomponents::<T>();
 
     let mut schemas = Vec::<(String, RefOr<Schema>)>::new();
@@ -15,7 +15,15 @@ fn get_schemas<T: DTO>(c: &mut ComponentsRegistry) -> Vec<(String, ComponentEntr
         .collect()
 }
 
-fn fold_parameter(op: OperationBuilder, mut p: Parameter, schemas: &[(String, ComponentEntry)]) -> OperationBuilder {
+/// Documents the `422` of `T`'s constraints, if it has any.
+pub(super) fn document_validation<T: DTO>(op: OperationBuilder, registry: &mut ComponentsRegistry) -> OperationBuilder {
+    match T::__groom_validates() {
+        true => document_validation_errors(op, registry),
+        false => op,
+    }
+}
+
+pub(super) fn fold_parameter(op: OperationBuilder, mut p: Parameter, schemas: &[(String, ComponentEntry)]) -> OperationBuilder {
     if let Some(ref schema) = p.schema {
         match schema {
             RefOr::T(schema)   => {
@@ -45,11 +53,26 @@ impl<T: DTO + IntoParams> GroomExtractor for Path<T> {
     fn __openapi_modify_operation(op: OperationBuilder, registry: &mut ComponentsRegistry) -> OperationBuilder {
         let schemas = get_schemas::<T>(registry);
 
-        T::into_params(|| Some(ParameterIn::Path))
+        let op = T::into_params(|| Some(ParameterIn::Path))
             .into_iter()
             .fold(op, |op, p| {
                 fold_parameter(op, p, &schemas)
-            })
+            });
+
+        document_validation::<T>(op, registry)
+    }
+
+    fn __groom_validate(&self) -> Result<(), ValidationErrors> {
+        self.0.__groom_validate()
+    }
+
+    fn __groom_path_parameters() -> Option<Vec<String>> {
+        Some(
+            T::into_params(|| Some(ParameterIn::Path))
+                .into_iter()
+                .map(|p| p.name)
+                .collect()
+        )
     }
 }
 
@@ -57,11 +80,17 @@ impl<T: DTO + IntoParams> GroomExtractor for Query<T> {
     fn __openapi_modify_operation(op: OperationBuilder, registry: &mut ComponentsRegistry) -> OperationBuilder {
         let schemas = get_schemas::<T>(registry);
 
-        T::into_params(|| Some(ParameterIn::Query))
+        let op = T::into_params(|| Some(ParameterIn::Query))
             .into_iter()
             .fold(op, |op, p| {
                 fold_parameter(op, p, &schemas)
-            })
+            });
+
+        document_validation::<T>(op, registry)
+    }
+
+    fn __groom_validate(&self) -> Result<(), ValidationErrors> {
+        self.0.__groom_validate()
     }
 }
 
@@ -70,10 +99,16 @@ impl<T: DTO + IntoParams> GroomExtractor for axum_extra::extract::Query<T> {
     fn __openapi_modify_operation(op: OperationBuilder, registry: &mut ComponentsRegistry) -> OperationBuilder {
         let schemas = get_schemas::<T>(registry);
 
-        T::into_params(|| Some(ParameterIn::Query))
+        let op = T::into_params(|| Some(ParameterIn::Query))
             .into_iter()
             .fold(op, |op, p| {
                 fold_parameter(op, p, &schemas)
-            })
+            });
+
+        document_validation::<T>(op, registry)
+    }
+
+    fn __groom_validate(&self) -> Result<(), ValidationErrors> {
+        self.0.__groom_validate()
     }
 }
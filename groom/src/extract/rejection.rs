@@ -0,0 +1,163 @@
+//! Shared parts of the `*Rejection` enums generated by `#[RequestBody]`.
+
+use std::convert::Infallible;
+
+use axum::{
+    body::Body,
+    extract::{DefaultBodyLimit, Request},
+    http::{HeaderName, Method, StatusCode, header::{CONTENT_LENGTH, HeaderValue}},
+    response::IntoResponse,
+};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use tower::{Layer, ServiceExt, service_fn};
+use utoipa::openapi::{
+    Content, ContentBuilder, RefOr, ResponseBuilder,
+    extensions::ExtensionsBuilder,
+    path::{Operation, OperationBuilder},
+};
+
+use crate::extract::ComponentsRegistry;
+
+static ACCEPT_POST: HeaderName = HeaderName::from_static("accept-post");
+static ACCEPT_PATCH: HeaderName = HeaderName::from_static("accept-patch");
+
+/// Builds the 415 Unsupported Media Type response for a request body.
+///
+/// `supported` is the comma-separated list of accepted mimes. It is sent in `Accept-Patch` for
+/// PATCH requests and in `Accept-Post` otherwise.
+pub fn unsupported_media_type(method: &Method, supported: &'static str) -> axum::response::Response {
+    let header = match *method {
+        Method::PATCH => ACCEPT_PATCH.clone(),
+        _ => ACCEPT_POST.clone(),
+    };
+
+    (
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        [(header, HeaderValue::from_static(supported))],
+        "Unsupported Content-Type",
+    ).into_response()
+}
+
+/// Adds plain-text responses for the failure modes of a request body extractor.
+///
+/// `rejections` are `(status code, description)` pairs. Responses declared by the handler's
+/// return type are applied later and take precedence for the same status code.
+pub fn document_rejections(
+    op: OperationBuilder,
+    components: &mut ComponentsRegistry,
+    rejections: &[(&str, &str)],
+) -> OperationBuilder {
+    let schema = ComponentsRegistry::schema_or_ref::<String>(components);
+
+    rejections.iter().fold(op.build(), |operation, (code, description)| {
+        add_response_content(
+            operation,
+            code,
+            description,
+            mime::TEXT_PLAIN_UTF_8.as_ref(),
+            ContentBuilder::new().schema(Some(schema.clone())).build(),
+        )
+    }).into()
+}
+
+/// Adds `content` to the `code` response of `operation`, keeping content that other extractors
+/// documented for the same code. A different description is appended to the earlier one.
+pub(crate) fn add_response_content(
+    mut operation: Operation,
+    code: &str,
+    description: &str,
+    mime: &str,
+    content: Content,
+) -> Operation {
+    match operation.responses.responses.get_mut(code) {
+        Some(RefOr::T(response)) => {
+            if !response.description.contains(description) {
+                response.description = format!("{}. {description}", response.description);
+            }
+
+            response.content.insert(mime.to_owned(), content);
+        },
+        _ => {
+            operation.responses.responses.insert(
+                code.to_owned(),
+                ResponseBuilder::new().description(description).content(mime, content).build().into(),
+            );
+        },
+    }
+
+    operation
+}
+
+/// Failure of [`limit_body`].
+#[derive(Debug)]
+pub enum BodyLimitError {
+    /// The body is larger than the limit.
+    TooLarge,
+
+    /// The body could not be read.
+    Read(axum::Error),
+}
+
+/// Buffers the request body, failing with [`BodyLimitError::TooLarge`] once it exceeds
+/// `max_size` bytes.
+///
+/// A `Content-Length` above the limit is rejected without reading the body. The returned request
+/// carries the buffered body, so extractors that run afterwards do not stream from the client.
+/// It also disables axum's `DefaultBodyLimit` for them: `max_size` replaces it, even above 2 MiB.
+pub async fn limit_body(req: Request, max_size: usize) -> Result<Request, BodyLimitError> {
+    let declared_length = req.headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+
+    if let Some(length) = declared_length && length > max_size as u64 {
+        return Err(BodyLimitError::TooLarge);
+    }
+
+    let (parts, body) = req.into_parts();
+
+    let bytes = match Limited::new(body, max_size).collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) if e.is::<LengthLimitError>() => return Err(BodyLimitError::TooLarge),
+        Err(e) => return Err(BodyLimitError::Read(axum::Error::new(e))),
+    };
+
+    let req = Request::from_parts(parts, Body::from(bytes));
+
+    // `DefaultBodyLimit` sets the limit through a private request extension, only as a layer
+    let disable_default_limit = DefaultBodyLimit::disable()
+        .layer(service_fn(|req: Request| async move { Ok::<_, Infallible>(req) }));
+
+    match disable_default_limit.oneshot(req).await {
+        Ok(req) => Ok(req),
+        Err(infallible) => match infallible {},
+    }
+}
+
+/// Builds the 413 Payload Too Large response for a request body; `max_size` is the limit as
+/// written in `#[RequestBody(max_size = "...")]`.
+pub fn payload_too_large(max_size: &'static str) -> axum::response::Response {
+    (
+        StatusCode::PAYLOAD_TOO_LARGE,
+        format!("Payload Too Large: the request body must not exceed {max_size}"),
+    ).into_response()
+}
+
+/// Builds the 400 response for a request body that could not be read.
+pub fn failed_to_buffer_body(error: axum::Error) -> axum::response::Response {
+    (
+        StatusCode::BAD_REQUEST,
+        format!("Failed to read the request body: {error}"),
+    ).into_response()
+}
+
+/// Publishes the body size limit of an operation as the `x-max-body-size` extension (in bytes).
+pub fn document_body_limit(op: OperationBuilder, max_size: usize) -> OperationBuilder {
+    let mut operation: Operation = op.build();
+
+    operation.extensions
+        .get_or_insert_default()
+        .merge(ExtensionsBuilder::new().add("x-max-body-size", max_size).build());
+
+    operation.into()
+}
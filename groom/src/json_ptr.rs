@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn test_build_openapi_ref() {
         // Realistic OpenAPI $ref examples
-        let ref_path = format!("#/paths/~1users~1{{id}}");
+        let ref_path = "#/paths/~1users~1{id}".to_string();
         assert_eq!(ref_path, "#/paths/~1users~1{id}");
         
         let escaped = escape_json_pointer("paths/users/{id}");
@@ -3,16 +3,35 @@ use utoipa::ToSchema;
 pub mod extract;
 pub mod response;
 pub mod content_negotiation;
+pub mod locale;
 pub mod json_ptr;
+pub mod xml;
+pub mod plain_text;
+#[cfg(feature = "cbor")]
+pub mod cbor;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
 
 pub mod runtime_checks;
+pub mod validation;
 
 pub mod router;
 
 /// Indicates that type is annotated with `#[DTO(...)]`.
 /// 
 /// Do not implement this manually.
-pub trait DTO: ToSchema + 'static {}
+pub trait DTO: ToSchema + 'static {
+    /// Checks the `#[validate(...)]` constraints of the fields.
+    fn __groom_validate(&self) -> Result<(), validation::ValidationErrors> {
+        Ok(())
+    }
+
+    /// Whether [`DTO::__groom_validate`] checks any constraint, so that extractors document
+    /// its `422`.
+    fn __groom_validates() -> bool {
+        false
+    }
+}
 
 /// Indicates that type is annotated with `#[DTO(request)]`.
 ///
@@ -0,0 +1,207 @@
+//! Language negotiation from `Accept-Language`.
+//!
+//! The [`Localization`] router layer negotiates the request language against the languages of
+//! the application, and makes the result available as a [`Locale`]:
+//!
+//! - to handlers, as an extractor;
+//! - to HTML templates, through [`RenderContext`](crate::response::RenderContext) in
+//!   [`HtmlFormat::render_with`](crate::response::HtmlFormat::render_with).
+//!
+//! Responses with content get `Content-Language`, and every response gets
+//! `Vary: Accept-Language`.
+//!
+//! ```ignore
+//! let router = controller::into_router()
+//!     .layer_with_spec(Localization::new(&["en", "de"]));
+//!
+//! #[Route(method = "get", path = "/greeting")]
+//! async fn greeting(locale: Locale) -> GreetingResponse {
+//!     match locale.as_str() {
+//!         "de" => GreetingResponse::Ok("Hallo".into()),
+//!         _ => GreetingResponse::Ok("Hello".into()),
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use axum::{
+    extract::{FromRequestParts, OptionalFromRequestParts, Request, State},
+    http::{
+        HeaderValue, StatusCode,
+        header::{CONTENT_LANGUAGE, CONTENT_TYPE},
+        request::Parts,
+    },
+    middleware::Next,
+    response::IntoResponse,
+};
+use utoipa::openapi::{
+    ObjectBuilder, Required, Type,
+    path::{HttpMethod, Operation, ParameterBuilder, ParameterIn},
+};
+
+use crate::{
+    content_negotiation::{negotiate_language, parse_accept_language_header},
+    extract::{ComponentsRegistry, GroomExtractor},
+    response::append_vary,
+    router::OpenApiSpecLayer,
+};
+
+/// Language of the response, negotiated by [`Localization`].
+///
+/// As an extractor, requires the [`Localization`] layer on the router (`500` without it);
+/// extract `Option<Locale>` to make it optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale(&'static str);
+
+impl Locale {
+    /// Language tag, one of the tags given to [`Localization::new`].
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Locale {
+    type Rejection = MissingLocalization;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<Locale>().copied().ok_or(MissingLocalization)
+    }
+}
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for Locale {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Option<Self>, Self::Rejection> {
+        Ok(parts.extensions.get::<Locale>().copied())
+    }
+}
+
+/// `Accept-Language` is documented by the [`Localization`] layer, which negotiates it.
+impl GroomExtractor for Locale {
+    fn __openapi_modify_operation(
+        op: utoipa::openapi::path::OperationBuilder,
+        _c: &mut ComponentsRegistry,
+    ) -> utoipa::openapi::path::OperationBuilder {
+        op
+    }
+}
+
+impl GroomExtractor for Option<Locale> {
+    fn __openapi_modify_operation(
+        op: utoipa::openapi::path::OperationBuilder,
+        _c: &mut ComponentsRegistry,
+    ) -> utoipa::openapi::path::OperationBuilder {
+        op
+    }
+}
+
+/// Rejection of [`Locale`] on a router without the [`Localization`] layer.
+#[derive(Debug)]
+pub struct MissingLocalization;
+
+impl IntoResponse for MissingLocalization {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, "Missing request locale; is the `Localization` layer added to the router?").into_response()
+    }
+}
+
+/// Languages of the application; an [`OpenApiSpecLayer`] negotiating the [`Locale`] of each
+/// request and documenting the `Accept-Language` header parameter.
+#[derive(Debug, Clone, Copy)]
+pub struct Localization {
+    languages: &'static [&'static str],
+}
+
+impl Localization {
+    /// Supported language tags (`en`, `de-CH`); the first one is used when the client accepts
+    /// none of them.
+    ///
+    /// # Panics
+    ///
+    /// When `languages` is empty or a tag is not made of ASCII letters, digits and `-`.
+    pub const fn new(languages: &'static [&'static str]) -> Self {
+        assert!(!languages.is_empty(), "Localization needs at least one language");
+
+        let mut i = 0;
+        while i < languages.len() {
+            let tag = languages[i].as_bytes();
+            assert!(!tag.is_empty(), "Localization language tags must not be empty");
+
+            let mut j = 0;
+            while j < tag.len() {
+                assert!(tag[j].is_ascii_alphanumeric() || tag[j] == b'-', "Localization language tags must be made of ASCII letters, digits and `-`");
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        Self { languages }
+    }
+
+    /// Negotiates the locale of a request from its `Accept-Language`.
+    pub fn negotiate(&self, headers: &axum::http::HeaderMap) -> Locale {
+        let ranges = parse_accept_language_header(headers);
+
+        Locale(negotiate_language(&ranges, self.languages).unwrap_or(self.languages[0]))
+    }
+}
+
+impl OpenApiSpecLayer for Localization {
+    fn modify_operation(&self, _path: &str, _method: &HttpMethod, operation: &mut Operation) {
+        let parameters = operation.parameters.get_or_insert_with(Vec::new);
+
+        let documented = parameters.iter().any(|p| {
+            p.parameter_in == ParameterIn::Header && p.name.eq_ignore_ascii_case("accept-language")
+        });
+
+        if documented {
+            return;
+        }
+
+        let languages = self.languages.iter()
+            .map(|tag| format!("`{tag}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        parameters.push(
+            ParameterBuilder::new()
+                .name("Accept-Language")
+                .parameter_in(ParameterIn::Header)
+                .required(Required::False)
+                .description(Some(format!("Preferred languages of the response; supported: {languages}")))
+                .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+                .build()
+        );
+    }
+
+    fn mount<S>(&self, r: axum::Router<S>) -> axum::Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        r.layer(axum::middleware::from_fn_with_state(*self, localize_middleware))
+    }
+}
+
+async fn localize_middleware(State(localization): State<Localization>, mut req: Request, next: Next) -> axum::response::Response {
+    let locale = localization.negotiate(req.headers());
+    req.extensions_mut().insert(locale);
+
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+
+    if headers.contains_key(CONTENT_TYPE) && !headers.contains_key(CONTENT_LANGUAGE) {
+        headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.as_str()));
+    }
+
+    append_vary(headers, "accept-language");
+
+    response
+}
@@ -0,0 +1,108 @@
+//! MessagePack request and response bodies for `format(msgpack)` (`msgpack` cargo feature).
+//!
+//! [`MsgPack`] works like `axum::Json`: it is both an extractor and a response.
+//! (De)serialization goes through `serde` and `rmp-serde`; structs are encoded as maps with
+//! named fields so that the payload matches the documented schema.
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request, rejection::BytesRejection},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
+use rmp_serde::decode::Error as DecodeError;
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// MessagePack body extractor and response.
+///
+/// The extractor does not check `Content-Type` — `#[RequestBody(format(msgpack))]` only calls
+/// it after the content type was negotiated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MsgPack<T>(pub T);
+
+/// Rejection of the [`MsgPack`] extractor.
+#[derive(Debug, Error)]
+pub enum MsgPackRejection {
+    #[error(transparent)]
+    BytesRejection(#[from] BytesRejection),
+
+    /// The body is not well-formed MessagePack.
+    #[error("Failed to parse the MessagePack body: {0}")]
+    Syntax(DecodeError),
+
+    /// The body is valid MessagePack that does not fit the target type.
+    #[error("Failed to deserialize the MessagePack body into the target type: {0}")]
+    Deserialize(DecodeError),
+}
+
+impl From<DecodeError> for MsgPackRejection {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::InvalidMarkerRead(_)
+            | DecodeError::InvalidDataRead(_)
+            | DecodeError::Utf8Error(_)
+            | DecodeError::DepthLimitExceeded => MsgPackRejection::Syntax(e),
+            _ => MsgPackRejection::Deserialize(e),
+        }
+    }
+}
+
+impl IntoResponse for MsgPackRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            MsgPackRejection::BytesRejection(r) => r.into_response(),
+            MsgPackRejection::Syntax(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            MsgPackRejection::Deserialize(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for MsgPack<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = MsgPackRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state).await?;
+
+        Ok(MsgPack(rmp_serde::from_slice(&bytes)?))
+    }
+}
+
+impl<T: Serialize> IntoResponse for MsgPack<T> {
+    fn into_response(self) -> axum::response::Response {
+        match rmp_serde::to_vec_named(&self.0) {
+            Ok(body) => ([(CONTENT_TYPE, mime::APPLICATION_MSGPACK.as_ref())], body).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Person {
+        name: String,
+    }
+
+    #[test]
+    fn missing_field_is_a_deserialize_error() {
+        let body = rmp_serde::to_vec_named(&serde_json::json!({})).unwrap();
+
+        let e: MsgPackRejection = rmp_serde::from_slice::<Person>(&body).unwrap_err().into();
+        assert!(matches!(e, MsgPackRejection::Deserialize(_)), "{e:?}");
+    }
+
+    #[test]
+    fn truncated_body_is_a_syntax_error() {
+        let e: MsgPackRejection = rmp_serde::from_slice::<Person>(&[0x81]).unwrap_err().into();
+        assert!(matches!(e, MsgPackRejection::Syntax(_)), "{e:?}");
+    }
+}
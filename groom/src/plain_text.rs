@@ -0,0 +1,111 @@
+//! `text/plain` request bodies for `format(plain_text)`.
+//!
+//! [`PlainText`] decodes the body according to the `charset` parameter of `Content-Type` and
+//! parses the text with [`FromStr`].
+
+use std::{fmt::Display, str::FromStr};
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request, rejection::BytesRejection},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use thiserror::Error;
+
+use crate::content_negotiation::parse_content_type_header;
+
+/// `text/plain` body extractor for types implementing [`FromStr`].
+///
+/// Supported charsets are `utf-8` (the default when `charset` is absent), `us-ascii` and
+/// `iso-8859-1`. The text is passed to `FromStr` as is, without trimming.
+///
+/// The extractor does not check the media type — `#[RequestBody(format(plain_text))]` only calls
+/// it after the content type was negotiated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainText<T>(pub T);
+
+/// Rejection of the [`PlainText`] extractor.
+#[derive(Debug, Error)]
+pub enum PlainTextRejection {
+    #[error(transparent)]
+    BytesRejection(#[from] BytesRejection),
+
+    #[error("Unsupported charset of the text/plain body: {0}")]
+    UnsupportedCharset(String),
+
+    #[error("text/plain body is not valid {0}")]
+    InvalidEncoding(&'static str),
+
+    /// `FromStr` of the target type rejected the text.
+    #[error("Failed to parse the text/plain body: {0}")]
+    Parse(String),
+}
+
+impl IntoResponse for PlainTextRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            PlainTextRejection::BytesRejection(r) => r.into_response(),
+            _ => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for PlainText<T>
+where
+    T: FromStr,
+    T::Err: Display,
+    S: Send + Sync,
+{
+    type Rejection = PlainTextRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let charset = parse_content_type_header(req.headers())
+            .ok()
+            .flatten()
+            .and_then(|mime| mime.get_param(mime::CHARSET).map(|c| c.as_str().to_ascii_lowercase()));
+
+        let bytes = Bytes::from_request(req, state).await?;
+        let text = decode(&bytes, charset.as_deref())?;
+
+        text.parse()
+            .map(PlainText)
+            .map_err(|e: T::Err| PlainTextRejection::Parse(e.to_string()))
+    }
+}
+
+/// Decodes `bytes` in the given (lowercase) charset.
+fn decode(bytes: &[u8], charset: Option<&str>) -> Result<String, PlainTextRejection> {
+    match charset {
+        None | Some("utf-8") | Some("utf8") => std::str::from_utf8(bytes)
+            .map(str::to_owned)
+            .map_err(|_| PlainTextRejection::InvalidEncoding("utf-8")),
+
+        Some("us-ascii") | Some("ascii") => match bytes.is_ascii() {
+            true => Ok(bytes.iter().map(|&b| b as char).collect()),
+            false => Err(PlainTextRejection::InvalidEncoding("us-ascii")),
+        },
+
+        // latin-1 bytes are the first 256 unicode code points
+        Some("iso-8859-1") | Some("latin1") => Ok(bytes.iter().map(|&b| b as char).collect()),
+
+        Some(other) => Err(PlainTextRejection::UnsupportedCharset(other.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_latin1() {
+        assert_eq!(decode(&[0x63, 0x61, 0x66, 0xe9], Some("iso-8859-1")).unwrap(), "café");
+    }
+
+    #[test]
+    fn rejects_invalid_utf8_and_unknown_charsets() {
+        assert!(matches!(decode(&[0xe9], None), Err(PlainTextRejection::InvalidEncoding("utf-8"))));
+        assert!(matches!(decode(&[0xe9], Some("us-ascii")), Err(PlainTextRejection::InvalidEncoding("us-ascii"))));
+        assert!(matches!(decode(b"x", Some("koi8-r")), Err(PlainTextRejection::UnsupportedCharset(_))));
+    }
+}
@@ -0,0 +1,190 @@
+//! Binary response bodies for `#[Response(format(binary))]`.
+//!
+//! A binary payload is anything implementing [`BinaryFormat`]: raw bytes, a streamed [`Body`] or
+//! a [`tokio::fs::File`]. Wrap it in [`Binary`] to send a `Content-Disposition` file name along.
+
+use axum::{
+    body::{Body, Bytes},
+    http::{HeaderValue, header::{CONTENT_DISPOSITION, CONTENT_TYPE}},
+    response::IntoResponse,
+};
+use tokio_util::io::ReaderStream;
+use utoipa::openapi::{KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, schema::{SchemaType, Type}};
+use utoipa::{PartialSchema, ToSchema};
+
+/// Payload of a `binary` response.
+pub trait BinaryFormat {
+    fn into_binary(self) -> Binary;
+}
+
+/// Binary response body with optional `Content-Disposition` metadata.
+///
+/// ```ignore
+/// #[Response(format(binary = "application/pdf"))]
+/// pub enum ExportResponse {
+///     #[Response()]
+///     Ok(Binary),
+/// }
+///
+/// ExportResponse::Ok(Binary::new(pdf_bytes).attachment("report.pdf"))
+/// ```
+#[derive(Debug)]
+pub struct Binary {
+    body: Body,
+    disposition: Option<(Disposition, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Disposition {
+    Inline,
+    Attachment,
+}
+
+impl Binary {
+    pub fn new(body: impl Into<Body>) -> Self {
+        Self {
+            body: body.into(),
+            disposition: None,
+        }
+    }
+
+    /// Asks the client to save the body as `file_name` (`Content-Disposition: attachment`).
+    pub fn attachment(mut self, file_name: impl Into<String>) -> Self {
+        self.disposition = Some((Disposition::Attachment, file_name.into()));
+        self
+    }
+
+    /// Asks the client to display the body, suggesting `file_name` should it be saved
+    /// (`Content-Disposition: inline`).
+    pub fn inline(mut self, file_name: impl Into<String>) -> Self {
+        self.disposition = Some((Disposition::Inline, file_name.into()));
+        self
+    }
+
+    /// Builds the response with the given `Content-Type`; used by `#[Response]` formatters.
+    pub fn into_response_with_content_type(self, content_type: &'static str) -> axum::response::Response {
+        let mut response = self.body.into_response();
+        let headers = response.headers_mut();
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+
+        if let Some((disposition, file_name)) = &self.disposition {
+            headers.insert(CONTENT_DISPOSITION, content_disposition(*disposition, file_name));
+        }
+
+        response
+    }
+}
+
+/// `Content-Disposition` value with a quoted ASCII `filename` and, for names that need it, a
+/// UTF-8 `filename*` (RFC 6266, RFC 8187).
+fn content_disposition(disposition: Disposition, file_name: &str) -> HeaderValue {
+    let kind = match disposition {
+        Disposition::Inline => "inline",
+        Disposition::Attachment => "attachment",
+    };
+
+    let is_quotable = |c: char| c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\';
+
+    let fallback: String = file_name.chars()
+        .map(|c| if is_quotable(c) { c } else { '_' })
+        .collect();
+
+    let value = match file_name.chars().all(is_quotable) {
+        true => format!("{kind}; filename=\"{fallback}\""),
+        false => format!("{kind}; filename=\"{fallback}\"; filename*=UTF-8''{}", percent_encode(file_name)),
+    };
+
+    HeaderValue::from_str(&value).expect("value consists of visible ASCII only")
+}
+
+/// Percent-encodes everything but RFC 8187 `attr-char`.
+fn percent_encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9'
+            | b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' =>
+                result.push(byte as char),
+
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    result
+}
+
+impl BinaryFormat for Binary {
+    fn into_binary(self) -> Binary {
+        self
+    }
+}
+
+impl BinaryFormat for Bytes {
+    fn into_binary(self) -> Binary {
+        Binary::new(self)
+    }
+}
+
+impl BinaryFormat for Vec<u8> {
+    fn into_binary(self) -> Binary {
+        Binary::new(self)
+    }
+}
+
+impl BinaryFormat for &'static [u8] {
+    fn into_binary(self) -> Binary {
+        Binary::new(self)
+    }
+}
+
+impl BinaryFormat for Body {
+    fn into_binary(self) -> Binary {
+        Binary::new(self)
+    }
+}
+
+/// The file is streamed from its current position.
+impl BinaryFormat for tokio::fs::File {
+    fn into_binary(self) -> Binary {
+        Binary::new(Body::from_stream(ReaderStream::new(self)))
+    }
+}
+
+impl PartialSchema for Binary {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Type(Type::String))
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)))
+            .into()
+    }
+}
+
+impl ToSchema for Binary {}
+
+/// Parses the mime of `format(binary = "...")`; validated by `#[Response]` at expand time.
+pub fn binary_mime(mime: &'static str) -> mime::Mime {
+    mime.parse().expect("binary response mime is validated by #[Response]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_file_name_is_quoted() {
+        assert_eq!(
+            content_disposition(Disposition::Attachment, "report 2024.pdf"),
+            "attachment; filename=\"report 2024.pdf\"",
+        );
+    }
+
+    #[test]
+    fn non_ascii_file_name_gets_utf8_variant() {
+        assert_eq!(
+            content_disposition(Disposition::Inline, "отчёт \"1\".pdf"),
+            "inline; filename=\"_____ _1_.pdf\"; filename*=UTF-8''%D0%BE%D1%82%D1%87%D1%91%D1%82%20%221%22.pdf",
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Response compression negotiated from `Accept-Encoding`.
+//!
+//! Opt-in per `#[Response]` type with `#[Response(compress)]`, or for every route of a router
+//! with `router.layer_with_spec(Compression::new())`. The codings are the ones enabled by the
+//! `gzip`, `deflate`, `br` and `zstd` cargo features.
+//!
+//! Only bodies of a known size of at least [`Compression::min_size`] bytes are compressed:
+//! streamed bodies (event streams, NDJSON, files) are sent as is, since compressing them here
+//! would buffer the whole stream. Only text content types are compressed: `text/*`, JSON, XML and
+//! JavaScript, and CBOR and MessagePack with [`Compression::binary_formats`]. Responses with
+//! `Cache-Control: no-transform` are sent as is. Responses that may be compressed carry
+//! `Vary: Accept-Encoding`. Bodies of 64 KiB and more are encoded on tokio's blocking pool.
+
+use axum::{
+    body::{Body, HttpBody},
+    extract::{Request, State},
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{ACCEPT_ENCODING, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
+    },
+    middleware::Next,
+    response::IntoResponse,
+};
+use http_body_util::BodyExt;
+use utoipa::openapi::{
+    HeaderBuilder, ObjectBuilder, RefOr, Type,
+    path::{HttpMethod, Operation},
+};
+
+use crate::{response::append_vary, router::OpenApiSpecLayer};
+
+/// Smallest body compressed by default; smaller bodies gain little from it.
+pub const DEFAULT_MIN_SIZE: usize = 1024;
+
+/// Smallest body compressed on tokio's blocking pool; encoding larger bodies would hold up an
+/// async worker, while smaller ones cost less than handing them over.
+const BLOCKING_MIN_SIZE: usize = 64 * 1024;
+
+/// Codings in the order they are preferred when the client accepts several with the same weight.
+const PREFERENCE: &[&str] = &[
+    #[cfg(feature = "zstd")]
+    "zstd",
+    #[cfg(feature = "br")]
+    "br",
+    #[cfg(feature = "gzip")]
+    "gzip",
+    #[cfg(feature = "deflate")]
+    "deflate",
+];
+
+/// Response compression settings.
+///
+/// Also an [`OpenApiSpecLayer`] compressing every response of a router and documenting the
+/// `Content-Encoding` response header of its operations.
+#[derive(Debug, Clone, Copy)]
+pub struct Compression {
+    min_size: usize,
+    binary_formats: bool,
+}
+
+impl Compression {
+    pub const fn new() -> Self {
+        Self { min_size: DEFAULT_MIN_SIZE, binary_formats: false }
+    }
+
+    /// Smallest body size in bytes that is compressed ([`DEFAULT_MIN_SIZE`] by default).
+    pub const fn min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Whether CBOR and MessagePack bodies are compressed too (not by default: they are compact
+    /// already).
+    pub const fn binary_formats(mut self, binary_formats: bool) -> Self {
+        self.binary_formats = binary_formats;
+        self
+    }
+
+    /// Compresses the response with `coding` (negotiated by [`negotiate_encoding`]) when it is
+    /// eligible; used by controller wrappers of `#[Response(compress)]` types.
+    pub async fn compress(&self, coding: Option<&'static str>, response: axum::response::Response) -> axum::response::Response {
+        if PREFERENCE.is_empty() || !self.is_eligible(&response) {
+            return response;
+        }
+
+        let (mut parts, body) = response.into_parts();
+
+        append_vary(&mut parts.headers, "accept-encoding");
+
+        let Some(coding) = coding else {
+            return axum::response::Response::from_parts(parts, body);
+        };
+
+        if body.size_hint().exact().is_none_or(|size| size < self.min_size as u64) {
+            return axum::response::Response::from_parts(parts, body);
+        }
+
+        let bytes = match body.collect().await {
+            Ok(collected) => collected.to_bytes(),
+            Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        };
+
+        let encoded = match bytes.len() < BLOCKING_MIN_SIZE {
+            true => encode(coding, &bytes),
+            false => {
+                let input = bytes.clone();
+
+                match tokio::task::spawn_blocking(move || encode(coding, &input)).await {
+                    Ok(encoded) => encoded,
+                    Err(e) => std::panic::resume_unwind(e.into_panic()),
+                }
+            },
+        };
+
+        match encoded {
+            Ok(encoded) => {
+                parts.headers.insert(CONTENT_ENCODING, HeaderValue::from_static(coding));
+                parts.headers.remove(CONTENT_LENGTH);
+
+                axum::response::Response::from_parts(parts, Body::from(encoded))
+            },
+            Err(_) => axum::response::Response::from_parts(parts, Body::from(bytes)),
+        }
+    }
+
+    /// Documents the `Content-Encoding` header of a response whose content may be compressed.
+    pub fn document_response(&self, response: &mut utoipa::openapi::Response) {
+        if PREFERENCE.is_empty() || !response.content.keys().any(|mime| self.is_compressible(mime)) {
+            return;
+        }
+
+        let schema = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .enum_values(Some(PREFERENCE.iter().copied()))
+            .build();
+
+        response.headers.insert(
+            "Content-Encoding".to_owned(),
+            HeaderBuilder::new()
+                .schema(schema)
+                .description(Some(format!(
+                    "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least {} bytes",
+                    self.min_size,
+                )))
+                .build(),
+        );
+    }
+
+    fn is_eligible(&self, response: &axum::response::Response) -> bool {
+        let headers = response.headers();
+
+        !headers.contains_key(CONTENT_ENCODING) && !is_no_transform(headers) && headers.get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| self.is_compressible(content_type))
+    }
+
+    /// Content types worth compressing: text ones, not streamed, and the binary formats when
+    /// enabled.
+    fn is_compressible(&self, content_type: &str) -> bool {
+        let Ok(mime) = content_type.parse::<mime::Mime>() else {
+            return false;
+        };
+
+        match (mime.type_(), mime.subtype().as_str()) {
+            (mime::TEXT, "event-stream") => false,
+            (mime::TEXT, _) => true,
+            (mime::APPLICATION, "json" | "xml" | "javascript" | "x-javascript" | "ecmascript") => true,
+            (mime::APPLICATION, "cbor" | "msgpack" | "x-msgpack") => self.binary_formats,
+            _ => mime.suffix().is_some_and(|suffix| matches!(suffix.as_str(), "json" | "xml")),
+        }
+    }
+}
+
+/// Whether `Cache-Control` forbids intermediaries to transform the body (RFC 9111, section
+/// 5.2.2.6); compressing it here would change what the origin sent.
+fn is_no_transform(headers: &HeaderMap) -> bool {
+    headers.get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-transform"))
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenApiSpecLayer for Compression {
+    fn modify_operation(&self, _path: &str, _method: &HttpMethod, operation: &mut Operation) {
+        for response in operation.responses.responses.values_mut() {
+            if let RefOr::T(response) = response {
+                self.document_response(response);
+            }
+        }
+    }
+
+    fn mount<S>(&self, r: axum::Router<S>) -> axum::Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        r.layer(axum::middleware::from_fn_with_state(*self, compress_middleware))
+    }
+}
+
+async fn compress_middleware(State(compression): State<Compression>, req: Request, next: Next) -> axum::response::Response {
+    let coding = negotiate_encoding(req.headers());
+
+    compression.compress(coding, next.run(req).await).await
+}
+
+/// Picks the enabled coding with the highest weight in `Accept-Encoding`; `None` when the client
+/// accepts none of them (or sends no `Accept-Encoding`).
+pub fn negotiate_encoding(headers: &HeaderMap) -> Option<&'static str> {
+    let accepted = accepted_codings(headers);
+
+    let weight = |coding: &str| {
+        accepted.iter().find(|(c, _)| c == coding)
+            .or_else(|| accepted.iter().find(|(c, _)| c == "*"))
+            .map_or(0.0, |(_, q)| *q)
+    };
+
+    let mut best: Option<(&'static str, f32)> = None;
+
+    for coding in PREFERENCE {
+        let q = weight(coding);
+
+        if q > 0.0 && best.is_none_or(|(_, best_q)| q > best_q) {
+            best = Some((coding, q));
+        }
+    }
+
+    best.map(|(coding, _)| coding)
+}
+
+/// Lowercased codings of `Accept-Encoding` with their weights; entries with a malformed weight
+/// are ignored.
+fn accepted_codings(headers: &HeaderMap) -> Vec<(String, f32)> {
+    headers.get_all(ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|entry| {
+            let mut params = entry.split(';');
+            let coding = params.next()?.trim().to_ascii_lowercase();
+
+            let q = match params.find_map(|p| p.trim().strip_prefix("q=")) {
+                Some(q) => q.trim().parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))?,
+                None => 1.0,
+            };
+
+            (!coding.is_empty()).then_some((coding, q))
+        })
+        .collect()
+}
+
+#[cfg_attr(not(any(feature = "gzip", feature = "deflate", feature = "br", feature = "zstd")), allow(unused_variables))]
+fn encode(coding: &str, input: &[u8]) -> std::io::Result<Vec<u8>> {
+    #[cfg(any(feature = "gzip", feature = "deflate", feature = "br"))]
+    use std::io::Write;
+
+    match coding {
+        #[cfg(feature = "gzip")]
+        "gzip" => {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(input)?;
+            encoder.finish()
+        },
+
+        // `deflate` in HTTP is the zlib format (RFC 9110, section 8.4.1.2)
+        #[cfg(feature = "deflate")]
+        "deflate" => {
+            let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(input)?;
+            encoder.finish()
+        },
+
+        // quality 11 (the default) is meant for static assets, too slow per request
+        #[cfg(feature = "br")]
+        "br" => {
+            let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 5, 22);
+            encoder.write_all(input)?;
+            Ok(encoder.into_inner())
+        },
+
+        #[cfg(feature = "zstd")]
+        "zstd" => zstd::stream::encode_all(input, 0),
+
+        _ => Err(std::io::Error::other(format!("unsupported coding `{coding}`"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept_encoding(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[cfg(all(feature = "gzip", feature = "br", feature = "zstd"))]
+    #[test]
+    fn coding_with_highest_weight_is_picked() {
+        assert_eq!(negotiate_encoding(&accept_encoding("gzip, br, zstd")), Some("zstd"));
+        assert_eq!(negotiate_encoding(&accept_encoding("gzip, br;q=0.5")), Some("gzip"));
+        assert_eq!(negotiate_encoding(&accept_encoding("*;q=0.1, br;q=0.2")), Some("br"));
+        assert_eq!(negotiate_encoding(&accept_encoding("*, zstd;q=0, br;q=0")), Some("gzip"));
+    }
+
+    #[test]
+    fn unknown_codings_are_not_negotiated() {
+        assert_eq!(negotiate_encoding(&HeaderMap::new()), None);
+        assert_eq!(negotiate_encoding(&accept_encoding("identity, compress")), None);
+        assert_eq!(negotiate_encoding(&accept_encoding("gzip;q=2")), None);
+    }
+
+    #[test]
+    fn only_text_types_are_compressed() {
+        let compression = Compression::new();
+
+        assert!(compression.is_compressible("application/json"));
+        assert!(compression.is_compressible("application/problem+json"));
+        assert!(compression.is_compressible("text/html; charset=utf-8"));
+        assert!(compression.is_compressible("image/svg+xml"));
+        assert!(compression.is_compressible("application/javascript"));
+
+        assert!(!compression.is_compressible("image/png"));
+        assert!(!compression.is_compressible("application/zip"));
+        assert!(!compression.is_compressible("application/octet-stream"));
+        assert!(!compression.is_compressible("application/pdf"));
+        assert!(!compression.is_compressible("application/x-ndjson"));
+        assert!(!compression.is_compressible("text/event-stream"));
+        assert!(!compression.is_compressible("application/cbor"));
+        assert!(!compression.is_compressible("not a mime"));
+
+        assert!(compression.binary_formats(true).is_compressible("application/cbor"));
+        assert!(compression.binary_formats(true).is_compressible("application/msgpack"));
+    }
+
+    #[test]
+    fn no_transform_is_detected() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_no_transform(&headers));
+
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=60, No-Transform"));
+        assert!(is_no_transform(&headers));
+    }
+}
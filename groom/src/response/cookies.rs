@@ -0,0 +1,106 @@
+//! Typed response cookies declared with `#[Response(cookie(name = "..."))]` fields.
+//!
+//! ```ignore
+//! #[Response()]
+//! pub enum LoginResponse {
+//!     /// Logged in
+//!     #[Response(code = 204)]
+//!     LoggedIn {
+//!         /// Session token
+//!         #[Response(cookie(name = "session", path = "/", http_only, secure, same_site = "lax", max_age = 3600))]
+//!         session: String,
+//!     },
+//! }
+//! ```
+//!
+//! Cookie attributes are checked and rendered by `#[Response]` at expand time; only the value is
+//! checked at runtime.
+
+use axum::http::HeaderValue;
+
+use crate::response::headers::InvalidResponseHeader;
+
+/// Type of a response cookie field.
+pub trait ResponseCookie {
+    /// Value of the cookie; `None` leaves the cookie out.
+    fn cookie_value(&self) -> Option<String>;
+}
+
+impl ResponseCookie for String {
+    fn cookie_value(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+impl ResponseCookie for &'static str {
+    fn cookie_value(&self) -> Option<String> {
+        Some((*self).to_owned())
+    }
+}
+
+macro_rules! integer_response_cookie {
+    ($($ty:ty),*) => {
+        $(
+            impl ResponseCookie for $ty {
+                fn cookie_value(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+integer_response_cookie!(u16, u32, u64, usize, i16, i32, i64, isize);
+
+/// `None` leaves the cookie out.
+impl<T: ResponseCookie> ResponseCookie for Option<T> {
+    fn cookie_value(&self) -> Option<String> {
+        self.as_ref().and_then(ResponseCookie::cookie_value)
+    }
+}
+
+/// Builds a `Set-Cookie` value; used by `#[Response]` formatters.
+///
+/// `attributes` is the `; Path=/; HttpOnly`-like suffix rendered at expand time. A value with
+/// characters outside of RFC 6265 `cookie-octet` is rejected rather than quoted or encoded, so
+/// it cannot smuggle attributes in.
+pub fn set_cookie(
+    name: &'static str,
+    value: Option<String>,
+    attributes: &'static str,
+) -> Result<Option<HeaderValue>, InvalidResponseHeader> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    if !value.bytes().all(is_cookie_octet) {
+        return Err(InvalidResponseHeader::CookieValue(name));
+    }
+
+    Ok(Some(HeaderValue::from_str(&format!("{name}={value}{attributes}"))?))
+}
+
+/// RFC 6265 `cookie-octet`: visible ASCII except `"`, `,`, `;` and `\`.
+fn is_cookie_octet(byte: u8) -> bool {
+    matches!(byte, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cookie_is_rendered_with_attributes() {
+        let value = set_cookie("session", "abc123".cookie_value(), "; Path=/; HttpOnly").unwrap();
+
+        assert_eq!(value.unwrap(), "session=abc123; Path=/; HttpOnly");
+        assert!(set_cookie("session", None::<String>.cookie_value(), "").unwrap().is_none());
+    }
+
+    #[test]
+    fn value_cannot_add_attributes() {
+        let error = set_cookie("session", Some("abc; Domain=example.com".into()), "").unwrap_err();
+
+        assert!(matches!(error, InvalidResponseHeader::CookieValue("session")));
+    }
+}
@@ -0,0 +1,196 @@
+//! Server-Sent Events for `#[Response(format(event_stream))]`.
+//!
+//! A handler returns [`Sse<T>`], a stream of typed [`Event<T>`]s. Each event payload is sent as
+//! JSON in the `data` field, and OpenAPI documents `text/event-stream` with the schema of `T`.
+//!
+//! ```ignore
+//! #[Response(format(event_stream))]
+//! pub enum TaskEventsResponse {
+//!     #[Response()]
+//!     Ok(Sse<TaskEvent>),
+//! }
+//!
+//! TaskEventsResponse::Ok(Sse::new(events.map(|e| Event::new(e).event("task"))))
+//! ```
+
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::Duration,
+};
+
+use axum::response::{IntoResponse, sse::{self, KeepAlive}};
+use futures_core::Stream;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Payload of an `event_stream` response.
+pub trait EventStreamFormat {
+    /// Type of the `data` of a single event, documented in OpenAPI.
+    type Data: ToSchema + 'static;
+
+    fn into_event_stream(self) -> axum::response::Response;
+}
+
+/// Single Server-Sent Event with a typed payload.
+#[derive(Debug, Clone)]
+pub struct Event<T> {
+    data: T,
+    event: Option<String>,
+    id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl<T> Event<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            event: None,
+            id: None,
+            retry: None,
+        }
+    }
+
+    /// Event name (`event:`); browsers fire `message` for events without one.
+    pub fn event(mut self, name: impl Into<String>) -> Self {
+        self.event = Some(name.into());
+        self
+    }
+
+    /// Event id (`id:`), sent back by reconnecting clients in `Last-Event-ID`.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Reconnection delay hint (`retry:`).
+    pub fn retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+}
+
+impl<T> From<T> for Event<T> {
+    fn from(data: T) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T: Serialize> Event<T> {
+    /// An event that can't be sent ends the stream instead of panicking in axum.
+    fn into_axum_event(self) -> Result<sse::Event, axum::Error> {
+        let is_line = |value: &str| !value.contains(['\r', '\n', '\0']);
+
+        let mut event = sse::Event::default();
+
+        if let Some(name) = &self.event {
+            if !is_line(name) {
+                return Err(axum::Error::new("SSE event name must be a single line"));
+            }
+
+            event = event.event(name);
+        }
+
+        if let Some(id) = &self.id {
+            if !is_line(id) {
+                return Err(axum::Error::new("SSE event id must be a single line without null characters"));
+            }
+
+            event = event.id(id);
+        }
+
+        if let Some(retry) = self.retry {
+            event = event.retry(retry);
+        }
+
+        event.json_data(&self.data)
+    }
+}
+
+/// Stream of Server-Sent Events.
+pub struct Sse<T> {
+    events: Pin<Box<dyn Stream<Item = Event<T>> + Send>>,
+    keep_alive: Option<KeepAlive>,
+}
+
+impl<T> Sse<T> {
+    pub fn new(events: impl Stream<Item = Event<T>> + Send + 'static) -> Self {
+        Self {
+            events: Box::pin(events),
+            keep_alive: None,
+        }
+    }
+
+    /// Sends comments while no events are produced, so idle connections are not dropped by
+    /// proxies.
+    pub fn keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+}
+
+impl<T: Serialize + ToSchema + 'static> EventStreamFormat for Sse<T> {
+    type Data = T;
+
+    fn into_event_stream(self) -> axum::response::Response {
+        let events = AxumEvents { events: self.events };
+
+        match self.keep_alive {
+            Some(keep_alive) => sse::Sse::new(events).keep_alive(keep_alive).into_response(),
+            None => sse::Sse::new(events).into_response(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sse<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sse")
+            .field("keep_alive", &self.keep_alive.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Typed events rendered for `axum::response::Sse`.
+struct AxumEvents<T> {
+    events: Pin<Box<dyn Stream<Item = Event<T>> + Send>>,
+}
+
+impl<T: Serialize> Stream for AxumEvents<T> {
+    type Item = Result<sse::Event, axum::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let event = ready!(self.events.as_mut().poll_next(cx));
+
+        Poll::Ready(event.map(Event::into_axum_event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_fields_are_rendered() {
+        let event = Event::new(vec![1, 2])
+            .event("tasks")
+            .id("7")
+            .retry(Duration::from_secs(3))
+            .into_axum_event()
+            .unwrap();
+
+        assert_eq!(format!("{event:?}"), format!("{:?}", sse::Event::default()
+            .event("tasks")
+            .id("7")
+            .retry(Duration::from_secs(3))
+            .json_data([1, 2])
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    fn multiline_event_name_is_an_error() {
+        assert!(Event::new(1).event("a\nb").into_axum_event().is_err());
+        assert!(Event::new(1).id("a\0").into_axum_event().is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! Typed response headers declared with `#[Response(header(name = "..."))]` fields.
+//!
+//! ```ignore
+//! #[Response(format(json))]
+//! pub enum CreateTaskResponse {
+//!     /// Task is created
+//!     #[Response(code = 201)]
+//!     Created {
+//!         /// URL of the new task
+//!         #[Response(header(name = "Location"))]
+//!         location: String,
+//!
+//!         task: Task,
+//!     },
+//! }
+//! ```
+
+use axum::http::{
+    HeaderMap, HeaderName, HeaderValue, StatusCode, Uri,
+    header::InvalidHeaderValue,
+};
+use axum::response::IntoResponse;
+use thiserror::Error;
+use utoipa::{
+    PartialSchema,
+    openapi::{ObjectBuilder, RefOr, Schema, SchemaFormat, schema::{SchemaType, Type}},
+};
+
+/// Type of a response header field.
+pub trait ResponseHeader {
+    /// Value of the header; `Ok(None)` leaves the header out.
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue>;
+
+    /// Schema of the header in OpenAPI.
+    fn header_schema() -> RefOr<Schema>;
+}
+
+impl ResponseHeader for String {
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+        HeaderValue::from_str(self).map(Some)
+    }
+
+    fn header_schema() -> RefOr<Schema> {
+        String::schema()
+    }
+}
+
+impl ResponseHeader for &'static str {
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+        HeaderValue::from_str(self).map(Some)
+    }
+
+    fn header_schema() -> RefOr<Schema> {
+        String::schema()
+    }
+}
+
+impl ResponseHeader for HeaderValue {
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+        Ok(Some(self.clone()))
+    }
+
+    fn header_schema() -> RefOr<Schema> {
+        String::schema()
+    }
+}
+
+impl ResponseHeader for Uri {
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+        HeaderValue::from_str(&self.to_string()).map(Some)
+    }
+
+    fn header_schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Type(Type::String))
+            .format(Some(SchemaFormat::Custom("uri-reference".to_owned())))
+            .into()
+    }
+}
+
+macro_rules! integer_response_header {
+    ($($ty:ty),*) => {
+        $(
+            impl ResponseHeader for $ty {
+                fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+                    Ok(Some(HeaderValue::from(*self)))
+                }
+
+                fn header_schema() -> RefOr<Schema> {
+                    <$ty>::schema()
+                }
+            }
+        )*
+    };
+}
+
+integer_response_header!(u16, u32, u64, usize, i16, i32, i64, isize);
+
+/// `None` leaves the header out.
+impl<T: ResponseHeader> ResponseHeader for Option<T> {
+    fn header_value(&self) -> Result<Option<HeaderValue>, InvalidHeaderValue> {
+        match self {
+            Some(value) => value.header_value(),
+            None => Ok(None),
+        }
+    }
+
+    fn header_schema() -> RefOr<Schema> {
+        T::header_schema()
+    }
+}
+
+/// Header or cookie field value that cannot be sent.
+#[derive(Debug, Error)]
+pub enum InvalidResponseHeader {
+    #[error(transparent)]
+    Value(#[from] InvalidHeaderValue),
+
+    #[error("value of `{0}` cookie has characters not allowed in cookies")]
+    CookieValue(&'static str),
+}
+
+/// A value that is not a valid header value is a bug of the handler, so it is answered with 500
+/// instead of sending a response without the declared header.
+impl IntoResponse for InvalidResponseHeader {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
+    }
+}
+
+/// Collects header and cookie fields of a response; used by `#[Response]` formatters.
+///
+/// Values are appended, so each cookie gets its own `Set-Cookie` header. The first invalid value
+/// is returned as the error.
+pub fn response_headers<const N: usize>(
+    values: [(HeaderName, Result<Option<HeaderValue>, InvalidResponseHeader>); N]
+) -> Result<HeaderMap, InvalidResponseHeader> {
+    let mut headers = HeaderMap::with_capacity(N);
+
+    for (name, value) in values {
+        match value {
+            Ok(Some(value)) => {
+                headers.append(name, value);
+            },
+            Ok(None) => {},
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_headers_are_left_out() {
+        let headers = response_headers([
+            (HeaderName::from_static("location"), "/tasks/1".header_value().map_err(Into::into)),
+            (HeaderName::from_static("x-total-count"), Some(42u32).header_value().map_err(Into::into)),
+            (HeaderName::from_static("retry-after"), None::<u32>.header_value().map_err(Into::into)),
+        ]).unwrap();
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["location"], "/tasks/1");
+        assert_eq!(headers["x-total-count"], "42");
+    }
+
+    #[test]
+    fn invalid_value_is_internal_server_error() {
+        let response = response_headers([
+            (HeaderName::from_static("location"), String::from("/tasks/\n1").header_value().map_err(Into::into)),
+        ]).unwrap_err().into_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
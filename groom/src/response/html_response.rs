@@ -1,7 +1,37 @@
 use axum::{body::Body, response::Html};
 
+use crate::locale::Locale;
+
 pub trait HtmlFormat {
     fn render(self) -> Html<Body>;
+
+    /// Renders with the context of the request, e.g. in the negotiated [`Locale`].
+    ///
+    /// Defaults to [`HtmlFormat::render`]; override it for localized templates.
+    fn render_with(self, _ctx: &RenderContext) -> Html<Body>
+    where
+        Self: Sized,
+    {
+        self.render()
+    }
+}
+
+/// Request data available to templates through [`HtmlFormat::render_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderContext {
+    locale: Option<Locale>,
+}
+
+impl RenderContext {
+    pub fn new(locale: Option<Locale>) -> Self {
+        Self { locale }
+    }
+
+    /// Language negotiated by the [`Localization`](crate::locale::Localization) router layer;
+    /// `None` without it.
+    pub fn locale(&self) -> Option<Locale> {
+        self.locale
+    }
 }
 
 impl HtmlFormat for String {
@@ -45,6 +75,15 @@ impl HtmlFormat for &'static str {
 ///     Ok(HtmlOrJsonDataObject),
 /// }
 ///
+/// // 3a) Or a localized template, with the `RenderContext` of the request:
+///
+/// html_format!(HtmlOrJsonDataObject, self, ctx {
+///     match ctx.locale().map(|locale| locale.as_str()) {
+///         Some("de") => format!("<p>Zustand: {}.</p>", self.status),
+///         _ => format!("<p>Status: {}.</p>", self.status),
+///     }
+/// });
+///
 /// // 4) Return this DTO from a handler:
 ///
 /// #[Route(method = "get", path = "/html-or-json")]
@@ -70,6 +109,20 @@ macro_rules! html_format {
             }
         }
     };
+
+    ($ty:ty, $self:ident, $ctx:ident { $template:expr }) => {
+        impl ::groom::response::HtmlFormat for $ty {
+            fn render(self) -> ::axum::response::Html<axum::body::Body> {
+                self.render_with(&::groom::response::RenderContext::default())
+            }
+
+            fn render_with($self, $ctx: &::groom::response::RenderContext) -> ::axum::response::Html<axum::body::Body> {
+                ::axum::response::Html(
+                    $template.into()
+                )
+            }
+        }
+    };
 }
 
 pub use html_format;
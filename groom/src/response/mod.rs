@@ -10,16 +10,17 @@ pub trait Response {
 
     /// Consumes the pre-negotiated mime (produced by [`Response::__groom_negotiate_content_type`])
     /// into an HTTP response. `None` means no `Accept` header was sent — the `default_format`
-    /// applies, matching the previous no-`Accept` behavior.
-    fn __groom_into_response(self, negotiated: Option<&::mime::Mime>) -> ::axum::response::Response;
+    /// applies, matching the previous no-`Accept` behavior. `ctx` is passed to
+    /// [`HtmlFormat::render_with`].
+    fn __groom_into_response(self, negotiated: Option<&::mime::Mime>, ctx: &RenderContext) -> ::axum::response::Response;
 
     /// Negotiates the `Accept` header against this type's supported content types.
     /// Returns the negotiated mime on success (the single negotiation site per request),
-    /// or a ready-to-return response (406) when the request cannot be satisfied.
+    /// or [`NotAcceptable`] (406) when the request cannot be satisfied.
     ///
-    /// Success returns a `&'static Mime` into this type's supported-mime const — no clone.
+    /// Success returns a `&'static Mime` into this type's supported-mime static — no clone.
     fn __groom_negotiate_content_type(accept: &Accept)
-        -> ::core::result::Result<Option<&'static ::mime::Mime>, ::axum::response::Response>;
+        -> ::core::result::Result<Option<&'static ::mime::Mime>, NotAcceptable>;
 
     /// Performs runtime checks of response codes of this Response.
     /// Used to detect duplicated codes in composite types like Result<T, E>
@@ -29,6 +30,12 @@ pub trait Response {
     /// Used to detect format-list mismatches in composite types like Result<T, E>
     /// (both variants must support the same list of formats).
     fn __groom_check_response_formats(context: impl ::std::fmt::Display, formats: &mut HTTPFormatsSet);
+
+    /// Compression of this response (`#[Response(compress)]`), applied by the controller
+    /// wrapper after [`Response::__groom_into_response`].
+    fn __groom_compression(&self) -> Option<compression::Compression> {
+        None
+    }
 }
 
 /// Builds the 406 Not Acceptable response: `Vary: Accept`, text/plain body listing supported mimes.
@@ -50,17 +57,77 @@ pub fn not_acceptable(supported_mimes: &[::mime::Mime]) -> ::axum::response::Res
     ).into_response()
 }
 
+/// `Accept` header that matches none of the supported content types of a response.
+///
+/// Turns into the 406 response of [`not_acceptable`].
+#[derive(Debug, Clone, Copy)]
+pub struct NotAcceptable(pub &'static [::mime::Mime]);
+
+impl IntoResponse for NotAcceptable {
+    fn into_response(self) -> ::axum::response::Response {
+        not_acceptable(self.0)
+    }
+}
+
 /// Builds the 400 Bad Request response for a malformed Accept header (D-08 body text).
 pub fn bad_accept_header() -> ::axum::response::Response {
     (::axum::http::StatusCode::BAD_REQUEST, "Invalid Accept header.").into_response()
 }
 
+/// Appends `name` (lowercase) to `Vary` unless it is already listed, or `Vary: *` is sent.
+pub(crate) fn append_vary(headers: &mut ::axum::http::HeaderMap, name: &'static str) {
+    let listed = headers.get_all(::axum::http::header::VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|listed| listed.trim().eq_ignore_ascii_case(name) || listed.trim() == "*");
+
+    if !listed {
+        headers.append(::axum::http::header::VARY, ::axum::http::HeaderValue::from_static(name));
+    }
+}
+
+pub mod binary_response;
+pub use binary_response::{Binary, BinaryFormat};
+
+pub mod event_stream;
+pub use event_stream::{Event, EventStreamFormat, Sse};
+
+pub mod ndjson_response;
+pub use ndjson_response::{JsonLines, NdJsonFormat};
+
 pub mod html_response;
-pub use html_response::{HtmlFormat, html_format};
+pub use html_response::{HtmlFormat, RenderContext, html_format};
+
+pub mod headers;
+pub use headers::ResponseHeader;
+
+pub mod compression;
+pub use compression::Compression;
+
+pub mod cookies;
+pub use cookies::ResponseCookie;
 use crate::{extract::ComponentsRegistry, runtime_checks::{HTTPCodeSet, HTTPFormatsSet}};
 
 pub mod result;
 
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue, header::VARY};
+
+    #[test]
+    fn vary_is_appended_once() {
+        let mut headers = HeaderMap::new();
+        headers.insert(VARY, HeaderValue::from_static("accept"));
+
+        super::append_vary(&mut headers, "accept-encoding");
+        super::append_vary(&mut headers, "accept-encoding");
+
+        let vary: Vec<_> = headers.get_all(VARY).iter().collect();
+        assert_eq!(vary, ["accept", "accept-encoding"]);
+    }
+}
+
 #[cfg(test)]
 mod p006_negotiate_static_ref_gate {
     /// Structural gate (P006): negotiate must return Option<&'static Mime>, not owned Mime.
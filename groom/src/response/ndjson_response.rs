@@ -0,0 +1,128 @@
+//! Newline-delimited JSON response bodies for `#[Response(format(ndjson))]`.
+//!
+//! Items are serialized one by one while the body is sent, so a large list is never held in
+//! memory as a single JSON document. The body is polled by the connection, so a slow client
+//! slows down the item stream (backpressure).
+//!
+//! ```ignore
+//! #[Response(format(ndjson))]
+//! pub enum TaskStreamResponse {
+//!     #[Response()]
+//!     Ok(JsonLines<Task>),
+//! }
+//!
+//! TaskStreamResponse::Ok(JsonLines::new(repository.stream_tasks()))
+//! ```
+
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use axum::{
+    body::{Body, Bytes},
+    http::{HeaderValue, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
+use futures_core::Stream;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::content_negotiation::APPLICATION_NDJSON;
+
+/// Payload of an `ndjson` response.
+pub trait NdJsonFormat {
+    /// Type of a single line, documented in OpenAPI.
+    type Item: ToSchema + 'static;
+
+    fn into_ndjson(self) -> axum::response::Response;
+}
+
+/// Stream of items sent as newline-delimited JSON, one item per line.
+pub struct JsonLines<T> {
+    items: Pin<Box<dyn Stream<Item = T> + Send>>,
+}
+
+impl<T> JsonLines<T> {
+    pub fn new(items: impl Stream<Item = T> + Send + 'static) -> Self {
+        Self { items: Box::pin(items) }
+    }
+}
+
+impl<T: Serialize + ToSchema + 'static> NdJsonFormat for JsonLines<T> {
+    type Item = T;
+
+    fn into_ndjson(self) -> axum::response::Response {
+        let mut response = Body::from_stream(Lines { items: self.items }).into_response();
+
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static(APPLICATION_NDJSON.as_ref()));
+
+        response
+    }
+}
+
+/// The items are serialized lazily, one line at a time.
+impl<T: Serialize + ToSchema + Send + 'static> NdJsonFormat for Vec<T> {
+    type Item = T;
+
+    fn into_ndjson(self) -> axum::response::Response {
+        JsonLines::new(futures_util::stream::iter(self)).into_ndjson()
+    }
+}
+
+impl<T> fmt::Debug for JsonLines<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonLines").finish_non_exhaustive()
+    }
+}
+
+/// Serialized lines of a [`JsonLines`]; an item that fails to serialize ends the body with an
+/// error.
+struct Lines<T> {
+    items: Pin<Box<dyn Stream<Item = T> + Send>>,
+}
+
+impl<T: Serialize> Stream for Lines<T> {
+    type Item = Result<Bytes, serde_json::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.items.as_mut().poll_next(cx));
+
+        Poll::Ready(item.map(|item| {
+            let mut line = serde_json::to_vec(&item)?;
+            line.push(b'\n');
+
+            Ok(Bytes::from(line))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Waker;
+
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    fn poll_lines<T: Serialize>(lines: &mut Lines<T>) -> Poll<Option<Result<Bytes, serde_json::Error>>> {
+        Pin::new(lines).poll_next(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn items_are_sent_one_per_line() {
+        let mut lines = Lines { items: Box::pin(futures_util::stream::iter(vec![vec![1], vec![2, 3]])) };
+
+        assert_matches!(poll_lines(&mut lines), Poll::Ready(Some(Ok(line))) if line == "[1]\n");
+        assert_matches!(poll_lines(&mut lines), Poll::Ready(Some(Ok(line))) if line == "[2,3]\n");
+        assert_matches!(poll_lines(&mut lines), Poll::Ready(None));
+    }
+
+    #[test]
+    fn content_type_is_ndjson() {
+        let response = vec![1, 2].into_ndjson();
+
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/x-ndjson");
+    }
+}
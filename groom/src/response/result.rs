@@ -1,7 +1,7 @@
 use accept_header::Accept;
 use utoipa::openapi::path::OperationBuilder;
 use crate::extract::ComponentsRegistry;
-use crate::response::Response;
+use crate::response::{Compression, NotAcceptable, RenderContext, Response};
 use crate::runtime_checks::{HTTPCodeSet, HTTPFormatsSet};
 
 /// `Result<T, E>` as a [`Response`] when both arms implement [`Response`].
@@ -19,17 +19,24 @@ where T: Response, E: Response
     }
 
     fn __groom_negotiate_content_type(accept: &Accept)
-        -> ::core::result::Result<Option<&'static ::mime::Mime>, ::axum::response::Response>
+        -> ::core::result::Result<Option<&'static ::mime::Mime>, NotAcceptable>
     {
         // Format equality (below) proves Ok/Err share the same list, so E cannot
         // accept what T rejected. Negotiate once with T (P009 / D-26).
         T::__groom_negotiate_content_type(accept)
     }
 
-    fn __groom_into_response(self, negotiated: Option<&::mime::Mime>) -> axum::response::Response {
+    fn __groom_into_response(self, negotiated: Option<&::mime::Mime>, ctx: &RenderContext) -> axum::response::Response {
         match self {
-            Ok(t) => t.__groom_into_response(negotiated),
-            Err(e) => e.__groom_into_response(negotiated),
+            Ok(t) => t.__groom_into_response(negotiated, ctx),
+            Err(e) => e.__groom_into_response(negotiated, ctx),
+        }
+    }
+
+    fn __groom_compression(&self) -> Option<Compression> {
+        match self {
+            Ok(t) => t.__groom_compression(),
+            Err(e) => e.__groom_compression(),
         }
     }
 
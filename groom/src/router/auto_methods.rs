@@ -0,0 +1,494 @@
+//! Responders and operations derived from the methods of each path of a validated router:
+//! synthesized `HEAD` and `OPTIONS`, and `405 Method Not Allowed` with `Allow`.
+
+use axum::{
+    Json,
+    http::{HeaderMap, HeaderValue, StatusCode, header::ALLOW},
+    response::IntoResponse,
+};
+use serde::Serialize;
+use utoipa::openapi::{
+    ArrayBuilder, ContentBuilder, HeaderBuilder, ObjectBuilder, RefOr, ResponseBuilder, Type,
+    path::{HttpMethod, Operation, OperationBuilder, PathItemBuilder},
+};
+
+use crate::content_negotiation::{negotiate_parameter_insensitive, parse_accept_header};
+
+use super::{Validated, operations_mut};
+use super::core::{GroomRouter, MethodFlags};
+use super::versioning::{VersionedRoute, Versioning};
+
+/// Methods in the order they are listed in `Allow`.
+const ALLOW_ORDER: [(HttpMethod, &str); 8] = [
+    (HttpMethod::Get, "GET"),
+    (HttpMethod::Head, "HEAD"),
+    (HttpMethod::Post, "POST"),
+    (HttpMethod::Put, "PUT"),
+    (HttpMethod::Patch, "PATCH"),
+    (HttpMethod::Delete, "DELETE"),
+    (HttpMethod::Options, "OPTIONS"),
+    (HttpMethod::Trace, "TRACE"),
+];
+
+impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
+    /// Documents `HEAD` for every path with a `GET` operation and no `HEAD` one.
+    ///
+    /// axum already answers `HEAD` with the `GET` handler and drops the body; this adds the
+    /// operations to the spec. A `HEAD` operation is the `GET` one without request and response
+    /// bodies, with `Head` appended to its `operationId`. Spec layers of the `GET` operation
+    /// document it too, since the same layers serve it. A versioned `GET` route gets its `HEAD`
+    /// operation unless a version it exists in has a `HEAD` one on the path.
+    pub fn with_auto_head(mut self) -> Self {
+        let methods_by_path = self.methods_by_path();
+
+        for (path, methods) in &methods_by_path {
+            if !methods.common.contains(&HttpMethod::Get) || methods.any(&HttpMethod::Head) {
+                continue;
+            }
+
+            let Some(get) = self.openapi_paths.iter()
+                .filter(|(p, _)| p == path)
+                .find_map(|(_, item)| item.get.as_ref())
+            else {
+                continue;
+            };
+
+            let head = head_operation(get);
+
+            if let Some(bindings) = self.path_spec_layers.get_mut(path) {
+                for binding in bindings.iter_mut().filter(|b| b.methods.contains(&HttpMethod::Get)) {
+                    binding.methods = binding.methods.union_method(HttpMethod::Head);
+                }
+            }
+
+            self.openapi_paths.push((path.clone(), PathItemBuilder::new().operation(HttpMethod::Head, head).build()));
+        }
+
+        let Some(versioning) = self.versioning else {
+            return self;
+        };
+
+        for route in &mut self.versioned_routes {
+            let Some(get) = &route.path_item.get else {
+                continue;
+            };
+
+            let Some(methods) = find_methods(&methods_by_path, &route.path) else {
+                continue;
+            };
+
+            let has_head = (0..versioning.versions().len())
+                .filter(|index| versioning.includes(route, *index))
+                .any(|index| methods.per_version[index].contains(&HttpMethod::Head));
+
+            if route.path_item.head.is_none() && !has_head {
+                route.path_item.head = Some(head_operation(get));
+            }
+        }
+
+        self
+    }
+
+    /// Answers `OPTIONS` with `204 No Content` and the `Allow` header on every path without an
+    /// `OPTIONS` operation, and documents it.
+    ///
+    /// `Allow` lists the documented methods of the path, `HEAD` when it has `GET`, and `OPTIONS`.
+    /// The responders are added after the layers of the router, so they are not behind them
+    /// (CORS preflight requests carry no credentials). On a path with versioned routes, each
+    /// version without an `OPTIONS` operation gets a versioned responder with its own `Allow`.
+    pub fn with_auto_options(mut self) -> Self {
+        for (path, methods) in self.methods_by_path() {
+            if !methods.per_version.is_empty() {
+                let ranges = version_ranges(self.versioning, &methods.per_version, |methods| {
+                    (*methods != MethodFlags::empty() && !methods.contains(&HttpMethod::Options))
+                        .then(|| allowed_methods(&methods.union_method(HttpMethod::Options)).join(", "))
+                });
+
+                for (since, until, allow) in ranges {
+                    let options = PathItemBuilder::new().operation(HttpMethod::Options, options_operation(&allow)).build();
+
+                    self.versioned_routes.push(VersionedRoute::new(&path, since, until, options_responder(&allow), options));
+                }
+
+                continue;
+            }
+
+            if methods.common.contains(&HttpMethod::Options) {
+                continue;
+            }
+
+            let allow = allowed_methods(&methods.common.union_method(HttpMethod::Options)).join(", ");
+
+            let options = options_operation(&allow);
+
+            self.router = self.router.route(&path, options_responder(&allow));
+
+            self.openapi_paths.push((path, PathItemBuilder::new().operation(HttpMethod::Options, options).build()));
+        }
+
+        self
+    }
+
+    /// Answers requests to a path with a method it does not serve with
+    /// `405 Method Not Allowed`, an `Allow` header and a body listing the allowed methods, as JSON
+    /// or plain text depending on `Accept`.
+    ///
+    /// `Allow` is computed from the documented methods of each path when this is called, so call
+    /// it after [`with_auto_head`](Self::with_auto_head) and
+    /// [`with_auto_options`](Self::with_auto_options). On a path with versioned routes, `Allow`
+    /// lists the methods of the selected version.
+    ///
+    /// # Panics
+    ///
+    /// When called twice: a path can have a single method fallback.
+    pub fn with_method_not_allowed(mut self) -> Self {
+        for (path, methods) in self.methods_by_path() {
+            if !methods.per_version.is_empty() {
+                let ranges = version_ranges(self.versioning, &methods.per_version, |methods| {
+                    (*methods != MethodFlags::empty()).then(|| allowed_methods(methods))
+                });
+
+                for (since, until, allowed) in ranges {
+                    let mut route = VersionedRoute::new(&path, since, until, method_not_allowed_fallback(allowed), Default::default());
+                    route.method_fallback = true;
+
+                    self.versioned_routes.push(route);
+                }
+
+                continue;
+            }
+
+            self.router = self.router.route(&path, method_not_allowed_fallback(allowed_methods(&methods.common)));
+        }
+
+        self
+    }
+
+    /// Documents the `405` response of [`with_method_not_allowed`](Self::with_method_not_allowed)
+    /// on every operation.
+    pub fn document_method_not_allowed(mut self) -> Self {
+        let methods_by_path = self.methods_by_path();
+        let versioning = self.versioning;
+
+        for (path, item) in &mut self.openapi_paths {
+            let Some(methods) = find_methods(&methods_by_path, path) else {
+                continue;
+            };
+
+            let allow = methods.describe_allow(versioning, |_| true);
+
+            for operation in operations_mut(item) {
+                operation.responses.responses
+                    .entry("405".to_owned())
+                    .or_insert_with(|| RefOr::T(method_not_allowed_response(&allow)));
+            }
+        }
+
+        if let Some(versioning) = versioning {
+            for route in &mut self.versioned_routes {
+                let Some(methods) = find_methods(&methods_by_path, &route.path) else {
+                    continue;
+                };
+
+                let allow = methods.describe_allow(Some(versioning), |index| versioning.includes(route, index));
+
+                for operation in operations_mut(&mut route.path_item) {
+                    operation.responses.responses
+                        .entry("405".to_owned())
+                        .or_insert_with(|| RefOr::T(method_not_allowed_response(&allow)));
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Documented methods of each path, in the order the paths were added.
+    fn methods_by_path(&self) -> Vec<(String, PathMethods)> {
+        let mut result: Vec<(String, PathMethods)> = Vec::new();
+
+        for (path, item) in &self.openapi_paths {
+            let methods = entry(&mut result, path);
+            methods.common = methods.common.union(MethodFlags::from_path_item(item));
+        }
+
+        if let Some(versioning) = self.versioning {
+            for route in &self.versioned_routes {
+                let methods = entry(&mut result, &route.path);
+
+                if methods.per_version.is_empty() {
+                    methods.per_version = vec![MethodFlags::empty(); versioning.versions().len()];
+                }
+
+                for (index, flags) in methods.per_version.iter_mut().enumerate() {
+                    if versioning.includes(route, index) {
+                        *flags = flags.union(MethodFlags::from_path_item(&route.path_item));
+                    }
+                }
+            }
+        }
+
+        for (_, methods) in &mut result {
+            let common = methods.common;
+
+            for flags in &mut methods.per_version {
+                *flags = flags.union(common);
+            }
+        }
+
+        result
+    }
+}
+
+/// Documented methods of a path.
+#[derive(Debug, Default)]
+struct PathMethods {
+    /// Methods of the operations existing in every version.
+    common: MethodFlags,
+    /// Per version, every method of the path in it; empty when no versioned route has the path.
+    per_version: Vec<MethodFlags>,
+}
+
+impl PathMethods {
+    /// Whether the path has `method` in some version.
+    fn any(&self, method: &HttpMethod) -> bool {
+        self.common.contains(method) || self.per_version.iter().any(|flags| flags.contains(method))
+    }
+
+    /// Description of `Allow` in the versions matching `filter`, each one listed when it differs
+    /// between them.
+    fn describe_allow(&self, versioning: Option<Versioning>, filter: impl Fn(usize) -> bool) -> String {
+        let Some(versioning) = versioning.filter(|_| !self.per_version.is_empty()) else {
+            return format!("`{}`", allowed_methods(&self.common).join(", "));
+        };
+
+        let mut allows: Vec<(String, Vec<&str>)> = Vec::new();
+
+        for (index, flags) in self.per_version.iter().enumerate().filter(|(index, _)| filter(*index)) {
+            let allow = allowed_methods(flags).join(", ");
+            let version = versioning.versions()[index];
+
+            match allows.iter_mut().find(|(a, _)| *a == allow) {
+                Some((_, versions)) => versions.push(version),
+                None => allows.push((allow, vec![version])),
+            }
+        }
+
+        match allows.as_slice() {
+            [(allow, _)] => format!("`{allow}`"),
+            _ => allows.iter()
+                .map(|(allow, versions)| format!("`{allow}` in {}", versions.join(", ")))
+                .collect::<Vec<_>>()
+                .join("; "),
+        }
+    }
+}
+
+/// Methods of `path`, added to `methods_by_path` when missing.
+fn entry<'m>(methods_by_path: &'m mut Vec<(String, PathMethods)>, path: &str) -> &'m mut PathMethods {
+    let index = match methods_by_path.iter().position(|(p, _)| p == path) {
+        Some(index) => index,
+        None => {
+            methods_by_path.push((path.to_owned(), PathMethods::default()));
+            methods_by_path.len() - 1
+        },
+    };
+
+    &mut methods_by_path[index].1
+}
+
+fn find_methods<'m>(methods_by_path: &'m [(String, PathMethods)], path: &str) -> Option<&'m PathMethods> {
+    methods_by_path.iter().find(|(p, _)| p == path).map(|(_, methods)| methods)
+}
+
+/// `since` and `until` bounds of the runs of consecutive versions with the same `value` of their
+/// methods, skipping the versions without one.
+fn version_ranges<T: PartialEq>(
+    versioning: Option<Versioning>,
+    per_version: &[MethodFlags],
+    value: impl Fn(&MethodFlags) -> Option<T>,
+) -> Vec<(Option<&'static str>, Option<&'static str>, T)> {
+    let versions = versioning.expect("versioned routes are validated with a versioning").versions();
+
+    let mut result = Vec::new();
+    let mut run: Option<(usize, T)> = None;
+
+    for index in 0..=per_version.len() {
+        let current = per_version.get(index).and_then(&value);
+
+        if run.as_ref().map(|(_, run)| run) != current.as_ref() {
+            if let Some((start, run)) = run.take() {
+                result.push((Some(versions[start]), versions.get(index).copied(), run));
+            }
+
+            run = current.map(|current| (index, current));
+        }
+    }
+
+    result
+}
+
+/// Names of the methods served on a path with `methods` operations, in `Allow` order; axum
+/// serves `HEAD` wherever there is `GET`.
+fn allowed_methods(methods: &MethodFlags) -> Vec<&'static str> {
+    ALLOW_ORDER.iter()
+        .filter(|(method, _)| {
+            methods.contains(method)
+                || matches!(method, HttpMethod::Head) && methods.contains(&HttpMethod::Get)
+        })
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Method router answering `OPTIONS` with `204 No Content` and `allow`.
+fn options_responder<S: Clone + Send + Sync + 'static>(allow: &str) -> axum::routing::MethodRouter<S> {
+    let allow = HeaderValue::from_str(allow).expect("method names are valid header values");
+
+    axum::routing::options(move || {
+        let allow = allow.clone();
+
+        async move { (StatusCode::NO_CONTENT, [(ALLOW, allow)]).into_response() }
+    })
+}
+
+/// Method router answering the methods it has no handler for with `405 Method Not Allowed`.
+fn method_not_allowed_fallback<S: Clone + Send + Sync + 'static>(allowed: Vec<&'static str>) -> axum::routing::MethodRouter<S> {
+    axum::routing::MethodRouter::new().fallback(move |headers: HeaderMap| {
+        let allowed = allowed.clone();
+
+        async move { method_not_allowed(allowed, &headers) }
+    })
+}
+
+/// Body of a `405` response.
+#[derive(Debug, Serialize)]
+struct MethodNotAllowed {
+    error: &'static str,
+    allowed: Vec<&'static str>,
+}
+
+fn method_not_allowed(allowed: Vec<&'static str>, headers: &HeaderMap) -> axum::response::Response {
+    let supported = [mime::APPLICATION_JSON, mime::TEXT_PLAIN_UTF_8];
+
+    // an unparseable `Accept` gets JSON rather than a `400` in place of the `405`
+    let negotiated = parse_accept_header(headers).ok().flatten()
+        .and_then(|accept| negotiate_parameter_insensitive(&accept, &supported, Some(&supported[0])).cloned())
+        .unwrap_or(mime::APPLICATION_JSON);
+
+    let allow = HeaderValue::from_str(&allowed.join(", ")).expect("method names are valid header values");
+
+    let mut response = if negotiated == mime::TEXT_PLAIN_UTF_8 {
+        format!("Method not allowed; allowed methods: {}", allowed.join(", ")).into_response()
+    } else {
+        Json(MethodNotAllowed { error: "Method not allowed", allowed }).into_response()
+    };
+
+    *response.status_mut() = StatusCode::METHOD_NOT_ALLOWED;
+    response.headers_mut().insert(ALLOW, allow);
+
+    response
+}
+
+fn method_not_allowed_response(allow: &str) -> utoipa::openapi::Response {
+    let json_schema = ObjectBuilder::new()
+        .property("error", ObjectBuilder::new().schema_type(Type::String))
+        .property("allowed", ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::String)))
+        .required("error")
+        .required("allowed");
+
+    ResponseBuilder::new()
+        .description("The method is not allowed on this path")
+        .header(
+            "Allow",
+            HeaderBuilder::new()
+                .schema(ObjectBuilder::new().schema_type(Type::String))
+                .description(Some(allow))
+                .build(),
+        )
+        .content("application/json", ContentBuilder::new().schema(Some(json_schema)).build())
+        .content(
+            mime::TEXT_PLAIN_UTF_8.as_ref(),
+            ContentBuilder::new().schema(Some(ObjectBuilder::new().schema_type(Type::String))).build(),
+        )
+        .build()
+}
+
+/// `GET` operation without request and response bodies.
+fn head_operation(get: &Operation) -> Operation {
+    let mut head = get.clone();
+
+    head.operation_id = get.operation_id.as_ref().map(|id| format!("{id}Head"));
+    head.request_body = None;
+
+    for response in head.responses.responses.values_mut() {
+        if let RefOr::T(response) = response {
+            response.content.clear();
+        }
+    }
+
+    head
+}
+
+fn options_operation(allow: &str) -> Operation {
+    OperationBuilder::new()
+        .summary(Some("Methods allowed on this path"))
+        .response(
+            "204",
+            ResponseBuilder::new()
+                .description("Methods allowed on this path")
+                .header(
+                    "Allow",
+                    HeaderBuilder::new()
+                        .schema(ObjectBuilder::new().schema_type(Type::String))
+                        .description(Some(format!("`{allow}`")))
+                        .build(),
+                )
+                .build(),
+        )
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::header::{ACCEPT, CONTENT_TYPE};
+    use utoipa::openapi::{Required, request_body::RequestBodyBuilder};
+
+    use super::*;
+
+    #[test]
+    fn head_operation_has_no_bodies() {
+        let get = OperationBuilder::new()
+            .operation_id(Some("getTask"))
+            .request_body(Some(RequestBodyBuilder::new().required(Some(Required::False)).build()))
+            .response("200", ResponseBuilder::new()
+                .description("Task")
+                .content("application/json", ContentBuilder::new().build())
+                .build()
+            )
+            .build();
+
+        let head = head_operation(&get);
+
+        assert_eq!(head.operation_id.as_deref(), Some("getTaskHead"));
+        assert!(head.request_body.is_none());
+        assert!(matches!(&head.responses.responses["200"], RefOr::T(r) if r.content.is_empty() && r.description == "Task"));
+    }
+
+    #[test]
+    fn method_not_allowed_body_is_negotiated() {
+        let mut headers = HeaderMap::new();
+
+        let response = method_not_allowed(vec!["GET", "HEAD"], &headers);
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[ALLOW], "GET, HEAD");
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+
+        headers.insert(ACCEPT, HeaderValue::from_static("text/plain"));
+        let response = method_not_allowed(vec!["GET", "HEAD"], &headers);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/plain; charset=utf-8");
+
+        headers.insert(ACCEPT, HeaderValue::from_static("image/png"));
+        let response = method_not_allowed(vec!["GET", "HEAD"], &headers);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+    }
+}
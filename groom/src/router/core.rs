@@ -2,11 +2,14 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use utoipa::openapi::path::{HttpMethod, PathItem};
+use utoipa::openapi::tag::{Tag, TagBuilder};
 
 use crate::extract::{ComponentsRegistry, SchemaMergeError};
 
 use super::error::MergeError;
 use super::traits::{OpenApiSpecLayer, SpecLayerModifier};
+use super::validate::{PathParameters, routes_conflict};
+use super::versioning::{VersionedRoute, Versioning};
 use super::{MergeResult, NotValidated};
 
 /// Map registry merge conflicts to router [`MergeError`] (name only; Display frozen).
@@ -14,6 +17,23 @@ fn schema_conflict(SchemaMergeError { name, .. }: SchemaMergeError) -> MergeErro
     MergeError::SchemaConflict { name }
 }
 
+/// Appends the tags of `other` to `tags`; a tag declared by several controllers is kept once,
+/// with the first description given.
+pub(crate) fn merge_tags(mut tags: Vec<Tag>, other: Vec<Tag>) -> Vec<Tag> {
+    for tag in other {
+        match tags.iter_mut().find(|t| t.name == tag.name) {
+            Some(existing) => {
+                if existing.description.is_none() {
+                    existing.description = tag.description;
+                }
+            },
+            None => tags.push(tag),
+        }
+    }
+
+    tags
+}
+
 /// Bit mask of HTTP methods a per-path spec layer is tagged for (D-13 / D-14).
 ///
 /// Mapped to utoipa's eight `HttpMethod` variants — no external bitflags crate.
@@ -103,6 +123,15 @@ pub struct GroomRouter<S = (), V = NotValidated> {
     /// Whole-spec layers filled once per `layer_with_spec` attach (P003 / D-11).
     /// Used only for `modify_openapi` — not deduped by pointer identity.
     pub(crate) whole_spec_layers: Vec<Box<dyn SpecLayerModifier>>,
+    /// Tags declared by controllers, added to the spec by `to_openapi` along with their
+    /// descriptions.
+    pub(crate) tags: Vec<Tag>,
+    /// Routes existing in some API versions only, served by `to_axum_router` as `versioning`
+    /// tells.
+    pub(crate) versioned_routes: Vec<VersionedRoute<S>>,
+    pub(crate) versioning: Option<Versioning>,
+    /// Path parameters read by each handler, checked against its path by `validate`.
+    pub(crate) path_parameters: Vec<PathParameters>,
     pub(crate) _marker: PhantomData<V>,
 }
 
@@ -124,10 +153,26 @@ impl<S: Clone + Send + Sync + 'static, V> GroomRouter<S, V> {
             openapi_paths,
             path_spec_layers,
             whole_spec_layers: Vec::new(),
+            tags: Vec::new(),
+            versioned_routes: Vec::new(),
+            versioning: None,
+            path_parameters: Vec::new(),
             _marker: PhantomData,
         }
     }
 
+    /// macro-internal — not public API
+    #[doc(hidden)]
+    pub fn with_controller_tag(mut self, name: &str, description: Option<&str>) -> Self {
+        let tag = TagBuilder::new()
+            .name(name)
+            .description(description)
+            .build();
+
+        self.tags = merge_tags(self.tags, vec![tag]);
+        self
+    }
+
     pub fn fallback<H, T>(self, handler: H) -> Self
     where
         H: axum::handler::Handler<T, S>,
@@ -139,6 +184,10 @@ impl<S: Clone + Send + Sync + 'static, V> GroomRouter<S, V> {
             openapi_paths: self.openapi_paths,
             path_spec_layers: self.path_spec_layers,
             whole_spec_layers: self.whole_spec_layers,
+            tags: self.tags,
+            versioned_routes: self.versioned_routes,
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: PhantomData,
         }
     }
@@ -152,16 +201,24 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths: Vec::new(),
             path_spec_layers: HashMap::new(),
             whole_spec_layers: Vec::new(),
+            tags: Vec::new(),
+            versioned_routes: Vec::new(),
+            versioning: None,
+            path_parameters: Vec::new(),
             _marker: PhantomData,
         }
     }
 
     pub fn merge(self, other: GroomRouter<S, NotValidated>) -> MergeResult<Self> {
-        let router = self.router.merge(other.router);
-
         let mut openapi_paths = self.openapi_paths;
         openapi_paths.extend(other.openapi_paths);
 
+        // axum panics on conflicting routes; keep them out of the router for `validate` to report
+        let router = match routes_conflict(&openapi_paths) {
+            true => self.router,
+            false => self.router.merge(other.router),
+        };
+
         let mut path_spec_layers = self.path_spec_layers;
         for (path, other_layers) in other.path_spec_layers {
             path_spec_layers
@@ -173,6 +230,14 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
         let mut whole_spec_layers = self.whole_spec_layers;
         whole_spec_layers.extend(other.whole_spec_layers);
 
+        let tags = merge_tags(self.tags, other.tags);
+
+        let mut versioned_routes = self.versioned_routes;
+        versioned_routes.extend(other.versioned_routes);
+
+        let mut path_parameters = self.path_parameters;
+        path_parameters.extend(other.path_parameters);
+
         let registry = self
             .registry
             .merge(other.registry)
@@ -184,13 +249,15 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths,
             path_spec_layers,
             whole_spec_layers,
+            tags,
+            versioned_routes,
+            versioning: self.versioning.or(other.versioning),
+            path_parameters,
             _marker: PhantomData,
         })
     }
 
     pub fn nest(self, path: &str, other: GroomRouter<S, NotValidated>) -> MergeResult<Self> {
-        let router = self.router.nest(path, other.router);
-
         let mut openapi_paths = self.openapi_paths;
         let mut path_spec_layers = self.path_spec_layers;
 
@@ -207,9 +274,30 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths.push((super::prepend_path(path, &nested_path), item));
         }
 
+        // axum panics on conflicting routes; keep them out of the router for `validate` to report
+        let router = match routes_conflict(&openapi_paths) {
+            true => self.router,
+            false => self.router.nest(path, other.router),
+        };
+
         let mut whole_spec_layers = self.whole_spec_layers;
         whole_spec_layers.extend(other.whole_spec_layers);
 
+        let tags = merge_tags(self.tags, other.tags);
+
+        let mut versioned_routes = self.versioned_routes;
+        for route in other.versioned_routes {
+            let mut route = route.map_router(|router| axum::Router::new().nest(path, router));
+            route.path = super::prepend_path(path, &route.path);
+            versioned_routes.push(route);
+        }
+
+        let mut path_parameters = self.path_parameters;
+        for mut parameters in other.path_parameters {
+            parameters.path = super::prepend_path(path, &parameters.path);
+            path_parameters.push(parameters);
+        }
+
         let registry = self
             .registry
             .merge(other.registry)
@@ -221,6 +309,10 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths,
             path_spec_layers,
             whole_spec_layers,
+            tags,
+            versioned_routes,
+            versioning: self.versioning.or(other.versioning),
+            path_parameters,
             _marker: PhantomData,
         })
     }
@@ -234,11 +326,17 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
         <L::Service as tower::Service<axum::extract::Request>>::Future: Send + 'static,
     {
         Self {
-            router: self.router.layer(layer),
+            router: self.router.layer(layer.clone()),
             registry: self.registry,
             openapi_paths: self.openapi_paths,
             path_spec_layers: self.path_spec_layers,
             whole_spec_layers: self.whole_spec_layers,
+            tags: self.tags,
+            versioned_routes: self.versioned_routes.into_iter()
+                .map(|route| route.map_router(|router| router.layer(layer.clone())))
+                .collect(),
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: PhantomData,
         }
     }
@@ -252,11 +350,17 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
         <L::Service as tower::Service<axum::extract::Request>>::Future: Send + 'static,
     {
         Self {
-            router: self.router.route_layer(layer),
+            router: self.router.route_layer(layer.clone()),
             registry: self.registry,
             openapi_paths: self.openapi_paths,
             path_spec_layers: self.path_spec_layers,
             whole_spec_layers: self.whole_spec_layers,
+            tags: self.tags,
+            versioned_routes: self.versioned_routes.into_iter()
+                .map(|route| route.map_router(|router| router.route_layer(layer.clone())))
+                .collect(),
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: PhantomData,
         }
     }
@@ -300,7 +404,17 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
         }
 
         let mut whole_spec_layers = self.whole_spec_layers;
-        whole_spec_layers.push(boxed);
+        whole_spec_layers.push(boxed.clone_box());
+
+        let tags = self.tags;
+
+        let versioned_routes = self.versioned_routes.into_iter()
+            .map(|route| {
+                let mut route = route.map_router(|router| spec_layer.mount(router));
+                route.spec_layers.push(boxed.clone_box());
+                route
+            })
+            .collect();
 
         Self {
             router: spec_layer.mount(self.router),
@@ -308,6 +422,10 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths: self.openapi_paths,
             path_spec_layers,
             whole_spec_layers,
+            tags,
+            versioned_routes,
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: PhantomData,
         }
     }
@@ -319,14 +437,14 @@ mod tests {
     use crate::extract::ComponentsRegistry;
 
     mod sub1 {
-        #[derive(utoipa::ToSchema)]
+        #[derive(serde::Serialize, utoipa::ToSchema)]
         pub struct SchemaA {
             pub value: i32,
         }
     }
 
     mod sub2 {
-        #[derive(utoipa::ToSchema)]
+        #[derive(serde::Serialize, utoipa::ToSchema)]
         pub struct SchemaA {
             pub value: String,
         }
@@ -368,6 +486,22 @@ mod tests {
         let _axum_router: axum::Router = r.validate().unwrap().to_axum_router();
     }
 
+    #[test]
+    fn test_merge_keeps_first_tag_description() {
+        let a: GroomRouter = GroomRouter::new().with_controller_tag("tasks", None);
+        let b: GroomRouter = GroomRouter::new()
+            .with_controller_tag("tasks", Some("Tasks of a board"))
+            .with_controller_tag("boards", None);
+        let c: GroomRouter = GroomRouter::new().with_controller_tag("tasks", Some("Ignored"));
+
+        let merged = a.merge(b).unwrap().merge(c).unwrap();
+
+        let tags: Vec<_> = merged.tags.iter()
+            .map(|tag| (tag.name.as_str(), tag.description.as_deref()))
+            .collect();
+        assert_eq!(tags, [("tasks", Some("Tasks of a board")), ("boards", None)]);
+    }
+
     #[test]
     fn test_merge_combines_empty_routers() {
         let r1: GroomRouter<()> = GroomRouter::new();
@@ -21,6 +21,45 @@ pub enum RouterValidationError {
         path: String,
         method: ::http::Method,
     },
+
+    #[error("route `{path}` exists in some API versions only, but the router has no versioning")]
+    MissingVersioning {
+        path: String,
+    },
+
+    #[error("route `{path}` refers to unknown API version `{version}`")]
+    UnknownVersion {
+        path: String,
+        version: String,
+    },
+
+    #[error("path parameters of handler `{handler}` do not match route `{path}`: not extracted {not_extracted:?}, not in the path {not_in_path:?}")]
+    PathParameterMismatch {
+        path: String,
+        handler: String,
+        not_extracted: Vec<String>,
+        not_in_path: Vec<String>,
+    },
+
+    #[error("route `{path}` is ambiguous with `{conflicts_with}`: they differ only by the names of their parameters")]
+    AmbiguousRoute {
+        path: String,
+        conflicts_with: String,
+    },
+}
+
+/// Two routes matching some of the same requests, like `/tasks/export` and `/tasks/{id}`; see
+/// `GroomRouter::route_overlaps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteOverlap {
+    pub path: String,
+    pub other: String,
+}
+
+impl std::fmt::Display for RouteOverlap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "route `{}` overlaps `{}`: requests matching both are served by the more specific one", self.path, self.other)
+    }
 }
 
 #[cfg(test)]
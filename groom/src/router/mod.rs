@@ -1,14 +1,24 @@
+use utoipa::openapi::path::{Operation, PathItem};
+
 mod error;
 pub use error::MergeError;
 pub use error::RouterValidationError;
+pub use error::RouteOverlap;
 
 pub(crate) mod core;
 pub(crate) mod traits;
 mod validate;
 mod openapi;
+mod auto_methods;
+mod versioning;
 
 pub use core::GroomRouter;
 pub use traits::{OpenApiSpecLayer, SpecLayerModifier};
+pub use versioning::{Versioning, VersioningStrategy};
+#[doc(hidden)]
+pub use validate::PathParameters;
+#[doc(hidden)]
+pub use versioning::VersionedRoute;
 
 pub(crate) type MergeResult<T> = Result<T, MergeError>;
 
@@ -39,14 +49,21 @@ fn prepend_path(prefix: &str, path: &str) -> String {
     }
 }
 
-impl GroomRouter<(), NotValidated> {
-    pub fn with_state<S2: Clone + Send + Sync + 'static>(self, state: ()) -> GroomRouter<S2, NotValidated> {
+impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
+    /// Binds `state` to the routes, like [`axum::Router::with_state`].
+    pub fn with_state<S2: Clone + Send + Sync + 'static>(self, state: S) -> GroomRouter<S2, NotValidated> {
         GroomRouter {
-            router: self.router.with_state(state),
+            router: self.router.with_state(state.clone()),
             registry: self.registry,
             openapi_paths: self.openapi_paths,
             path_spec_layers: self.path_spec_layers,
             whole_spec_layers: self.whole_spec_layers,
+            tags: self.tags,
+            versioned_routes: self.versioned_routes.into_iter()
+                .map(|route| route.map_router(|router| router.with_state(state.clone())))
+                .collect(),
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: std::marker::PhantomData,
         }
     }
@@ -72,11 +89,31 @@ impl<S: Clone, V: Clone> Clone for GroomRouter<S, V> {
                 .iter()
                 .map(|layer| layer.clone_box())
                 .collect(),
+            tags: self.tags.clone(),
+            versioned_routes: self.versioned_routes.clone(),
+            versioning: self.versioning,
+            path_parameters: self.path_parameters.clone(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
+/// Operations of a path item.
+pub(crate) fn operations_mut(item: &mut PathItem) -> impl Iterator<Item = &mut Operation> {
+    [
+        &mut item.get,
+        &mut item.put,
+        &mut item.post,
+        &mut item.delete,
+        &mut item.options,
+        &mut item.head,
+        &mut item.patch,
+        &mut item.trace,
+    ]
+    .into_iter()
+    .filter_map(Option::as_mut)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,19 +1,88 @@
 use utoipa::openapi::OpenApi;
+use utoipa::openapi::path::{HttpMethod, Operation, PathItem};
 
 use super::core::GroomRouter;
-use super::Validated;
+use super::{Validated, Versioning, VersioningStrategy};
 
 impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
-    pub fn to_openapi(&self, mut api: OpenApi) -> OpenApi {
-        let mut paths_builder = utoipa::openapi::path::PathsBuilder::new();
+    /// OpenAPI document of the router, merged into `api`; with versioning, the document of the
+    /// default version.
+    pub fn to_openapi(&self, api: OpenApi) -> OpenApi {
+        let version = self.versioning.as_ref().map(|versioning| (versioning, versioning.default_index()));
+
+        self.build_openapi(api, version)
+    }
+
+    /// OpenAPI document of `version`, merged into `api`: the routes existing in it, under their
+    /// `/{version}` prefix with [`VersioningStrategy::UrlPrefix`], and with the version header
+    /// parameter with [`VersioningStrategy::Header`].
+    ///
+    /// # Panics
+    ///
+    /// When the router has no versioning or `version` is not one of its versions.
+    pub fn to_openapi_for_version(&self, version: &str, api: OpenApi) -> OpenApi {
+        let versioning = self.versioning.as_ref().expect("the router has no versioning");
+
+        let index = versioning.position(version)
+            .unwrap_or_else(|| panic!("`{version}` is not one of the versions {:?}", versioning.versions()));
+
+        self.build_openapi(api, Some((versioning, index)))
+    }
+
+    fn build_openapi(&self, mut api: OpenApi, version: Option<(&Versioning, usize)>) -> OpenApi {
+        let mut paths: Vec<(String, PathItem)> = Vec::with_capacity(self.openapi_paths.len());
+
+        // Per-operation modification: apply each path's spec layers to its own operations
         for (path_str, path_item) in &self.openapi_paths {
-            paths_builder = paths_builder.path(path_str.as_str(), path_item.clone());
+            let mut path_item = path_item.clone();
+
+            if let Some(layers) = self.path_spec_layers.get(path_str.as_str()) {
+                for (method, operation) in operations_with_methods_mut(&mut path_item) {
+                    for binding in layers {
+                        if !binding.methods.contains(&method) {
+                            continue;
+                        }
+                        binding.layer.modify_operation(path_str.as_str(), &method, operation);
+                    }
+                }
+            }
+
+            paths.push((path_str.clone(), path_item));
+        }
+
+        if let Some((versioning, index)) = version {
+            for route in self.versioned_routes.iter().filter(|route| versioning.includes(route, index)) {
+                let mut path_item = route.path_item.clone();
+
+                for (method, operation) in operations_with_methods_mut(&mut path_item) {
+                    for layer in &route.spec_layers {
+                        layer.modify_operation(&route.path, &method, operation);
+                    }
+                }
+
+                versioning.document(&mut path_item, index);
+
+                paths.push((route.path.clone(), path_item));
+            }
+
+            if versioning.strategy() == VersioningStrategy::UrlPrefix {
+                let prefix = format!("/{}", versioning.versions()[index]);
+
+                for (path, _) in &mut paths {
+                    *path = super::prepend_path(&prefix, path);
+                }
+            }
+        }
+
+        let mut paths_builder = utoipa::openapi::path::PathsBuilder::new();
+        for (path_str, path_item) in paths {
+            paths_builder = paths_builder.path(path_str, path_item);
         }
         let paths = paths_builder.build();
 
         let existing_components = api.components
             .take()
-            .unwrap_or_else(utoipa::openapi::Components::new);
+            .unwrap_or_default();
         let merged_components = self.registry.into_components(existing_components);
 
         let spec_part = utoipa::openapi::OpenApiBuilder::new()
@@ -23,39 +92,9 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
 
         api.merge(spec_part);
 
-        // Per-operation modification: apply each path's spec layers to its own operations
-        for (path_str, path_item) in &mut api.paths.paths {
-            let methods: [(
-                utoipa::openapi::path::HttpMethod,
-                &mut Option<utoipa::openapi::path::Operation>,
-            ); 8] = [
-                (utoipa::openapi::path::HttpMethod::Get,     &mut path_item.get),
-                (utoipa::openapi::path::HttpMethod::Put,     &mut path_item.put),
-                (utoipa::openapi::path::HttpMethod::Post,    &mut path_item.post),
-                (utoipa::openapi::path::HttpMethod::Delete,  &mut path_item.delete),
-                (utoipa::openapi::path::HttpMethod::Options, &mut path_item.options),
-                (utoipa::openapi::path::HttpMethod::Head,    &mut path_item.head),
-                (utoipa::openapi::path::HttpMethod::Patch,   &mut path_item.patch),
-                (utoipa::openapi::path::HttpMethod::Trace,   &mut path_item.trace),
-            ];
-
-            if let Some(layers) = self.path_spec_layers.get(path_str.as_str()) {
-                for (method, operation_opt) in methods {
-                    if let Some(operation) = operation_opt {
-                        for binding in layers {
-                            if !binding.methods.contains(&method) {
-                                continue;
-                            }
-                            binding.layer.modify_operation(
-                                path_str.as_str(),
-                                &method,
-                                operation,
-                            );
-                        }
-                    }
-                }
-            }
-        }
+        // Tags of the base document win over the ones of controllers; descriptions only fill gaps
+        api.tags = Some(super::core::merge_tags(api.tags.take().unwrap_or_default(), self.tags.clone()))
+            .filter(|tags| !tags.is_empty());
 
         // Whole-spec modification: once per intentional layer_with_spec attach (P003).
         for spec_layer in &self.whole_spec_layers {
@@ -65,9 +104,40 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
         api
     }
 
+    /// Router serving the routes; with versioning, serving each version as its strategy tells.
     pub fn to_axum_router(self) -> axum::Router<S> {
-        self.router
+        match self.versioning {
+            None => self.router,
+            Some(versioning) => self.versioned_axum_router(versioning),
+        }
     }
+
+    /// Router serving the routes with `state` bound once, like `to_axum_router().with_state(state)`.
+    ///
+    /// With versions selected by header or media type, requests are then served without
+    /// binding the state to the routes of their version again.
+    pub fn to_axum_router_with_state<S2: Clone + Send + Sync + 'static>(self, state: S) -> axum::Router<S2> {
+        match self.versioning {
+            None => self.router.with_state(state),
+            Some(versioning) => self.bound_versioned_axum_router(versioning, state),
+        }
+    }
+}
+
+/// Operations of a path item with their methods.
+fn operations_with_methods_mut(path_item: &mut PathItem) -> impl Iterator<Item = (HttpMethod, &mut Operation)> {
+    [
+        (HttpMethod::Get,     &mut path_item.get),
+        (HttpMethod::Put,     &mut path_item.put),
+        (HttpMethod::Post,    &mut path_item.post),
+        (HttpMethod::Delete,  &mut path_item.delete),
+        (HttpMethod::Options, &mut path_item.options),
+        (HttpMethod::Head,    &mut path_item.head),
+        (HttpMethod::Patch,   &mut path_item.patch),
+        (HttpMethod::Trace,   &mut path_item.trace),
+    ]
+    .into_iter()
+    .filter_map(|(method, operation)| Some((method, operation.as_mut()?)))
 }
 
 #[cfg(test)]
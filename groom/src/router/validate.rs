@@ -1,12 +1,14 @@
 use std::collections::HashMap;
+use std::ops::Range;
 
-use utoipa::openapi::path::HttpMethod;
+use utoipa::openapi::path::{HttpMethod, PathItem};
 
-use crate::router::error::RouterValidationError;
+use crate::router::error::{RouteOverlap, RouterValidationError};
 
 use super::core::{GroomRouter, MethodFlags};
 use super::NotValidated;
 use super::Validated;
+use super::VersioningStrategy;
 
 /// OpenAPI PathItem methods (eight; no CONNECT) with matching `http::Method` for errors.
 const OPENAPI_METHODS: [(HttpMethod, ::http::Method); 8] = [
@@ -29,20 +31,202 @@ fn first_shadowed_method(existing: MethodFlags, incoming: MethodFlags) -> Option
     None
 }
 
+/// Adds the methods of `items` to `seen`; a method already seen on a path → RouteShadow (P008).
+fn check_route_shadows<'a>(
+    seen: &mut HashMap<&'a str, MethodFlags>,
+    items: impl IntoIterator<Item = (&'a String, &'a PathItem)>,
+) -> Result<(), RouterValidationError> {
+    for (path, item) in items {
+        let incoming = MethodFlags::from_path_item(item);
+        let entry = seen.entry(path.as_str()).or_insert_with(MethodFlags::empty);
+        if let Some(method) = first_shadowed_method(*entry, incoming) {
+            return Err(RouterValidationError::RouteShadow {
+                path: path.clone(),
+                method,
+            });
+        }
+        *entry = entry.union(incoming);
+    }
+
+    Ok(())
+}
+
+/// Paths differing only by the names of their captures → AmbiguousRoute; axum refuses to route
+/// both of them.
+fn check_ambiguous_routes<'a>(paths: impl IntoIterator<Item = &'a str>) -> Result<(), RouterValidationError> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+
+    for path in paths {
+        let existing = *seen.entry(normalize_template(path)).or_insert(path);
+        if existing != path {
+            return Err(RouterValidationError::AmbiguousRoute {
+                path: path.to_owned(),
+                conflicts_with: existing.to_owned(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `paths` has a route shadow or an ambiguous route, on which axum would panic while
+/// building the router.
+pub(crate) fn routes_conflict(paths: &[(String, PathItem)]) -> bool {
+    let mut seen = HashMap::new();
+
+    check_route_shadows(&mut seen, paths.iter().map(|(path, item)| (path, item))).is_err()
+        || check_ambiguous_routes(paths.iter().map(|(path, _)| path.as_str())).is_err()
+}
+
+/// Path parameters read by the extractors of a handler.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct PathParameters {
+    pub(crate) path: String,
+    pub(crate) handler: &'static str,
+    /// `None` when no extractor of the handler reads the path.
+    pub(crate) extracted: Option<Vec<String>>,
+}
+
+impl PathParameters {
+    /// macro-internal — not public API
+    #[doc(hidden)]
+    pub fn new(path: &str, handler: &'static str, extracted: impl IntoIterator<Item = Option<Vec<String>>>) -> Self {
+        let extracted = extracted.into_iter()
+            .flatten()
+            .reduce(|mut names, more| {
+                names.extend(more);
+                names
+            });
+
+        Self { path: path.to_owned(), handler, extracted }
+    }
+
+    /// Names extracted but missing from the path, and the other way round → PathParameterMismatch.
+    fn check(&self) -> Result<(), RouterValidationError> {
+        let Some(extracted) = &self.extracted else {
+            return Ok(());
+        };
+
+        let in_path = template_parameters(&self.path);
+
+        let not_extracted: Vec<String> = in_path.iter()
+            .filter(|name| !extracted.iter().any(|e| e == *name))
+            .map(|name| name.to_string())
+            .collect();
+        let not_in_path: Vec<String> = extracted.iter()
+            .filter(|name| !in_path.contains(&name.as_str()))
+            .cloned()
+            .collect();
+
+        if not_extracted.is_empty() && not_in_path.is_empty() {
+            return Ok(());
+        }
+
+        Err(RouterValidationError::PathParameterMismatch {
+            path: self.path.clone(),
+            handler: self.handler.to_owned(),
+            not_extracted,
+            not_in_path,
+        })
+    }
+}
+
+/// `{name}` and `{*name}` captures of a path template, as byte ranges with the braces and their
+/// contents; `{{` and `}}` are literal.
+fn template_captures(path: &str) -> Vec<(Range<usize>, &str)> {
+    let mut captures = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = path[offset..].find('{').map(|start| offset + start) {
+        if path[start + 1..].starts_with('{') {
+            offset = start + 2;
+            continue;
+        }
+
+        let Some(len) = path[start + 1..].find('}') else {
+            break;
+        };
+
+        let end = start + len + 2;
+        captures.push((start..end, &path[start + 1..end - 1]));
+        offset = end;
+    }
+
+    captures
+}
+
+/// Names of the `{name}` and `{*name}` captures of a path template.
+fn template_parameters(path: &str) -> Vec<&str> {
+    template_captures(path).into_iter()
+        .map(|(_, capture)| capture.strip_prefix('*').unwrap_or(capture))
+        .collect()
+}
+
+/// `path` with unnamed captures: `/tasks/{id}` and `/tasks/{task_id}` both give `/tasks/{}`.
+fn normalize_template(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    let mut offset = 0;
+
+    for (range, capture) in template_captures(path) {
+        normalized.push_str(&path[offset..range.start]);
+        normalized.push_str(if capture.starts_with('*') { "{*}" } else { "{}" });
+        offset = range.end;
+    }
+
+    normalized.push_str(&path[offset..]);
+    normalized
+}
+
+/// Segment of a path template, for overlap detection.
+#[derive(PartialEq)]
+enum Segment<'a> {
+    Static(&'a str),
+    Capture,
+    CatchAll,
+}
+
+fn template_segments(path: &str) -> Vec<Segment<'_>> {
+    path.split('/')
+        .map(|segment| match template_captures(segment).as_slice() {
+            [(range, capture)] if *range == (0..segment.len()) => match capture.starts_with('*') {
+                true => Segment::CatchAll,
+                false => Segment::Capture,
+            },
+            _ => Segment::Static(segment),
+        })
+        .collect()
+}
+
+/// Whether some request path matches both templates, which are not ambiguous.
+fn templates_overlap(a: &[Segment], b: &[Segment]) -> bool {
+    match (a, b) {
+        ([], []) => true,
+        ([Segment::CatchAll], [_, ..]) | ([_, ..], [Segment::CatchAll]) => true,
+        ([first_a, rest_a @ ..], [first_b, rest_b @ ..]) => {
+            let segments_overlap = match (first_a, first_b) {
+                (Segment::Static(a), Segment::Static(b)) => a == b,
+                (Segment::CatchAll, _) | (_, Segment::CatchAll) => false,
+                _ => true,
+            };
+
+            segments_overlap && templates_overlap(rest_a, rest_b)
+        },
+        _ => false,
+    }
+}
+
 impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
     pub fn validate(self) -> Result<GroomRouter<S, Validated>, RouterValidationError> {
-        // One-pass path → method-flags insert; duplicate method bit → RouteShadow (P008).
+        // One-pass path → method-flags insert
         let mut seen: HashMap<&str, MethodFlags> = HashMap::new();
-        for (path, item) in &self.openapi_paths {
-            let incoming = MethodFlags::from_path_item(item);
-            let entry = seen.entry(path.as_str()).or_insert_with(MethodFlags::empty);
-            if let Some(method) = first_shadowed_method(*entry, incoming) {
-                return Err(RouterValidationError::RouteShadow {
-                    path: path.clone(),
-                    method,
-                });
-            }
-            *entry = entry.union(incoming);
+        check_route_shadows(&mut seen, self.openapi_paths.iter().map(|(path, item)| (path, item)))?;
+        check_ambiguous_routes(self.openapi_paths.iter().map(|(path, _)| path.as_str()))?;
+
+        self.validate_versions(&seen)?;
+
+        for parameters in &self.path_parameters {
+            parameters.check()?;
         }
 
         Ok(GroomRouter {
@@ -51,9 +235,93 @@ impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
             openapi_paths: self.openapi_paths,
             path_spec_layers: self.path_spec_layers,
             whole_spec_layers: self.whole_spec_layers,
+            tags: self.tags,
+            versioned_routes: self.versioned_routes,
+            versioning: self.versioning,
+            path_parameters: self.path_parameters,
             _marker: std::marker::PhantomData,
         })
     }
+
+    /// Checks the versioned routes against the versions, and each version for shadowed and
+    /// ambiguous routes; `unversioned` are the methods of the routes existing in every version.
+    fn validate_versions(&self, unversioned: &HashMap<&str, MethodFlags>) -> Result<(), RouterValidationError> {
+        let Some(versioning) = &self.versioning else {
+            return match self.versioned_routes.first() {
+                Some(route) => Err(RouterValidationError::MissingVersioning { path: route.path.clone() }),
+                None => Ok(()),
+            };
+        };
+
+        for route in &self.versioned_routes {
+            for version in [route.since, route.until].into_iter().flatten() {
+                if versioning.position(version).is_none() {
+                    return Err(RouterValidationError::UnknownVersion {
+                        path: route.path.clone(),
+                        version: version.to_owned(),
+                    });
+                }
+            }
+        }
+
+        for index in 0..versioning.versions().len() {
+            let mut seen = unversioned.clone();
+            check_route_shadows(&mut seen, self.versioned_routes.iter()
+                .filter(|route| versioning.includes(route, index))
+                .map(|route| (&route.path, &route.path_item))
+            )?;
+            check_ambiguous_routes(self.openapi_paths.iter()
+                .map(|(path, _)| path)
+                .chain(self.versioned_routes.iter().filter(|route| versioning.includes(route, index)).map(|route| &route.path))
+                .map(String::as_str)
+            )?;
+        }
+
+        // the header and media type strategies route the paths of every version in one router
+        if versioning.strategy() != VersioningStrategy::UrlPrefix {
+            check_ambiguous_routes(self.openapi_paths.iter()
+                .map(|(path, _)| path)
+                .chain(self.versioned_routes.iter().map(|route| &route.path))
+                .map(String::as_str)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// macro-internal — not public API
+    #[doc(hidden)]
+    pub fn with_path_parameters(mut self, parameters: Vec<PathParameters>) -> Self {
+        self.path_parameters.extend(parameters);
+        self
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
+    /// Pairs of routes matching some of the same requests without being ambiguous, like
+    /// `/tasks/export` and `/tasks/{id}`: axum serves the more specific one. `validate()` accepts
+    /// them; call this to warn about them.
+    pub fn route_overlaps(&self) -> Vec<RouteOverlap> {
+        let mut paths: Vec<&str> = Vec::new();
+        for path in self.openapi_paths.iter().map(|(path, _)| path).chain(self.versioned_routes.iter().map(|route| &route.path)) {
+            if !paths.contains(&path.as_str()) {
+                paths.push(path);
+            }
+        }
+
+        let segments: Vec<Vec<Segment>> = paths.iter().map(|path| template_segments(path)).collect();
+
+        let mut overlaps = Vec::new();
+        for (i, path) in paths.iter().enumerate() {
+            for (j, other) in paths.iter().enumerate().skip(i + 1) {
+                if normalize_template(path) != normalize_template(other) && templates_overlap(&segments[i], &segments[j]) {
+                    overlaps.push(RouteOverlap { path: path.to_string(), other: other.to_string() });
+                }
+            }
+        }
+
+        overlaps
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +461,135 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_validate_checks_versioned_routes_per_version() {
+        use utoipa::openapi::path::{PathItemBuilder, HttpMethod, OperationBuilder};
+        use crate::router::{VersionedRoute, Versioning};
+
+        let route = |since, until| {
+            let op = OperationBuilder::new().operation_id(Some("getTask")).build();
+            let pi = PathItemBuilder::new().operation(HttpMethod::Get, op).build();
+            VersionedRoute::new("/tasks", since, until, axum::routing::get(|| async {}), pi)
+        };
+
+        let disjoint: GroomRouter<()> = GroomRouter::new()
+            .with_versioned_routes(vec![route(None, Some("v2")), route(Some("v2"), None)]);
+        assert!(matches!(
+            disjoint.clone().validate().err(),
+            Some(RouterValidationError::MissingVersioning { path }) if path == "/tasks"
+        ));
+        assert!(disjoint.with_versioning(Versioning::url_prefix(&["v1", "v2"])).validate().is_ok());
+
+        let overlapping: GroomRouter<()> = GroomRouter::new()
+            .with_versioned_routes(vec![route(None, None), route(Some("v2"), None)])
+            .with_versioning(Versioning::url_prefix(&["v1", "v2"]));
+        assert!(matches!(overlapping.validate().err(), Some(RouterValidationError::RouteShadow { .. })));
+
+        let renamed = |versioning| -> GroomRouter<()> {
+            GroomRouter::new()
+                .with_versioned_routes(vec![VersionedRoute::new("/tasks/{id}", None, Some("v2"), axum::routing::get(|| async {}), PathItem::default())])
+                .with_versioned_routes(vec![VersionedRoute::new("/tasks/{task_id}", Some("v2"), None, axum::routing::get(|| async {}), PathItem::default())])
+                .with_versioning(versioning)
+        };
+        // each version has its own router with the URL prefix strategy
+        let _ = renamed(Versioning::url_prefix(&["v1", "v2"])).validate().unwrap().to_axum_router();
+        for versioning in [Versioning::header("Api-Version", &["v1", "v2"]), Versioning::media_type_parameter("version", &["v1", "v2"])] {
+            assert!(matches!(
+                renamed(versioning).validate().err(),
+                Some(RouterValidationError::AmbiguousRoute { path, conflicts_with })
+                    if path == "/tasks/{task_id}" && conflicts_with == "/tasks/{id}"
+            ));
+        }
+
+        let unknown: GroomRouter<()> = GroomRouter::new()
+            .with_versioned_routes(vec![route(Some("v3"), None)])
+            .with_versioning(Versioning::url_prefix(&["v1", "v2"]));
+        assert!(matches!(
+            unknown.validate().err(),
+            Some(RouterValidationError::UnknownVersion { version, .. }) if version == "v3"
+        ));
+    }
+
+    #[test]
+    fn test_template_parameters() {
+        assert_eq!(template_parameters("/tasks"), Vec::<&str>::new());
+        assert_eq!(template_parameters("/tasks/{id}/notes/{note_id}"), vec!["id", "note_id"]);
+        assert_eq!(template_parameters("/files/{*path}"), vec!["path"]);
+        assert_eq!(template_parameters("/{{literal}}/{id}"), vec!["id"]);
+    }
+
+    #[test]
+    fn test_validate_checks_path_parameters() {
+        let router = |path: &str, extracted: Option<Vec<String>>| -> GroomRouter<()> {
+            GroomRouter::new().with_path_parameters(vec![PathParameters::new(path, "get_task", [extracted])])
+        };
+        let names = |names: &[&str]| Some(names.iter().map(|name| name.to_string()).collect());
+
+        assert!(router("/tasks/{id}", names(&["id"])).validate().is_ok());
+        assert!(router("/tasks/{id}", None).validate().is_ok(), "handlers not reading the path are not checked");
+
+        match router("/tasks/{id}", names(&["task_id"])).validate().err() {
+            Some(RouterValidationError::PathParameterMismatch { path, handler, not_extracted, not_in_path }) => {
+                assert_eq!(path, "/tasks/{id}");
+                assert_eq!(handler, "get_task");
+                assert_eq!(not_extracted, vec!["id"]);
+                assert_eq!(not_in_path, vec!["task_id"]);
+            }
+            other => panic!("expected PathParameterMismatch, got {:?}", other),
+        }
+
+        let nested = GroomRouter::new().nest("/users/{user_id}", router("/tasks/{id}", names(&["id"]))).unwrap();
+        assert!(matches!(
+            nested.validate().err(),
+            Some(RouterValidationError::PathParameterMismatch { path, not_extracted, .. })
+                if path == "/users/{user_id}/tasks/{id}" && not_extracted == ["user_id"]
+        ));
+    }
+
+    #[test]
+    fn test_normalize_template() {
+        assert_eq!(normalize_template("/tasks/{id}"), normalize_template("/tasks/{task_id}"));
+        assert_eq!(normalize_template("/files/{*path}"), "/files/{*}");
+        assert_eq!(normalize_template("/{{literal}}/{id}"), "/{{literal}}/{}");
+        assert_ne!(normalize_template("/tasks/{id}"), normalize_template("/tasks/export"));
+    }
+
+    #[test]
+    fn test_validate_detects_ambiguous_routes() {
+        use utoipa::openapi::path::{PathItemBuilder, HttpMethod, OperationBuilder};
+
+        let router = |path: &str, method| -> GroomRouter<()> {
+            let op = OperationBuilder::new().operation_id(Some("op")).build();
+            GroomRouter::from_controller_parts(
+                axum::Router::new(), ComponentsRegistry::new(),
+                vec![(path.to_string(), PathItemBuilder::new().operation(method, op).build())],
+            )
+        };
+
+        let ambiguous = router("/tasks/{id}", HttpMethod::Get).merge(router("/tasks/{task_id}", HttpMethod::Delete)).unwrap();
+        match ambiguous.validate().err() {
+            Some(RouterValidationError::AmbiguousRoute { path, conflicts_with }) => {
+                assert_eq!(path, "/tasks/{task_id}");
+                assert_eq!(conflicts_with, "/tasks/{id}");
+            }
+            other => panic!("expected AmbiguousRoute, got {:?}", other),
+        }
+
+        let overlapping = router("/tasks/{id}", HttpMethod::Get).merge(router("/tasks/export", HttpMethod::Get)).unwrap();
+        let validated = overlapping.validate().expect("static and capture segments are not ambiguous");
+        assert_eq!(validated.route_overlaps(), vec![RouteOverlap { path: "/tasks/{id}".into(), other: "/tasks/export".into() }]);
+    }
+
+    #[test]
+    fn test_templates_overlap() {
+        let overlap = |a: &str, b: &str| templates_overlap(&template_segments(a), &template_segments(b));
+
+        assert!(overlap("/tasks/{id}", "/tasks/export"));
+        assert!(overlap("/{team}/tasks", "/admin/{page}"));
+        assert!(overlap("/files/{*path}", "/files/readme/raw"));
+        assert!(!overlap("/files/{*path}", "/files"));
+        assert!(!overlap("/tasks/{id}", "/users/{id}"));
+        assert!(!overlap("/tasks/{id}", "/tasks/{id}/notes"));
+    }
 }
@@ -0,0 +1,545 @@
+//! API versions served side by side by one router.
+//!
+//! Routes get the versions they exist in with `#[Route(since = "v2", until = "v3")]` (`until`
+//! excluded), or the same arguments of `#[Controller]` for every route of a module. Routes
+//! without them exist in every version. The versions, in order, and the way a request selects
+//! one are given to [`GroomRouter::with_versioning`]:
+//!
+//! ```ignore
+//! let router = tasks_v1::into_router()
+//!     .merge(tasks_v2::into_router())?
+//!     .with_versioning(Versioning::url_prefix(&["v1", "v2"]))
+//!     .validate()?;
+//!
+//! let v1_spec = router.to_openapi_for_version("v1", ApiDoc::openapi());
+//! ```
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header::ACCEPT},
+    response::IntoResponse,
+    routing::MethodFilter,
+};
+use tower::ServiceExt;
+use utoipa::openapi::{
+    ObjectBuilder, Required, Type,
+    path::{HttpMethod, ParameterBuilder, ParameterIn, PathItem},
+};
+
+use crate::response::append_vary;
+
+use super::core::{GroomRouter, MethodFlags};
+use super::traits::SpecLayerModifier;
+use super::{NotValidated, Validated};
+
+/// How a request selects the API version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersioningStrategy {
+    /// `/v2/tasks`: every route is served under `/{version}` for each version it exists in.
+    UrlPrefix,
+
+    /// `Api-Version: v2`: the header with this name gives the version.
+    Header(&'static str),
+
+    /// `Accept: application/json; version=v2`: the media type parameter with this name gives
+    /// the version.
+    MediaTypeParameter(&'static str),
+}
+
+/// API versions of a router and the [`VersioningStrategy`] selecting them.
+///
+/// With the header and media type parameter strategies, requests selecting no version get the
+/// default one (the last version, unless set with [`default_version`](Self::default_version)),
+/// and requests selecting an unknown one get `400 Bad Request`. Every response of a versioned
+/// route carries `Vary` with the header the version is read from.
+#[derive(Debug, Clone, Copy)]
+pub struct Versioning {
+    strategy: VersioningStrategy,
+    versions: &'static [&'static str],
+    default: usize,
+}
+
+impl Versioning {
+    /// Serves each version under `/{version}`: `url_prefix(&["v1", "v2"])` serves `/v1/tasks`
+    /// and `/v2/tasks`.
+    ///
+    /// # Panics
+    ///
+    /// When `versions` is empty or a version is not made of ASCII letters, digits, `.`, `-` and
+    /// `_`.
+    pub const fn url_prefix(versions: &'static [&'static str]) -> Self {
+        Self::new(VersioningStrategy::UrlPrefix, versions)
+    }
+
+    /// Reads the version from the header `name`, e.g. `header("Api-Version", &["1", "2"])`.
+    ///
+    /// # Panics
+    ///
+    /// As [`url_prefix`](Self::url_prefix), and when `name` is not a header name.
+    pub const fn header(name: &'static str, versions: &'static [&'static str]) -> Self {
+        assert!(is_token(name), "Versioning header name must be made of ASCII letters, digits and `-`");
+
+        Self::new(VersioningStrategy::Header(name), versions)
+    }
+
+    /// Reads the version from the parameter `name` of the media types of `Accept`, e.g.
+    /// `media_type_parameter("version", &["1", "2"])` for `application/json; version=2`.
+    ///
+    /// # Panics
+    ///
+    /// As [`url_prefix`](Self::url_prefix), and when `name` is not a parameter name.
+    pub const fn media_type_parameter(name: &'static str, versions: &'static [&'static str]) -> Self {
+        assert!(is_token(name), "Versioning parameter name must be made of ASCII letters, digits and `-`");
+
+        Self::new(VersioningStrategy::MediaTypeParameter(name), versions)
+    }
+
+    const fn new(strategy: VersioningStrategy, versions: &'static [&'static str]) -> Self {
+        assert!(!versions.is_empty(), "Versioning needs at least one version");
+
+        let mut i = 0;
+        while i < versions.len() {
+            let version = versions[i].as_bytes();
+            assert!(!version.is_empty(), "Versioning versions must not be empty");
+
+            let mut j = 0;
+            while j < version.len() {
+                let c = version[j];
+                assert!(c.is_ascii_alphanumeric() || c == b'.' || c == b'-' || c == b'_', "Versioning versions must be made of ASCII letters, digits, `.`, `-` and `_`");
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        Self { strategy, versions, default: versions.len() - 1 }
+    }
+
+    /// Version of requests selecting none, and of [`GroomRouter::to_openapi`].
+    ///
+    /// # Panics
+    ///
+    /// When `version` is not one of the versions.
+    pub fn default_version(mut self, version: &str) -> Self {
+        self.default = self.position(version)
+            .unwrap_or_else(|| panic!("`{version}` is not one of the versions {:?}", self.versions));
+        self
+    }
+
+    pub fn strategy(&self) -> VersioningStrategy {
+        self.strategy
+    }
+
+    /// Versions, oldest first.
+    pub fn versions(&self) -> &'static [&'static str] {
+        self.versions
+    }
+
+    pub(crate) fn default_index(&self) -> usize {
+        self.default
+    }
+
+    pub(crate) fn position(&self, version: &str) -> Option<usize> {
+        self.versions.iter().position(|v| *v == version)
+    }
+
+    /// Whether `route` exists in the version at `index`.
+    pub(crate) fn includes<S>(&self, route: &VersionedRoute<S>, index: usize) -> bool {
+        route.since.is_none_or(|since| self.position(since).is_some_and(|since| since <= index))
+            && route.until.is_none_or(|until| self.position(until).is_some_and(|until| index < until))
+    }
+
+    /// Index of the version selected by a request; the selected version when it is unknown.
+    fn select<'h>(&self, headers: &'h HeaderMap) -> Result<usize, &'h str> {
+        let requested = match self.strategy {
+            VersioningStrategy::UrlPrefix => None,
+            VersioningStrategy::Header(name) => match headers.get(name).map(|value| value.to_str()) {
+                None => None,
+                Some(Ok(value)) => Some(value.trim()),
+                Some(Err(_)) => Some(""),
+            },
+            VersioningStrategy::MediaTypeParameter(name) => media_type_parameter(headers, name),
+        };
+
+        match requested {
+            None => Ok(self.default),
+            Some(version) => self.position(version).ok_or(version),
+        }
+    }
+
+    fn unsupported_version(&self, version: &str) -> axum::response::Response {
+        let message = format!("Unsupported API version `{version}`; supported versions: {}", self.versions.join(", "));
+
+        (StatusCode::BAD_REQUEST, message).into_response()
+    }
+
+    /// Header a response of a versioned route varies with.
+    fn vary(&self) -> Option<&'static str> {
+        match self.strategy {
+            VersioningStrategy::UrlPrefix => None,
+            VersioningStrategy::Header(name) => Some(name),
+            VersioningStrategy::MediaTypeParameter(_) => Some("accept"),
+        }
+    }
+
+    /// Documents how the version at `index` is selected on the operations of a versioned route.
+    pub(crate) fn document(&self, item: &mut PathItem, index: usize) {
+        let VersioningStrategy::Header(name) = self.strategy else {
+            // `Accept` cannot be described as a parameter, and URL prefixes are in the paths
+            return;
+        };
+
+        let version = self.versions[index];
+
+        let description = if index == self.default {
+            format!("API version; `{version}` when absent")
+        } else {
+            "API version".to_owned()
+        };
+
+        let parameter = ParameterBuilder::new()
+            .name(name)
+            .parameter_in(ParameterIn::Header)
+            .required(if index == self.default { Required::False } else { Required::True })
+            .description(Some(description))
+            .schema(Some(ObjectBuilder::new().schema_type(Type::String).enum_values(Some([version]))))
+            .build();
+
+        for operation in super::operations_mut(item) {
+            operation.parameters.get_or_insert_with(Vec::new).push(parameter.clone());
+        }
+    }
+}
+
+/// `true` for an HTTP token made of ASCII letters, digits and `-`.
+const fn is_token(name: &str) -> bool {
+    let name = name.as_bytes();
+
+    let mut i = 0;
+    while i < name.len() {
+        if !(name[i].is_ascii_alphanumeric() || name[i] == b'-') {
+            return false;
+        }
+        i += 1;
+    }
+
+    !name.is_empty()
+}
+
+/// Value of the parameter `name` of the first media range of `Accept` having it.
+fn media_type_parameter<'h>(headers: &'h HeaderMap, name: &str) -> Option<&'h str> {
+    headers.get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|range| {
+            range.split(';').skip(1).find_map(|param| {
+                let (key, value) = param.split_once('=')?;
+
+                key.trim().eq_ignore_ascii_case(name).then(|| value.trim().trim_matches('"'))
+            })
+        })
+}
+
+/// Route of a controller existing in some versions only.
+#[doc(hidden)]
+pub struct VersionedRoute<S> {
+    pub(crate) path: String,
+    pub(crate) since: Option<&'static str>,
+    pub(crate) until: Option<&'static str>,
+    /// Router serving just this route, behind the layers added after it.
+    pub(crate) router: axum::Router<S>,
+    pub(crate) path_item: PathItem,
+    /// Spec layers added after the route; they document all of its operations.
+    pub(crate) spec_layers: Vec<Box<dyn SpecLayerModifier>>,
+    /// The router answers the methods without an operation, with `405 Method Not Allowed`.
+    pub(crate) method_fallback: bool,
+}
+
+impl<S: Clone + Send + Sync + 'static> VersionedRoute<S> {
+    /// macro-internal — not public API
+    #[doc(hidden)]
+    pub fn new(
+        path: &str,
+        since: Option<&'static str>,
+        until: Option<&'static str>,
+        method_router: axum::routing::MethodRouter<S>,
+        path_item: PathItem,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            since,
+            until,
+            router: axum::Router::new().route(path, method_router),
+            path_item,
+            spec_layers: Vec::new(),
+            method_fallback: false,
+        }
+    }
+
+    pub(crate) fn map_router<S2>(self, f: impl FnOnce(axum::Router<S>) -> axum::Router<S2>) -> VersionedRoute<S2> {
+        VersionedRoute {
+            path: self.path,
+            since: self.since,
+            until: self.until,
+            router: f(self.router),
+            path_item: self.path_item,
+            spec_layers: self.spec_layers,
+            method_fallback: self.method_fallback,
+        }
+    }
+}
+
+impl<S: Clone> Clone for VersionedRoute<S> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            since: self.since,
+            until: self.until,
+            router: self.router.clone(),
+            path_item: self.path_item.clone(),
+            spec_layers: self.spec_layers.iter().map(|layer| layer.clone_box()).collect(),
+            method_fallback: self.method_fallback,
+        }
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> GroomRouter<S, NotValidated> {
+    /// Serves the routes with `since`/`until` in the versions of `versioning`.
+    ///
+    /// [`validate`](Self::validate) rejects versioned routes on a router without versioning,
+    /// bounds that are not versions, and two routes serving the same method and path in a
+    /// version. Synthesized `HEAD`, `OPTIONS` and `405` responses consider the routes of each
+    /// version.
+    pub fn with_versioning(mut self, versioning: Versioning) -> Self {
+        self.versioning = Some(versioning);
+        self
+    }
+
+    /// macro-internal — not public API
+    #[doc(hidden)]
+    pub fn with_versioned_routes(mut self, routes: Vec<VersionedRoute<S>>) -> Self {
+        self.versioned_routes.extend(routes);
+        self
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> GroomRouter<S, Validated> {
+    /// Router serving every version with the versioning strategy.
+    ///
+    /// Versions selected from the headers bind the state of each request to the routes of its
+    /// version; [`bound_versioned_axum_router`](Self::bound_versioned_axum_router) binds it once.
+    pub(crate) fn versioned_axum_router(self, versioning: Versioning) -> axum::Router<S> {
+        if versioning.strategy == VersioningStrategy::UrlPrefix {
+            return self.url_prefix_router(versioning);
+        }
+
+        let mut result = self.router.clone();
+
+        for PathVersions { path, methods, method_fallback, routers } in self.routers_per_version(versioning, axum::Router::clone) {
+            let handler = move |State(state): State<S>, req: Request| {
+                let routers = routers.clone();
+                dispatch(versioning, req, move |index| routers[index].clone().map(|router| router.with_state(state)))
+            };
+
+            result = result.route(path, on_methods(methods, method_fallback, handler));
+        }
+
+        result
+    }
+
+    /// Router serving every version with `state` bound once, so requests are served by cloning
+    /// finished routers.
+    pub(crate) fn bound_versioned_axum_router<S2: Clone + Send + Sync + 'static>(self, versioning: Versioning, state: S) -> axum::Router<S2> {
+        if versioning.strategy == VersioningStrategy::UrlPrefix {
+            return self.url_prefix_router(versioning).with_state(state);
+        }
+
+        let mut result = self.router.clone().with_state(state.clone());
+
+        for PathVersions { path, methods, method_fallback, routers } in self.routers_per_version(versioning, |router| router.clone().with_state(state.clone())) {
+            let handler = move |req: Request| {
+                let routers = routers.clone();
+                dispatch(versioning, req, move |index| routers[index].clone())
+            };
+
+            result = result.route(path, on_methods(methods, method_fallback, handler));
+        }
+
+        result
+    }
+
+    /// Router nesting every version under its prefix.
+    fn url_prefix_router(self, versioning: Versioning) -> axum::Router<S> {
+        (0..versioning.versions.len()).fold(axum::Router::new(), |result, index| {
+            let router = self.versioned_routes.iter()
+                .filter(|route| versioning.includes(route, index))
+                .fold(self.router.clone(), |router, route| router.merge(route.router.clone()));
+
+            result.nest(&format!("/{}", versioning.versions[index]), router)
+        })
+    }
+
+    /// Paths of the versioned routes with, per version, the routes of the path existing in it,
+    /// each route turned into a router by `bind` once.
+    fn routers_per_version<T: Clone + Send + Sync + 'static>(
+        &self,
+        versioning: Versioning,
+        bind: impl Fn(&axum::Router<S>) -> axum::Router<T>,
+    ) -> Vec<PathVersions<'_, T>> {
+        let versions = 0..versioning.versions.len();
+
+        let mut paths: Vec<&str> = Vec::new();
+        for route in &self.versioned_routes {
+            if !paths.contains(&route.path.as_str()) {
+                paths.push(&route.path);
+            }
+        }
+
+        let bound: Vec<axum::Router<T>> = self.versioned_routes.iter().map(|route| bind(&route.router)).collect();
+
+        paths.into_iter()
+            .filter_map(|path| {
+                let on_path: Vec<(&VersionedRoute<S>, &axum::Router<T>)> = self.versioned_routes.iter()
+                    .zip(&bound)
+                    .filter(|(route, _)| route.path == path && versions.clone().any(|index| versioning.includes(route, index)))
+                    .collect();
+
+                let methods = on_path.iter()
+                    .fold(MethodFlags::empty(), |flags, (route, _)| flags.union(MethodFlags::from_path_item(&route.path_item)))
+                    .method_filter()?;
+
+                let method_fallback = on_path.iter().any(|(route, _)| route.method_fallback);
+
+                let routers = versions.clone()
+                    .map(|index| {
+                        on_path.iter()
+                            .filter(|(route, _)| versioning.includes(route, index))
+                            .map(|(_, router)| (*router).clone())
+                            .reduce(axum::Router::merge)
+                    })
+                    .collect();
+
+                Some(PathVersions { path, methods, method_fallback, routers })
+            })
+            .collect()
+    }
+}
+
+/// Versioned routes of a path.
+struct PathVersions<'r, T> {
+    path: &'r str,
+    methods: MethodFilter,
+    /// Some version answers the methods without an operation.
+    method_fallback: bool,
+    /// Per version, the router of the routes of the path existing in it.
+    routers: Arc<[Option<axum::Router<T>>]>,
+}
+
+/// Method router sending `methods`, and every other method with `method_fallback`, to `handler`.
+fn on_methods<H, T, S>(methods: MethodFilter, method_fallback: bool, handler: H) -> axum::routing::MethodRouter<S>
+where
+    H: axum::handler::Handler<T, S>,
+    T: 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    let method_router = axum::routing::on(methods, handler.clone());
+
+    if method_fallback {
+        method_router.fallback(handler)
+    } else {
+        method_router
+    }
+}
+
+/// Serves a request with `router`, the finished router of the selected version.
+async fn dispatch(
+    versioning: Versioning,
+    req: Request,
+    router: impl FnOnce(usize) -> Option<axum::Router>,
+) -> axum::response::Response {
+    let mut response = match versioning.select(req.headers()) {
+        Err(version) => versioning.unsupported_version(version),
+        Ok(index) => match router(index) {
+            None => StatusCode::NOT_FOUND.into_response(),
+            Some(router) => match router.oneshot(req).await {
+                Ok(response) => response,
+                Err(infallible) => match infallible {},
+            },
+        },
+    };
+
+    if let Some(name) = versioning.vary() {
+        append_vary(response.headers_mut(), name);
+    }
+
+    response
+}
+
+impl MethodFlags {
+    /// Methods as an axum filter; `None` without methods.
+    fn method_filter(self) -> Option<MethodFilter> {
+        [
+            (HttpMethod::Get, MethodFilter::GET),
+            (HttpMethod::Put, MethodFilter::PUT),
+            (HttpMethod::Post, MethodFilter::POST),
+            (HttpMethod::Delete, MethodFilter::DELETE),
+            (HttpMethod::Options, MethodFilter::OPTIONS),
+            (HttpMethod::Head, MethodFilter::HEAD),
+            (HttpMethod::Patch, MethodFilter::PATCH),
+            (HttpMethod::Trace, MethodFilter::TRACE),
+        ]
+        .into_iter()
+        .filter(|(method, _)| self.contains(method))
+        .map(|(_, filter)| filter)
+        .reduce(MethodFilter::or)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn routes_exist_from_since_until_before_until() {
+        let versioning = Versioning::url_prefix(&["v1", "v2", "v3"]);
+        let route = |since, until| VersionedRoute::<()>::new(
+            "/tasks", since, until, axum::routing::get(|| async {}), PathItem::default(),
+        );
+
+        let included = |route: &VersionedRoute<()>| (0..3).filter(|i| versioning.includes(route, *i)).collect::<Vec<_>>();
+
+        assert_eq!(included(&route(Some("v2"), None)), [1, 2]);
+        assert_eq!(included(&route(None, Some("v2"))), [0]);
+        assert_eq!(included(&route(Some("v1"), Some("v3"))), [0, 1]);
+        assert_eq!(included(&route(Some("v4"), None)), [] as [usize; 0]);
+    }
+
+    #[test]
+    fn version_is_selected_from_the_request() {
+        let header = Versioning::header("Api-Version", &["1", "2"]).default_version("1");
+        let media_type = Versioning::media_type_parameter("version", &["1", "2"]);
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(header.select(&headers).ok(), Some(0));
+        assert_eq!(media_type.select(&headers).ok(), Some(1));
+
+        headers.insert("api-version", HeaderValue::from_static("2"));
+        headers.insert(ACCEPT, HeaderValue::from_static("text/html, application/json; q=0.9; version=\"1\""));
+        assert_eq!(header.select(&headers).ok(), Some(1));
+        assert_eq!(media_type.select(&headers).ok(), Some(0));
+
+        headers.insert("api-version", HeaderValue::from_static("3"));
+        assert_eq!(header.select(&headers), Err("3"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Versioning versions must be made of")]
+    fn versions_must_be_url_safe() {
+        Versioning::url_prefix(&["v 1"]);
+    }
+}
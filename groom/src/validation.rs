@@ -0,0 +1,230 @@
+//! Field constraints declared with `#[validate(...)]` on `#[DTO]` and `#[RequestBody]` structs.
+//!
+//! The macros generate a `__groom_validate` method that calls the checks below for every
+//! constrained field. The controller wrapper runs it for each handler argument before the
+//! handler; failures are collected into [`ValidationErrors`] and answered with `422`.
+
+use std::{fmt::Display, sync::LazyLock};
+
+use axum::{Json, http::StatusCode, response::IntoResponse};
+use serde::Serialize;
+use utoipa::{ToSchema, openapi::{ContentBuilder, path::OperationBuilder}};
+
+use crate::{DTO, extract::{ComponentsRegistry, rejection::add_response_content}};
+
+pub use regex::Regex;
+
+/// A constraint violated by a single field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FieldError {
+    /// Name of the field as it is deserialized; fields of nested DTOs are prefixed with the
+    /// name of their parent (`address.city`, `items[0].name`).
+    pub field: String,
+
+    /// Name of the violated constraint (`length`, `range`, `pattern`, `email` or `non_empty`).
+    pub code: &'static str,
+
+    pub message: String,
+}
+
+/// All constraint violations found in a request, answered as
+/// `422 {"errors": [{"field": ..., "code": ..., "message": ...}]}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ValidationErrors {
+    pub errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn add(&mut self, field: &str, code: &'static str, message: String) {
+        self.errors.push(FieldError { field: field.to_owned(), code, message });
+    }
+
+    /// Moves the errors of `other` into `self`.
+    pub fn append(&mut self, mut other: ValidationErrors) {
+        self.errors.append(&mut other.errors);
+    }
+
+    /// `Ok(())` when nothing was collected.
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+}
+
+impl IntoResponse for ValidationErrors {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(self)).into_response()
+    }
+}
+
+/// Documents the `422` answered with [`ValidationErrors`] by an extractor with constraints.
+pub fn document_validation_errors(op: OperationBuilder, components: &mut ComponentsRegistry) -> OperationBuilder {
+    let schema = components.add_components::<ValidationErrors>();
+
+    add_response_content(
+        op.build(),
+        "422",
+        "The request does not satisfy the constraints of its fields",
+        mime::APPLICATION_JSON.as_ref(),
+        ContentBuilder::new().schema(Some(schema)).build(),
+    ).into()
+}
+
+/// Checks a field marked `#[validate(nested)]` with the constraints of its own DTO.
+pub fn nested<T: DTO>(errors: &mut ValidationErrors, field: &str, value: &T) {
+    if let Err(nested) = value.__groom_validate() {
+        for error in nested.errors {
+            errors.add(&format!("{field}.{}", error.field), error.code, error.message);
+        }
+    }
+}
+
+/// [`nested`] for each item of a collection, named `field[index]`.
+pub fn nested_items<T: DTO>(errors: &mut ValidationErrors, field: &str, values: &[T]) {
+    for (index, value) in values.iter().enumerate() {
+        nested(errors, &format!("{field}[{index}]"), value);
+    }
+}
+
+/// Values that `length` and `non_empty` apply to.
+///
+/// Strings are measured in characters, collections in items.
+pub trait HasLength {
+    fn length(&self) -> usize;
+}
+
+impl HasLength for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl HasLength for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T> HasLength for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: HasLength + ?Sized> HasLength for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+pub fn length<T: HasLength + ?Sized>(
+    errors: &mut ValidationErrors,
+    field: &str,
+    value: &T,
+    min: Option<usize>,
+    max: Option<usize>,
+) {
+    let length = value.length();
+
+    if let Some(min) = min && length < min {
+        errors.add(field, "length", format!("length must be at least {min}"));
+    } else if let Some(max) = max && length > max {
+        errors.add(field, "length", format!("length must be at most {max}"));
+    }
+}
+
+pub fn non_empty<T: HasLength + ?Sized>(errors: &mut ValidationErrors, field: &str, value: &T) {
+    if value.length() == 0 {
+        errors.add(field, "non_empty", "must not be empty".to_owned());
+    }
+}
+
+pub fn range<T: PartialOrd + Display>(
+    errors: &mut ValidationErrors,
+    field: &str,
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) {
+    if let Some(min) = min && *value < min {
+        errors.add(field, "range", format!("must be at least {min}"));
+    } else if let Some(max) = max && *value > max {
+        errors.add(field, "range", format!("must be at most {max}"));
+    }
+}
+
+pub fn pattern(errors: &mut ValidationErrors, field: &str, value: &str, regex: &LazyLock<Regex>) {
+    if !regex.is_match(value) {
+        errors.add(field, "pattern", format!("must match `{}`", regex.as_str()));
+    }
+}
+
+/// A deliberately loose check: one `@`, a non-empty local part and a dotted domain, no spaces.
+pub fn email(errors: &mut ValidationErrors, field: &str, value: &str) {
+    let valid = match value.split_once('@') {
+        Some((local, domain)) => !local.is_empty()
+            && !domain.contains('@')
+            && domain.split('.').count() > 1
+            && domain.split('.').all(|label| !label.is_empty())
+            && !value.chars().any(char::is_whitespace),
+        None => false,
+    };
+
+    if !valid {
+        errors.add(field, "email", "must be an email address".to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_counts_characters() {
+        let mut errors = ValidationErrors::new();
+        length(&mut errors, "name", "été", Some(3), Some(3));
+        assert!(errors.is_empty());
+
+        length(&mut errors, "name", "", Some(1), None);
+        assert_eq!(errors.errors[0].message, "length must be at least 1");
+    }
+
+    #[test]
+    fn range_reports_bounds() {
+        let mut errors = ValidationErrors::new();
+        range(&mut errors, "age", &150u8, Some(0), Some(130));
+        assert_eq!(errors.errors, vec![FieldError {
+            field: "age".into(),
+            code: "range",
+            message: "must be at most 130".into(),
+        }]);
+    }
+
+    #[test]
+    fn email_is_loose() {
+        let mut errors = ValidationErrors::new();
+        email(&mut errors, "email", "me+tag@example.co.uk");
+        assert!(errors.is_empty());
+
+        for value in ["me", "@example.com", "me@localhost", "me@example..com", "m e@example.com"] {
+            email(&mut errors, "email", value);
+        }
+        assert_eq!(errors.errors.len(), 5);
+    }
+}
@@ -0,0 +1,139 @@
+//! XML request and response bodies for `format(xml)`.
+//!
+//! [`Xml`] works like `axum::Json`: it is both an extractor and a response. (De)serialization
+//! goes through `serde` and `quick-xml`; the root element is named after the serialized type.
+
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request, rejection::BytesRejection},
+    http::{HeaderValue, StatusCode, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+use utoipa::{
+    ToSchema,
+    openapi::{RefOr, Schema, xml::XmlBuilder},
+};
+
+use crate::extract::ComponentsRegistry;
+
+/// XML body extractor and response.
+///
+/// The extractor does not check `Content-Type` — `#[RequestBody(format(xml))]` only calls it
+/// after the content type was negotiated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xml<T>(pub T);
+
+/// Rejection of the [`Xml`] extractor.
+#[derive(Debug, Error)]
+pub enum XmlRejection {
+    #[error(transparent)]
+    BytesRejection(#[from] BytesRejection),
+
+    #[error("XML body is not valid utf-8")]
+    InvalidUtf8,
+
+    #[error("Failed to deserialize the XML body into the target type: {0}")]
+    Deserialize(quick_xml::DeError),
+}
+
+impl IntoResponse for XmlRejection {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            XmlRejection::BytesRejection(r) => r.into_response(),
+            XmlRejection::InvalidUtf8 => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            XmlRejection::Deserialize(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for Xml<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = XmlRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state).await?;
+        let text = std::str::from_utf8(&bytes).map_err(|_| XmlRejection::InvalidUtf8)?;
+
+        quick_xml::de::from_str(text)
+            .map(Xml)
+            .map_err(XmlRejection::Deserialize)
+    }
+}
+
+impl<T: Serialize> IntoResponse for Xml<T> {
+    fn into_response(self) -> axum::response::Response {
+        match quick_xml::se::to_string(&self.0) {
+            Ok(body) => ([(CONTENT_TYPE, "application/xml")], body).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
+
+/// Relabels an `application/xml` response as `text/xml` for clients that asked for it.
+///
+/// Responses of other content types (e.g. a serialization error) are returned as is.
+pub fn as_text_xml(mut response: axum::response::Response) -> axum::response::Response {
+    let headers = response.headers_mut();
+
+    if headers.get(CONTENT_TYPE).is_some_and(|ct| ct == "application/xml") {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
+    }
+
+    response
+}
+
+/// Schema of `T` for `application/xml` content, carrying the OpenAPI `xml` object.
+///
+/// The schema is inlined so that the root element name stays out of the shared component
+/// (the same type may be documented as JSON elsewhere). Nested components are registered
+/// and stay references.
+pub fn xml_schema<T: ToSchema + 'static>(components: &mut ComponentsRegistry) -> RefOr<Schema> {
+    components.add_subcomponents::<T>();
+
+    let xml = Some(XmlBuilder::new().name(Some(T::name())).build());
+
+    match T::schema() {
+        RefOr::T(Schema::Object(mut object)) => {
+            object.xml = xml;
+            RefOr::T(Schema::Object(object))
+        },
+        RefOr::T(Schema::Array(mut array)) => {
+            array.xml = xml;
+            RefOr::T(Schema::Array(array))
+        },
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use utoipa::ToSchema;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, ToSchema, PartialEq, Debug)]
+    struct Person {
+        name: String,
+    }
+
+    #[test]
+    fn xml_schema_names_root_element() {
+        let mut components = ComponentsRegistry::new();
+        let json = serde_json::to_value(xml_schema::<Person>(&mut components)).unwrap();
+
+        assert_eq!(json["xml"], serde_json::json!({ "name": "Person" }));
+        assert_eq!(json["properties"]["name"]["type"], "string");
+    }
+
+    #[test]
+    fn serializes_with_type_name_as_root() {
+        let body = quick_xml::se::to_string(&Person { name: "Mark".into() }).unwrap();
+        assert_eq!(body, "<Person><name>Mark</name></Person>");
+    }
+}
@@ -6,8 +6,9 @@ use quote::{format_ident, quote, ToTokens};
 use darling::FromMeta;
 use syn::Attribute;
 
-use crate::{http::HTTPMethod, annotation_attrs::{parse_attr, remove_attrs}};
+use crate::{http::{HTTPMethod, HTTPMethods}, annotation_attrs::{parse_attr, remove_attrs}};
 use crate::comments::DocblockParts;
+use crate::operation::OperationArgs;
 
 // region: ControllerArgs  -----------------------------------------------------------
 //
@@ -21,6 +22,24 @@ pub(crate) struct ControllerArgs {
     /// Defaults to `()` (unit type).
     #[darling(default)]
     pub(crate) state_type: Option<syn::Expr>,
+
+    /// OpenAPI tag of every operation of the module; the module's doc comment becomes the tag
+    /// description.
+    #[darling(default)]
+    pub(crate) tag: Option<String>,
+
+    /// `#[Response]` types documented on every operation of the module, e.g. a shared `500`
+    /// error. Responses of the handler (and its extractors) with the same status code win.
+    #[darling(default)]
+    pub(crate) default_responses: darling::util::PathList,
+
+    /// First API version every route of the module exists in, unless the route gives its own
+    #[darling(default)]
+    pub(crate) since: Option<String>,
+
+    /// First API version no route of the module exists in, unless the route gives its own
+    #[darling(default)]
+    pub(crate) until: Option<String>,
 }
 
 //
@@ -31,8 +50,24 @@ pub(crate) struct ControllerArgs {
 
 #[derive(FromMeta)]
 pub(crate) struct RouteArgs {
-    pub(crate) method: HTTPMethod,
+    /// `method = "get"` or `method("put", "patch")`
+    pub(crate) method: HTTPMethods,
     pub(crate) path: String,
+
+    /// `operationId` surviving renames of the handler; defaults to its camel-cased name
+    #[darling(default)]
+    pub(crate) operation_id: Option<String>,
+
+    #[darling(flatten)]
+    pub(crate) operation: OperationArgs,
+
+    /// First API version the route exists in; defaults to the one of the controller
+    #[darling(default)]
+    pub(crate) since: Option<String>,
+
+    /// First API version the route no longer exists in; defaults to the one of the controller
+    #[darling(default)]
+    pub(crate) until: Option<String>,
 }
 
 impl RouteArgs {
@@ -43,6 +78,11 @@ impl RouteArgs {
     pub(crate) fn remove_from_attrs(attrs: &mut Vec<Attribute>) {
         remove_attrs("Route", attrs)
     }
+
+    /// Whether the route exists in some API versions only.
+    fn is_versioned(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
 }
 
 //
@@ -73,6 +113,18 @@ struct ModuleASTFragments {
 
     /// Runtime HTTP status/format check fragments.
     runtime_checks: Vec<TokenStream>,
+
+    /// Operation builder modifiers shared by every handler (controller tag, default responses).
+    operation_defaults: Vec<TokenStream>,
+
+    /// `since` and `until` of the controller, for routes without their own.
+    version_defaults: (Option<String>, Option<String>),
+
+    /// `VersionedRoute`s of the routes existing in some API versions only.
+    versioned_routes: Vec<TokenStream>,
+
+    /// `PathParameters` of the handlers with inputs, checked against their path by `validate()`.
+    path_parameters: Vec<TokenStream>,
 }
 
 struct HandlerASTFragments {
@@ -85,9 +137,15 @@ struct HandlerASTFragments {
     /// input arguments which get delegated into the original handler
     delegated_inputs: Vec<TokenStream>,
 
+    /// wrapper inputs checked with `GroomExtractor::__groom_validate` before the handler runs
+    validated_inputs: Vec<Ident>,
+
     /// ast fragments to add extractors into OpenAPI spec
     openapi_extractors_modifiers: Vec<TokenStream>,
 
+    /// ast fragments listing the path parameters read by each extractor
+    path_parameters_extractors: Vec<TokenStream>,
+
     /// entire AST to set up this handler OpenAPI spec
     openapi_modification_code: TokenStream,
 }
@@ -107,8 +165,30 @@ fn generate_controller_impl(_args_t: TokenStream, args: ControllerArgs, input: T
         openapi_paths_setup: IndexMap::new(),
         type_assertions: Vec::new(),
         runtime_checks: Vec::new(),
+        operation_defaults: Vec::new(),
+        version_defaults: (args.since.clone(), args.until.clone()),
+        versioned_routes: Vec::new(),
+        path_parameters: Vec::new(),
     };
 
+    if let Some(tag) = &args.tag {
+        fragments.operation_defaults.push(quote! {
+            op_builder = op_builder.tag(#tag);
+        });
+    }
+
+    for ty in args.default_responses.iter() {
+        fragments.type_assertions.push(quote! {
+            assert_impl_all!(#ty: ::groom::response::Response);
+        });
+
+        fragments.operation_defaults.push(quote! {
+            op_builder = <#ty as ::groom::response::Response>::__openapi_modify_operation(op_builder, &mut components);
+        });
+    }
+
+    let tag_description = crate::comments::get_docblock(&item_mod.attrs).map_err(|e| e.to_compile_error())?;
+
     for item in items {
         if let Item::Fn(mut function) = item {
             parse_handler_function(&mut function, &mut fragments)?;
@@ -117,7 +197,7 @@ fn generate_controller_impl(_args_t: TokenStream, args: ControllerArgs, input: T
         }
     }
 
-    Ok(generate_new_mod_ast(args, &item_mod.vis, &item_mod.ident, fragments))
+    Ok(generate_new_mod_ast(args, tag_description, &item_mod.vis, &item_mod.ident, fragments))
 }
 
 /// Analyzes handler's AST and breaks it into meaningful fragments
@@ -125,10 +205,18 @@ fn parse_handler_function(
     function: &mut ItemFn,
     mod_fragments: &mut ModuleASTFragments,
 ) -> Result<(), TokenStream> {
-    let Some(route) = extract_route_args(function, mod_fragments)? else {
+    let Some(mut route) = extract_route_args(function, mod_fragments)? else {
         return Ok(());
     };
 
+    let (since, until) = &mod_fragments.version_defaults;
+    route.since = route.since.or_else(|| since.clone());
+    route.until = route.until.or_else(|| until.clone());
+
+    if route.is_versioned() && route.method.0.contains(&HTTPMethod::Connect) {
+        return Err(Error::new_spanned(&function.sig, "CONNECT routes cannot exist in some API versions only").to_compile_error());
+    }
+
     if function.sig.asyncness.is_none() {
         return Err(Error::new_spanned(function.sig.fn_token, "handler should be async fn").to_compile_error());
     }
@@ -138,6 +226,7 @@ fn parse_handler_function(
     let mut fn_fragments = generate_handler_fragments(function, mod_fragments)?;
 
     generate_router_modifier_for_handler(&fn_fragments.wrapper_name, &route, mod_fragments);
+    generate_path_parameters_ast(function, &fn_fragments, &route, mod_fragments);
     fn_fragments.openapi_modification_code = generate_openapi_modifier_for_handler(function, mod_fragments)?;
 
     let docblock = crate::comments::get_docblock_parts(&function.attrs).unwrap_or_default();
@@ -180,28 +269,36 @@ fn extract_route_args(function: &mut ItemFn, mod_fragments: &mut ModuleASTFragme
 }
 
 /// Checks if there is a duplicate of handler. If a duplicate is found, emits a compile error.
+///
+/// Routes of different API versions may share a method and path; `validate()` checks them per
+/// version.
 fn ensure_handler_is_unique(handler: &mut ItemFn, route: &RouteArgs, mod_fragments: &mut ModuleASTFragments) -> Result<(), TokenStream> {
+    if route.is_versioned() {
+        return Ok(());
+    }
+
     let path = &route.path;
-    let method = &route.method;
 
     let fn_name = &handler.sig.ident;
 
-    let duplicate_handler = mod_fragments.seen_handlers
-        .entry(path.clone())
-        .or_default()
-        .insert(*method, fn_name.to_string())
-    ;
-
-    if let Some(name) = duplicate_handler {
-        return Err(Error::new_spanned(
-            &handler.sig,
-            format!(
-                "duplicate handler: function named `{}` is already assigned to route `{} {}`",
-                name,
-                method,
-                path,
-            )
-        ).to_compile_error());
+    for method in &route.method.0 {
+        let duplicate_handler = mod_fragments.seen_handlers
+            .entry(path.clone())
+            .or_default()
+            .insert(*method, fn_name.to_string())
+        ;
+
+        if let Some(name) = duplicate_handler {
+            return Err(Error::new_spanned(
+                &handler.sig,
+                format!(
+                    "duplicate handler: function named `{}` is already assigned to route `{} {}`",
+                    name,
+                    method,
+                    path,
+                )
+            ).to_compile_error());
+        }
     }
     Ok(())
 }
@@ -211,8 +308,10 @@ fn generate_handler_fragments(handler: &mut ItemFn, mod_fragments: &mut ModuleAS
     let mut fn_fragments = HandlerASTFragments {
         wrapper_name: format_ident!("__groom_wrapper_{}", &handler.sig.ident),
         openapi_extractors_modifiers: Vec::new(),
+        path_parameters_extractors: Vec::new(),
         wrapper_inputs: Vec::new(),
         delegated_inputs: Vec::new(),
+        validated_inputs: Vec::new(),
         openapi_modification_code: Default::default(),
     };
 
@@ -237,6 +336,10 @@ fn generate_handler_fragments(handler: &mut ItemFn, mod_fragments: &mut ModuleAS
                     op_builder = <#ty>::__openapi_modify_operation(op_builder, &mut components);
                 });
 
+                fn_fragments.path_parameters_extractors.push(quote! {
+                    <#ty as ::groom::extract::GroomExtractor>::__groom_path_parameters()
+                });
+
                 let input_ident = format_ident!("input{}", fn_fragments.delegated_inputs.len());
 
                 fn_fragments.wrapper_inputs.push(quote! {
@@ -246,6 +349,8 @@ fn generate_handler_fragments(handler: &mut ItemFn, mod_fragments: &mut ModuleAS
                 fn_fragments.delegated_inputs.push(quote! {
                     #input_ident,
                 });
+
+                fn_fragments.validated_inputs.push(input_ident);
             },
         }
     }
@@ -280,28 +385,52 @@ fn generate_openapi_paths_setup_ast(
     mod_fragments: &mut ModuleASTFragments
 ) {
     let path = &route.path;
-    let method = &route.method;
 
-    if matches!(method, HTTPMethod::Connect) {
-        // CONNECT routes keep axum routing (installed by generate_router_modifier_for_handler)
-        // but are omitted from OpenAPI: OpenAPI 3 path items cannot model CONNECT via
-        // utoipa's HttpMethod (the enum has no Connect variant).
+    // CONNECT routes keep axum routing (installed by generate_router_modifier_for_handler)
+    // but are omitted from OpenAPI: OpenAPI 3 path items cannot model CONNECT via
+    // utoipa's HttpMethod (the enum has no Connect variant).
+    let methods: Vec<HTTPMethod> = route.method.0.iter()
+        .copied()
+        .filter(|method| !matches!(method, HTTPMethod::Connect))
+        .collect();
+
+    if methods.is_empty() {
         return;
     }
 
-    let operation = match method {
-        HTTPMethod::Delete   => quote! {::utoipa::openapi::path::HttpMethod::Delete },
-        HTTPMethod::Get      => quote! {::utoipa::openapi::path::HttpMethod::Get    },
-        HTTPMethod::Head     => quote! {::utoipa::openapi::path::HttpMethod::Head   },
-        HTTPMethod::Options  => quote! {::utoipa::openapi::path::HttpMethod::Options},
-        HTTPMethod::Patch    => quote! {::utoipa::openapi::path::HttpMethod::Patch  },
-        HTTPMethod::Post     => quote! {::utoipa::openapi::path::HttpMethod::Post   },
-        HTTPMethod::Put      => quote! {::utoipa::openapi::path::HttpMethod::Put    },
-        HTTPMethod::Trace    => quote! {::utoipa::openapi::path::HttpMethod::Trace  },
-        // The compiler cannot see that the early return above excludes CONNECT,
-        // so the match needs this arm. It is never reached.
-        HTTPMethod::Connect  => unreachable!("CONNECT is handled by the early return above"),
-    };
+    // one operation per documented method; their ids get the method as suffix when there are several
+    let is_single_method = methods.len() == 1;
+
+    let operations = methods.iter().map(|method| {
+        let operation = match method {
+            HTTPMethod::Delete   => quote! {::utoipa::openapi::path::HttpMethod::Delete },
+            HTTPMethod::Get      => quote! {::utoipa::openapi::path::HttpMethod::Get    },
+            HTTPMethod::Head     => quote! {::utoipa::openapi::path::HttpMethod::Head   },
+            HTTPMethod::Options  => quote! {::utoipa::openapi::path::HttpMethod::Options},
+            HTTPMethod::Patch    => quote! {::utoipa::openapi::path::HttpMethod::Patch  },
+            HTTPMethod::Post     => quote! {::utoipa::openapi::path::HttpMethod::Post   },
+            HTTPMethod::Put      => quote! {::utoipa::openapi::path::HttpMethod::Put    },
+            HTTPMethod::Trace    => quote! {::utoipa::openapi::path::HttpMethod::Trace  },
+            // The compiler cannot see that the filter above excludes CONNECT,
+            // so the match needs this arm. It is never reached.
+            HTTPMethod::Connect  => unreachable!("CONNECT is filtered out above"),
+        };
+
+        let operation_id = match (&route.operation_id, is_single_method) {
+            (Some(id), true) => id.clone(),
+            (Some(id), false) => format!("{id}{}", method.to_string().to_case(Case::Pascal)),
+            (None, true) => handler.sig.ident.to_string().to_case(Case::Camel),
+            (None, false) => format!("{}_{method}", handler.sig.ident).to_case(Case::Camel),
+        };
+
+        quote! {
+            .operation(#operation, {
+                let mut operation = operation.clone();
+                operation.operation_id = Some(#operation_id.to_owned());
+                operation
+            })
+        }
+    });
 
     let summary_tk = match &docblock.summary {
         Some(s) => quote! { Some(#s) },
@@ -313,27 +442,57 @@ fn generate_openapi_paths_setup_ast(
         None => quote! { None as Option<String> },
     };
 
+    let defaults = &mod_fragments.operation_defaults;
+    let metadata = route.operation.to_builder_ast();
+
+    let build_operation = match route.operation.to_operation_ast() {
+        Some(modifiers) => quote! {
+            let mut operation = op_builder.build();
+            #modifiers
+        },
+        None => quote! { let operation = op_builder.build(); },
+    };
     let extractors = &fn_fragments.openapi_extractors_modifiers;
     let openapi_setup = &fn_fragments.openapi_modification_code;
 
-    let operation_id = handler.sig.ident.to_string().to_case(Case::Camel);
-
-    mod_fragments.openapi_paths_setup.entry(path.clone()).or_default().push(quote! {
+    let path_item = quote! {
         {
             let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                     .summary(#summary_tk)
                     .description(#description_tk)
-                    .operation_id(Some(#operation_id))
             ;
 
+            #(#defaults)*
+
+            #metadata
+
             #(#extractors)*
 
             #openapi_setup
 
+            #build_operation
+
             ::utoipa::openapi::path::PathItemBuilder::new()
-                .operation(#operation, op_builder.build())
+                #(#operations)*
                 .build()
         }
+    };
+
+    if !route.is_versioned() {
+        mod_fragments.openapi_paths_setup.entry(path.clone()).or_default().push(path_item);
+        return;
+    }
+
+    let bound = |version: &Option<String>| match version {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    };
+    let since = bound(&route.since);
+    let until = bound(&route.until);
+    let method_router = method_router_ast(&fn_fragments.wrapper_name, route);
+
+    mod_fragments.versioned_routes.push(quote! {
+        ::groom::router::VersionedRoute::new(#path, #since, #until, #method_router, #path_item)
     });
 }
 
@@ -343,16 +502,52 @@ fn generate_router_modifier_for_handler(
     route: &RouteArgs,
     mod_fragments: &mut ModuleASTFragments
 ) {
-    let path = &route.path;
-    let method = &route.method;
+    // versioned routes are installed by `GroomRouter` (see generate_openapi_paths_setup_ast)
+    if route.is_versioned() {
+        return;
+    }
 
-    let routing_method = format_ident!("{}", method.to_string());
+    let path = &route.path;
+    let method_router = method_router_ast(handler_wrapper_name, route);
 
     mod_fragments.routes_setup.push(quote! {
-        .route(#path, ::axum::routing::#routing_method(#handler_wrapper_name))
+        .route(#path, #method_router)
     });
 }
 
+/// Generates AST listing the path parameters read by the handler, for `validate()` to check them
+/// against its path
+fn generate_path_parameters_ast(
+    handler: &ItemFn,
+    fn_fragments: &HandlerASTFragments,
+    route: &RouteArgs,
+    mod_fragments: &mut ModuleASTFragments
+) {
+    let extractors = &fn_fragments.path_parameters_extractors;
+
+    if extractors.is_empty() {
+        return;
+    }
+
+    let path = &route.path;
+    let handler_name = handler.sig.ident.to_string();
+
+    mod_fragments.path_parameters.push(quote! {
+        ::groom::router::PathParameters::new(#path, #handler_name, [#(#extractors),*])
+    });
+}
+
+/// Generates AST of one `MethodRouter` for all methods of the handler
+fn method_router_ast(handler_wrapper_name: &Ident, route: &RouteArgs) -> TokenStream {
+    let mut routing_methods = route.method.0.iter().map(|method| format_ident!("{}", method.to_string()));
+
+    let first_method = routing_methods.next().expect("`method` of `#[Route]` is never empty");
+
+    quote! {
+        ::axum::routing::#first_method(#handler_wrapper_name)#(.#routing_methods(#handler_wrapper_name))*
+    }
+}
+
 /// Generates new AST for the original handler and it's wrapper
 fn generate_new_handler_ast(
     original_handler: &ItemFn,
@@ -362,10 +557,12 @@ fn generate_new_handler_ast(
     mod_fragments: &mut ModuleASTFragments,
 ) {
     let path = &route.path;
-    let method = &route.method;
 
     // make new module item:
-    let method_str = method.to_string().to_ascii_uppercase();
+    let method_str = route.method.0.iter()
+        .map(|method| method.to_string().to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join(", ");
     let handler_comment = format!(" HTTP handler: {method_str} {path}");
 
     let mut new_comment: Vec<TokenStream> = Vec::new();
@@ -384,6 +581,18 @@ fn generate_new_handler_ast(
     let wrapper_name = &fn_fragments.wrapper_name;
     let wrapper_inputs = &fn_fragments.wrapper_inputs;
     let delegated_inputs = &fn_fragments.delegated_inputs;
+    let validated_inputs = &fn_fragments.validated_inputs;
+    let validation = (!validated_inputs.is_empty()).then(|| quote! {
+        let mut validation_errors = ::groom::validation::ValidationErrors::new();
+        #(
+            if let ::core::result::Result::Err(errors) = ::groom::extract::GroomExtractor::__groom_validate(&#validated_inputs) {
+                validation_errors.append(errors);
+            }
+        )*
+        if !validation_errors.is_empty() {
+            return ::axum::response::IntoResponse::into_response(validation_errors);
+        }
+    });
     let fn_name = &original_handler.sig.ident;
 
     // The wrapper needs the handler's return type to call its associated
@@ -402,7 +611,11 @@ fn generate_new_handler_ast(
         #(#new_comment)*
         #original_handler
 
-        async fn #wrapper_name(headers: ::axum::http::header::HeaderMap, #(#wrapper_inputs)*) -> impl ::axum::response::IntoResponse {
+        async fn #wrapper_name(
+            headers: ::axum::http::header::HeaderMap,
+            locale: ::core::option::Option<::groom::locale::Locale>,
+            #(#wrapper_inputs)*
+        ) -> impl ::axum::response::IntoResponse {
             let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
                 Err(_) => return ::groom::response::bad_accept_header(),
                 Ok(accept) => accept,
@@ -411,14 +624,24 @@ fn generate_new_handler_ast(
             let negotiated = match accept {
                 None => None,
                 Some(accept) => match <#return_ty>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => return ::axum::response::IntoResponse::into_response(not_acceptable),
                     Ok(negotiated) => negotiated,
                 },
             };
 
-            let result = #fn_name(#(#delegated_inputs)*).await;
+            #validation
 
-            result.__groom_into_response(negotiated)
+            let result = #fn_name(#(#delegated_inputs)*).await;
+            let compression = ::groom::response::Response::__groom_compression(&result);
+            let response = result.__groom_into_response(negotiated, &::groom::response::RenderContext::new(locale));
+
+            match compression {
+                None => response,
+                Some(compression) => {
+                    let coding = ::groom::response::compression::negotiate_encoding(&headers);
+                    compression.compress(coding, response).await
+                },
+            }
         }
     });
 }
@@ -426,6 +649,7 @@ fn generate_new_handler_ast(
 /// Generates new AST for the entire mod based on parsed fragments
 fn generate_new_mod_ast(
     args: ControllerArgs,
+    tag_description: Option<String>,
     vis: &Visibility,
     ident: &Ident,
     fragments: ModuleASTFragments
@@ -445,6 +669,35 @@ fn generate_new_mod_ast(
         || syn::parse_str::<syn::Expr>("()").unwrap()
     );
 
+    let with_tag = args.tag.map(|tag| {
+        let description = match tag_description {
+            Some(d) => quote! { Some(#d) },
+            None => quote! { None },
+        };
+
+        quote! { .with_controller_tag(#tag, #description) }
+    });
+
+    let versioned_routes = fragments.versioned_routes;
+    let (versioned_setup, with_versioned) = if versioned_routes.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(quote! { let __groom_versioned = ::std::vec![#(#versioned_routes),*]; }),
+            Some(quote! { .with_versioned_routes(__groom_versioned) }),
+        )
+    };
+
+    let path_parameters = fragments.path_parameters;
+    let (path_parameters_setup, with_path_parameters) = if path_parameters.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(quote! { let __groom_path_parameters = ::std::vec![#(#path_parameters),*]; }),
+            Some(quote! { .with_path_parameters(__groom_path_parameters) }),
+        )
+    };
+
     let module_items = fragments.module_items;
     let routes_setup = fragments.routes_setup;
     let type_assertions = fragments.type_assertions;
@@ -474,9 +727,14 @@ fn generate_new_mod_ast(
                 let mut components = ::groom::extract::ComponentsRegistry::new();
                 let mut __groom_paths: ::std::vec::Vec<(::std::string::String, ::utoipa::openapi::path::PathItem)> = ::std::vec::Vec::new();
                 #(#path_assignments)*
+                #versioned_setup
+                #path_parameters_setup
                 ::groom::router::GroomRouter::from_controller_parts(
                     this_router, components, __groom_paths
                 )
+                #with_tag
+                #with_versioned
+                #with_path_parameters
             }
 
             pub fn merge_into_router(other: impl Into<::groom::router::GroomRouter<#state_ty>>) -> ::std::result::Result<::groom::router::GroomRouter<#state_ty>, ::groom::router::MergeError> {
@@ -489,9 +747,14 @@ fn generate_new_mod_ast(
                 let mut components = ::groom::extract::ComponentsRegistry::new();
                 let mut __groom_paths: ::std::vec::Vec<(::std::string::String, ::utoipa::openapi::path::PathItem)> = ::std::vec::Vec::new();
                 #(#path_assignments)*
+                #versioned_setup
+                #path_parameters_setup
                 let __groom_this = ::groom::router::GroomRouter::from_controller_parts(
                     this_router, components, __groom_paths
-                );
+                )
+                #with_tag
+                #with_versioned
+                #with_path_parameters;
                 let __groom_other = other.into();
                 __groom_other.merge(__groom_this)
             }
@@ -5,6 +5,8 @@ use proc_macro2::TokenStream;
 use syn::parse2;
 use quote::{quote};
 
+use crate::validation::take_field_constraints;
+
 // region: Annotation args -------------------------------------------------------------------------
 //
 
@@ -19,6 +21,10 @@ pub(crate) struct DtoArgs {
 
     #[darling(default)]
     pub(crate) parameters: bool,
+
+    /// Struct is an extractor of request cookies (`in: cookie` parameters).
+    #[darling(default)]
+    pub(crate) cookies: bool,
 }
 
 //
@@ -47,7 +53,12 @@ pub(crate) fn generate(args_t: TokenStream, args: DtoArgs, input: TokenStream) -
 }
 
 /// Generates `#[DTO]` from a struct.
-fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, item_struct: ItemStruct) -> TokenStream {
+fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, mut item_struct: ItemStruct) -> TokenStream {
+    let validate_body = match take_field_constraints(&mut item_struct, args.parameters || args.cookies) {
+        Ok(body) => body,
+        Err(error) => return error,
+    };
+
     let ident = &item_struct.ident;
 
     let dto_request_impl =
@@ -58,7 +69,7 @@ fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, item_struct: It
         };
     
     let deserialize_derive =
-        if args.request || args.parameters {
+        if args.request || args.parameters || args.cookies {
             quote! { #[derive(::serde::Deserialize)] }
         } else {
             Default::default()
@@ -81,13 +92,53 @@ fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, item_struct: It
         };
 
     let into_parameters_derive =
-        if args.parameters {
+        if args.parameters || args.cookies {
             quote! { #[derive(::utoipa::IntoParams)] }
         } else {
             Default::default()
         }
     ;
 
+    let cookies_extractor_impl =
+        if args.cookies {
+            quote! {
+                impl<S: Send + Sync> ::axum::extract::FromRequestParts<S> for #ident {
+                    type Rejection = ::groom::extract::cookies::CookiesRejection;
+
+                    async fn from_request_parts(parts: &mut ::axum::http::request::Parts, _state: &S)
+                        -> ::core::result::Result<Self, Self::Rejection>
+                    {
+                        ::groom::extract::cookies::from_cookie_headers(&parts.headers)
+                    }
+                }
+
+                impl ::groom::extract::GroomExtractor for #ident {
+                    fn __openapi_modify_operation(
+                        op: ::utoipa::openapi::path::OperationBuilder,
+                        registry: &mut ::groom::extract::ComponentsRegistry
+                    ) -> ::utoipa::openapi::path::OperationBuilder {
+                        ::groom::extract::cookies::openapi_modify_operation::<Self>(op, registry)
+                    }
+
+                    fn __groom_validate(&self) -> ::core::result::Result<(), ::groom::validation::ValidationErrors> {
+                        ::groom::DTO::__groom_validate(self)
+                    }
+                }
+            }
+        } else {
+            Default::default()
+        };
+
+    let validate_fn = validate_body.map(|body| quote! {
+        fn __groom_validate(&self) -> ::core::result::Result<(), ::groom::validation::ValidationErrors> {
+            #body
+        }
+
+        fn __groom_validates() -> bool {
+            true
+        }
+    });
+
     let openapi_derive = derive_openapi_schema_generation();
 
     quote! {
@@ -97,10 +148,13 @@ fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, item_struct: It
         #into_parameters_derive
         #item_struct
 
-        impl ::groom::DTO for #ident {}
+        impl ::groom::DTO for #ident {
+            #validate_fn
+        }
 
         #dto_request_impl
         #dto_response_impl
+        #cookies_extractor_impl
     }
 }
 
@@ -108,6 +162,13 @@ fn generate_impl_for_struct(_args_t: TokenStream, args: DtoArgs, item_struct: It
 fn generate_impl_for_enum(_args_t: TokenStream, args: DtoArgs, item_enum: ItemEnum) -> TokenStream {
     let ident = &item_enum.ident;
 
+    if args.cookies {
+        return Error::new_spanned(
+            ident,
+            "error in `#[DTO]` annotation: `cookies` DTO should be a struct with a field per cookie"
+        ).to_compile_error();
+    }
+
     let (deserialize_derive, dto_request_impl) =
         if !args.request {
             Default::default()
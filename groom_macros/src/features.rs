@@ -0,0 +1,22 @@
+use proc_macro2::TokenStream;
+
+/// Fails with a compile error when a requested format needs a cargo feature that is disabled.
+///
+/// `enabled` is expected to be `cfg!(feature = "...")` of this crate; the feature forwards
+/// to the `groom` feature that carries the runtime support.
+pub(crate) fn ensure_format_feature(
+    requested: bool,
+    enabled: bool,
+    format: &str,
+    feature: &str,
+    span: &TokenStream,
+) -> Result<(), TokenStream> {
+    if requested && !enabled {
+        return Err(syn::Error::new_spanned(
+            span,
+            format!("`format({format})` requires the `{feature}` cargo feature of `groom_macros`")
+        ).into_compile_error());
+    }
+
+    Ok(())
+}
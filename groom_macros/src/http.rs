@@ -1,4 +1,4 @@
-use darling::{self, FromMeta};
+use darling::{self, FromMeta, ast::NestedMeta};
 use strum_macros::Display;
 
 /// This enum is used to parse `method` argument of `#[Route()]` annotation
@@ -18,6 +18,45 @@ pub(crate) enum HTTPMethod {
     Trace,
 }
 
+/// This newtype is used to parse `method` argument of `#[Route()]` annotation:
+/// either a single method (`method = "get"`) or a list (`method("put", "patch")`).
+#[derive(Debug, Clone)]
+pub(crate) struct HTTPMethods(pub(crate) Vec<HTTPMethod>);
+
+impl FromMeta for HTTPMethods {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        HTTPMethod::from_string(value).map(|method| Self(vec![method]))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        if items.is_empty() {
+            return Err(darling::Error::too_few_items(1));
+        }
+
+        let mut errors = darling::Error::accumulator();
+        let mut methods = Vec::with_capacity(items.len());
+
+        for item in items {
+            let method = match item {
+                NestedMeta::Lit(lit) => HTTPMethod::from_value(lit).map_err(|e| e.with_span(lit)),
+                NestedMeta::Meta(meta) => Err(darling::Error::unexpected_type("meta").with_span(meta)),
+            };
+
+            let Some(method) = errors.handle(method) else {
+                continue;
+            };
+
+            if methods.contains(&method) {
+                errors.push(darling::Error::custom(format!("duplicate method `{method}`")).with_span(item));
+            } else {
+                methods.push(method);
+            }
+        }
+
+        errors.finish_with(Self(methods))
+    }
+}
+
 /// This newtype is used to parse `code` argument of `#[Response()]` annotation.
 ///
 /// Default value is `200 OK`.
@@ -11,9 +11,12 @@ mod annotation_attrs;
 mod comments;
 mod controller;
 mod dto;
+mod features;
 mod http;
+mod operation;
 mod request_body;
 mod response;
+mod validation;
 
 /// Macro to parse arguments of proc macros into structs
 /// (like `default_format` part of `#[Response(default_format = "json")]`).
@@ -89,7 +92,7 @@ pub fn Response(args: TokenStream, input: TokenStream) -> TokenStream {
 pub fn DTO(args: TokenStream, input: TokenStream) -> TokenStream {
     let args: proc_macro2::TokenStream = args.into();
     if args.is_empty() {
-        abort!(args, "error in `#[DTO]` annotation: specify `request`, `response`, `parameters`, or all as DTO arguments (e.g. `#[DTO(request, response)`])")
+        abort!(args, "error in `#[DTO]` annotation: specify `request`, `response`, `parameters`, `cookies`, or all as DTO arguments (e.g. `#[DTO(request, response)`])")
     }
 
     let dto_args = match extract_macro_arguments!(dto::DtoArgs, &args) {
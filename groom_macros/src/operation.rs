@@ -0,0 +1,197 @@
+//! OpenAPI operation metadata given in `#[Route(...)]`: `tags`, `deprecated`, `external_docs`,
+//! `security` and `extensions`.
+
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Expr, LitStr, Token, bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// `#[Route(...)]` arguments copied as is into the operation.
+#[derive(FromMeta, Default)]
+pub(crate) struct OperationArgs {
+    /// `tags("tasks", "admin")`, after the controller tag
+    #[darling(default)]
+    pub(crate) tags: Vec<LitStr>,
+
+    /// `deprecated` or `deprecated = true`
+    #[darling(default)]
+    pub(crate) deprecated: bool,
+
+    /// `external_docs(url = "...", description = "...")`
+    #[darling(default)]
+    pub(crate) external_docs: Option<ExternalDocsArgs>,
+
+    /// `security(("oauth" = ["read:tasks"]), ())`
+    #[darling(default)]
+    pub(crate) security: Option<SecurityArgs>,
+
+    /// `extensions("x-rate-limit" = 100, "x-internal" = true)`
+    #[darling(default)]
+    pub(crate) extensions: Option<ExtensionsArgs>,
+}
+
+impl OperationArgs {
+    /// Statements applying the metadata to `op_builder`, except for `external_docs`.
+    pub(crate) fn to_builder_ast(&self) -> TokenStream {
+        let mut result = TokenStream::new();
+
+        for tag in &self.tags {
+            result.extend(quote! { op_builder = op_builder.tag(#tag); });
+        }
+
+        if self.deprecated {
+            result.extend(quote! {
+                op_builder = op_builder.deprecated(Some(::utoipa::openapi::Deprecated::True));
+            });
+        }
+
+        if let Some(SecurityArgs(requirements)) = &self.security {
+            let requirements = requirements.iter().map(|schemes| {
+                let schemes = schemes.iter().map(|SecuritySchemeArg { name, scopes }| quote! {
+                    .add(#name, ::std::vec::Vec::<&str>::from([#(#scopes),*]))
+                });
+
+                quote! { ::utoipa::openapi::security::SecurityRequirement::default() #(#schemes)* }
+            });
+
+            result.extend(quote! {
+                op_builder = op_builder.securities(Some(
+                    ::std::vec::Vec::<::utoipa::openapi::security::SecurityRequirement>::from([#(#requirements),*])
+                ));
+            });
+        }
+
+        if let Some(ExtensionsArgs(extensions)) = &self.extensions {
+            let extensions = extensions.iter().map(|ExtensionArg { name, value }| quote! {
+                .add(#name, #value)
+            });
+
+            result.extend(quote! {
+                op_builder = op_builder.extensions(Some(
+                    ::utoipa::openapi::extensions::ExtensionsBuilder::new() #(#extensions)* .build()
+                ));
+            });
+        }
+
+        result
+    }
+
+    /// Statements applying the metadata that `OperationBuilder` has no setter for to the built
+    /// `operation`; `None` when there is none.
+    pub(crate) fn to_operation_ast(&self) -> Option<TokenStream> {
+        let ExternalDocsArgs { url, description } = self.external_docs.as_ref()?;
+
+        let description = match description {
+            Some(d) => quote! { Some(#d) },
+            None => quote! { None as Option<String> },
+        };
+
+        Some(quote! {
+            operation.external_docs = Some(
+                ::utoipa::openapi::external_docs::ExternalDocsBuilder::new()
+                    .url(#url)
+                    .description(#description)
+                    .build()
+            );
+        })
+    }
+}
+
+#[derive(FromMeta)]
+pub(crate) struct ExternalDocsArgs {
+    url: String,
+
+    #[darling(default)]
+    description: Option<String>,
+}
+
+/// Alternative security requirements of an operation, in the syntax of utoipa's
+/// `#[utoipa::path(security(...))]`: each parenthesized group is one requirement, listing the
+/// schemes it needs with their scopes. `()` makes authentication optional and an empty
+/// `security()` turns it off for the operation.
+pub(crate) struct SecurityArgs(Vec<Vec<SecuritySchemeArg>>);
+
+/// `"name" = ["scope", ...]`, or `"name"` without scopes.
+pub(crate) struct SecuritySchemeArg {
+    name: LitStr,
+    scopes: Vec<LitStr>,
+}
+
+impl FromMeta for SecurityArgs {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let list = item.require_list()?;
+
+        let requirements = list.parse_args_with(Punctuated::<SecurityRequirementArg, Token![,]>::parse_terminated)?;
+
+        Ok(Self(requirements.into_iter().map(|r| r.0).collect()))
+    }
+}
+
+struct SecurityRequirementArg(Vec<SecuritySchemeArg>);
+
+impl Parse for SecurityRequirementArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let schemes = Punctuated::<SecuritySchemeArg, Token![,]>::parse_terminated(&content)?;
+
+        Ok(Self(schemes.into_iter().collect()))
+    }
+}
+
+impl Parse for SecuritySchemeArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+
+        if !input.peek(Token![=]) {
+            return Ok(Self { name, scopes: Vec::new() });
+        }
+
+        input.parse::<Token![=]>()?;
+
+        let content;
+        bracketed!(content in input);
+
+        let scopes = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+
+        Ok(Self { name, scopes: scopes.into_iter().collect() })
+    }
+}
+
+/// Specification extensions: `"x-name" = value`, where the value is any expression converting
+/// into a `serde_json::Value` (string, number and bool literals, among others).
+pub(crate) struct ExtensionsArgs(Vec<ExtensionArg>);
+
+struct ExtensionArg {
+    name: LitStr,
+    value: Expr,
+}
+
+impl FromMeta for ExtensionsArgs {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let list = item.require_list()?;
+
+        let extensions = list.parse_args_with(Punctuated::<ExtensionArg, Token![,]>::parse_terminated)?;
+
+        Ok(Self(extensions.into_iter().collect()))
+    }
+}
+
+impl Parse for ExtensionArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+
+        if !name.value().starts_with("x-") {
+            return Err(syn::Error::new(name.span(), "extension names must start with `x-`"));
+        }
+
+        input.parse::<Token![=]>()?;
+
+        Ok(Self { name, value: input.parse()? })
+    }
+}
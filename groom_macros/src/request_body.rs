@@ -10,6 +10,10 @@ use syn::{Error, Item, parse2};
 pub(crate) struct RequestBodyArgs {
     #[darling(default)]
     pub(crate) format: RequestBodyTypesList,
+
+    /// Body size limit, e.g. `max_size = "64KiB"`
+    #[darling(default)]
+    pub(crate) max_size: Option<String>,
 }
 
 /// `#[RequestBody(format(...))]` values
@@ -20,11 +24,83 @@ pub(crate) struct RequestBodyTypesList {
 
     #[darling(default)]
     pub(crate) json: bool,
+
+    #[darling(default)]
+    pub(crate) multipart: bool,
+
+    #[darling(default)]
+    pub(crate) xml: bool,
+
+    #[darling(default)]
+    pub(crate) cbor: bool,
+
+    #[darling(default)]
+    pub(crate) msgpack: bool,
+
+    #[darling(default)]
+    pub(crate) plain_text: bool,
 }
 
 impl RequestBodyTypesList {
     pub(crate) fn is_any(&self) -> bool {
-        self.url_encoded || self.json
+        self.url_encoded || self.json || self.multipart || self.xml || self.cbor || self.msgpack || self.plain_text
+    }
+
+    /// `text/plain` is the only format: the wrapped type needs `FromStr`, but no schema or `DTO`.
+    pub(crate) fn is_plain_text_only(&self) -> bool {
+        self.plain_text && !(self.url_encoded || self.json || self.multipart || self.xml || self.cbor || self.msgpack)
+    }
+
+    /// Formats that are decoded through `serde::Deserialize` of the whole body.
+    pub(crate) fn needs_deserialize(&self) -> bool {
+        self.url_encoded || self.json || self.xml || self.cbor || self.msgpack
+    }
+}
+
+/// Parses a byte size such as `1024`, `512B`, `64KB` (decimal) or `64KiB` (binary).
+pub(crate) fn parse_byte_size(value: &str) -> Result<usize, String> {
+    let value = value.trim();
+    let split_at = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split_at);
+
+    let number = number.parse::<usize>()
+        .map_err(|_| format!("`{value}` should start with a whole number of bytes"))?;
+
+    let multiplier: usize = match unit.trim() {
+        "" | "B" => 1,
+        "KB" | "kB" => 1_000,
+        "KiB" => 1 << 10,
+        "MB" => 1_000_000,
+        "MiB" => 1 << 20,
+        "GB" => 1_000_000_000,
+        "GiB" => 1 << 30,
+        other => return Err(format!("unknown unit `{other}` in `{value}`, expected one of B, KB, KiB, MB, MiB, GB, GiB")),
+    };
+
+    number.checked_mul(multiplier)
+        .filter(|size| *size > 0)
+        .ok_or_else(|| format!("`{value}` is not a valid body size"))
+}
+
+#[cfg(test)]
+mod byte_size_tests {
+    use super::parse_byte_size;
+
+    #[test]
+    fn parses_decimal_and_binary_units() {
+        assert_eq!(parse_byte_size("512"), Ok(512));
+        assert_eq!(parse_byte_size("512B"), Ok(512));
+        assert_eq!(parse_byte_size("64KB"), Ok(64_000));
+        assert_eq!(parse_byte_size("64KiB"), Ok(65_536));
+        assert_eq!(parse_byte_size("2 MiB"), Ok(2 * 1024 * 1024));
+    }
+
+    #[test]
+    fn rejects_invalid_sizes() {
+        assert!(parse_byte_size("").is_err());
+        assert!(parse_byte_size("0KiB").is_err());
+        assert!(parse_byte_size("1.5MiB").is_err());
+        assert!(parse_byte_size("64kib").is_err());
     }
 }
 
@@ -62,9 +138,12 @@ fn generate_req_body_implementation(args_t: TokenStream, args: RequestBodyArgs,
 mod struct_impl {
     use proc_macro2::{Ident, TokenStream};
     use quote::{format_ident, quote, ToTokens};
-    use syn::{Fields, ItemStruct};
-    use crate::request_body::RequestBodyArgs;
+    use syn::{Fields, GenericArgument, ItemStruct, PathArguments, Type};
+    use syn::ext::IdentExt;
+    use crate::request_body::{RequestBodyArgs, parse_byte_size};
     use crate::comments::get_docblock;
+    use crate::features::ensure_format_feature;
+    use crate::validation::take_field_constraints;
 
     /// All AST fragments for implementation generation
     struct AllFragments {
@@ -76,12 +155,38 @@ mod struct_impl {
         dto_fragments: DtoFragments,
 
         body_extractors: Vec<TokenStream>,
+        /// `BodyContentType`s of `body_extractors`, checked before the body is read
+        body_content_types: Vec<TokenStream>,
         rejection_types: Vec<TokenStream>,
         rejections_into_response: Vec<TokenStream>,
         openapi_generators: Vec<TokenStream>,
 
         /// compile-time checks of trait implementation (for better error messages)
-        type_assertions: Vec<TokenStream>
+        type_assertions: Vec<TokenStream>,
+
+        /// derives of the output struct that depend on requested formats
+        derives: Vec<TokenStream>,
+
+        /// whether content entries `$ref` the body schema under `#/components/schemas`
+        registers_component: bool,
+
+        /// whether the struct derives `utoipa::ToSchema`
+        derives_schema: bool,
+
+        /// body of `__groom_validate` (field constraints or delegation to the wrapped DTO)
+        validate_body: Option<TokenStream>,
+
+        /// `bool` expression telling whether `__groom_validate` checks any constraint
+        validates: Option<TokenStream>,
+
+        /// mimes listed in `Accept-Post` / `Accept-Patch` of the 415 response
+        accepted_mimes: Vec<&'static str>,
+
+        /// `(status code, description)` of the rejections documented in OpenAPI
+        documented_rejections: Vec<(&'static str, String)>,
+
+        /// `max_size` in bytes, along with its spelling in the annotation
+        max_size: Option<(usize, String)>,
     }
 
     /// AST fragments to work with output DTO
@@ -96,7 +201,7 @@ mod struct_impl {
     }
 
     /// Generates RequestBody implementation for `struct`
-    pub(crate) fn generate(args_t: TokenStream, args: RequestBodyArgs, item_struct: ItemStruct) -> Result<TokenStream, TokenStream> {
+    pub(crate) fn generate(args_t: TokenStream, args: RequestBodyArgs, mut item_struct: ItemStruct) -> Result<TokenStream, TokenStream> {
         let ident = &item_struct.ident;
 
         if !args.format.is_any() {
@@ -117,16 +222,49 @@ mod struct_impl {
 
             dto_fragments: Default::default(),
             body_extractors: Vec::new(),
+            body_content_types: Vec::new(),
             rejection_types: Vec::new(),
             rejections_into_response: Vec::new(),
             openapi_generators: Vec::new(),
             type_assertions: Vec::new(),
+            derives: Vec::new(),
+            registers_component: args.format.json || args.format.url_encoded || args.format.multipart || args.format.cbor || args.format.msgpack,
+            derives_schema: !args.format.is_plain_text_only(),
+            validate_body: None,
+            validates: None,
+            accepted_mimes: Vec::new(),
+            documented_rejections: vec![
+                ("400", "The request body is malformed".to_owned()),
+                ("415", "The request body content type or content encoding is not supported".to_owned()),
+            ],
+            max_size: None,
         };
 
-        let dto_fragments = make_dto_fragments(&item_struct, &mut context)?;
+        if !args.format.is_plain_text_only() {
+            context.documented_rejections.push(("422", "The request body does not match the expected schema".to_owned()));
+        }
+
+        if let Some(max_size) = &args.max_size {
+            let bytes = parse_byte_size(max_size).map_err(|e| syn::Error::new_spanned(
+                &args_t,
+                format!("error in #[RequestBody] annotation: invalid `max_size` of struct `{ident}`: {e}")
+            ).into_compile_error())?;
+
+            context.documented_rejections.push(("413", format!("The request body is larger than {max_size}")));
+            context.max_size = Some((bytes, max_size.clone()));
+        }
+
+        context.validate_body = take_field_constraints(&mut item_struct, false)?;
+        context.validates = context.validate_body.as_ref().map(|_| quote! { true });
+
+        let dto_fragments = make_dto_fragments(&item_struct, &args, &mut context)?;
 
         context.dto_fragments = dto_fragments;
 
+        if args.format.needs_deserialize() {
+            context.derives.push(quote! { #[derive(::serde::Deserialize)] });
+        }
+
         if args.format.json {
             make_fragments_for_format_json(&mut context);
         }
@@ -135,11 +273,33 @@ mod struct_impl {
             make_fragments_for_format_url_encoded(&mut context);
         }
 
+        if args.format.xml {
+            make_fragments_for_format_xml(&mut context);
+        }
+
+        ensure_format_feature(args.format.cbor, cfg!(feature = "cbor"), "cbor", "cbor", &args_t)?;
+        if args.format.cbor {
+            make_fragments_for_format_cbor(&mut context);
+        }
+
+        ensure_format_feature(args.format.msgpack, cfg!(feature = "msgpack"), "msgpack", "msgpack", &args_t)?;
+        if args.format.msgpack {
+            make_fragments_for_format_msgpack(&mut context);
+        }
+
+        if args.format.plain_text {
+            make_fragments_for_format_plain_text(&item_struct, &mut context)?;
+        }
+
+        if args.format.multipart {
+            make_fragments_for_format_multipart(&mut item_struct, &mut context)?;
+        }
+
         Ok(make_new_struct_ast(item_struct, &context))
     }
 
     /// Makes AST fragments to work with target DTO
-    fn make_dto_fragments(item_struct: &ItemStruct, context: &mut AllFragments) -> Result<DtoFragments, TokenStream> {
+    fn make_dto_fragments(item_struct: &ItemStruct, args: &RequestBodyArgs, context: &mut AllFragments) -> Result<DtoFragments, TokenStream> {
         let ident = &item_struct.ident;
 
         match &item_struct.fields {
@@ -175,9 +335,18 @@ mod struct_impl {
                 let first_field = f.unnamed.first().unwrap();
                 let ty = &first_field.ty;
 
-                context.type_assertions.push(quote! {
-                    assert_impl_all!(#ty: ::groom::DTO);
-                });
+                if !args.format.is_plain_text_only() {
+                    context.type_assertions.push(quote! {
+                        assert_impl_all!(#ty: ::groom::DTO);
+                    });
+
+                    context.validate_body = Some(quote! {
+                        ::groom::DTO::__groom_validate(&self.0)
+                    });
+                    context.validates = Some(quote! {
+                        <#ty as ::groom::DTO>::__groom_validates()
+                    });
+                }
 
                 Ok(DtoFragments {
                     extract_ty: quote! { #ty },
@@ -195,15 +364,71 @@ mod struct_impl {
         let rejection_ident = &context.rejection_ident;
         let openapi_generators = &context.openapi_generators;
         let body_extractors = &context.body_extractors;
+        let body_content_types = &context.body_content_types;
         let rejection_types = &context.rejection_types;
         let rejections_into_response = &context.rejections_into_response;
         let type_assertions = &context.type_assertions;
         let description_tk = &context.description_tk;
         let extract_ty = &context.dto_fragments.extract_ty;
+        let derives = &context.derives;
+        let register_component = context.registers_component.then(|| quote! {
+            c.add_components::<#extract_ty>();
+        });
+        let derive_schema = context.derives_schema.then(|| quote! {
+            #[derive(::utoipa::ToSchema)]
+        });
+        let accepted_mimes = context.accepted_mimes.join(", ");
+        let rejection_codes = context.documented_rejections.iter().map(|(code, _)| code);
+        let rejection_descriptions = context.documented_rejections.iter().map(|(_, description)| description);
+        let validate_fn = context.validate_body.as_ref().map(|body| quote! {
+            fn __groom_validate(&self) -> ::core::result::Result<(), ::groom::validation::ValidationErrors> {
+                #body
+            }
+        });
+        let document_validation = match &context.validates {
+            Some(validates) => quote! {
+                match #validates {
+                    true => ::groom::validation::document_validation_errors(op, c),
+                    false => op,
+                }
+            },
+            None => quote! { op },
+        };
+
+        let (decoded_limit, limit_display) = match &context.max_size {
+            Some((bytes, spelling)) => (quote! { #bytes }, quote! { #spelling }),
+            None => (
+                quote! { ::groom::extract::content_encoding::DEFAULT_DECODED_LIMIT },
+                quote! { ::groom::extract::content_encoding::DEFAULT_DECODED_LIMIT_DISPLAY },
+            ),
+        };
+
+        let (document_body_limit, limit_body) = match &context.max_size {
+            Some((bytes, _)) => (
+                quote! {
+                    let op = ::groom::extract::rejection::document_body_limit(op, #bytes);
+                },
+                quote! {
+                    let req = match ::groom::extract::rejection::limit_body(req, #bytes).await {
+                        Ok(req) => req,
+                        Err(::groom::extract::rejection::BodyLimitError::TooLarge) =>
+                            return Err(#rejection_ident::PayloadTooLarge),
+                        Err(::groom::extract::rejection::BodyLimitError::Read(e)) =>
+                            return Err(#rejection_ident::FailedToBufferBody(e)),
+                    };
+                },
+            ),
+            None => (
+                quote! {
+                    let op = ::groom::extract::content_encoding::document_decoded_limit(op, c);
+                },
+                Default::default(),
+            ),
+        };
 
         quote! {
-            #[derive(::serde::Deserialize)]
-            #[derive(::utoipa::ToSchema)]
+            #(#derives)*
+            #derive_schema
             #item_struct
 
             impl ::groom::extract::GroomExtractor for #ident {
@@ -211,16 +436,27 @@ mod struct_impl {
                     op: ::utoipa::openapi::path::OperationBuilder,
                     c: &mut ::groom::extract::ComponentsRegistry
                 ) -> ::utoipa::openapi::path::OperationBuilder {
-                    c.add_components::<#extract_ty>();
+                    #register_component
 
-                    op.request_body(Some(
+                    let op = op.request_body(Some(
                         ::utoipa::openapi::request_body::RequestBodyBuilder::new()
                             #(#openapi_generators)*
                             #description_tk
                             .required(Some(::utoipa::openapi::Required::True))
+                            .extensions(::groom::extract::content_encoding::request_body_extensions())
                             .build()
-                    ))
+                    ));
+
+                    #document_body_limit
+
+                    let op = ::groom::extract::rejection::document_rejections(op, c, &[
+                        #((#rejection_codes, #rejection_descriptions)),*
+                    ]);
+
+                    #document_validation
                 }
+
+                #validate_fn
             }
 
             impl<S> ::axum::extract::FromRequest<S> for #ident
@@ -233,30 +469,70 @@ mod struct_impl {
                     -> ::core::result::Result<Self, Self::Rejection> 
                 {
                     let content_type = match ::groom::content_negotiation::parse_content_type_header(req.headers()) {
-                        Err(_) => return Err(#rejection_ident::BadContentType),
+                        Err(_) => return Err(#rejection_ident::BadContentType(req.method().clone())),
                         Ok(content_type) => content_type,
                     };
 
-                    match ::groom::content_negotiation::get_body_content_type(content_type) {
+                    // unsupported formats get 415 before the body is read and limited
+                    let body_content_type = match ::groom::content_negotiation::get_body_content_type(content_type) {
+                        Some(body_content_type @ (#(#body_content_types)|*)) => Some(body_content_type),
+                        _ => return Err(#rejection_ident::BadContentType(req.method().clone())),
+                    };
+
+                    let req = match ::groom::extract::content_encoding::decode_body(req, #decoded_limit).await {
+                        Ok(req) => req,
+                        Err(::groom::extract::content_encoding::DecodeError::Unsupported(coding)) =>
+                            return Err(#rejection_ident::UnsupportedContentEncoding(coding)),
+                        Err(::groom::extract::content_encoding::DecodeError::TooLarge) =>
+                            return Err(#rejection_ident::PayloadTooLarge),
+                        Err(::groom::extract::content_encoding::DecodeError::Invalid(e)) =>
+                            return Err(#rejection_ident::InvalidEncodedBody(e)),
+                        Err(::groom::extract::content_encoding::DecodeError::Read(e)) =>
+                            return Err(#rejection_ident::FailedToBufferBody(e)),
+                    };
+
+                    #limit_body
+
+                    match body_content_type {
                         #(#body_extractors)*
 
                         _ => {
-                            Err(#rejection_ident::BadContentType)
+                            Err(#rejection_ident::BadContentType(req.method().clone()))
                         },
                     }
                 }
             }
 
             #vis enum #rejection_ident {
-                BadContentType,
+                /// `Content-Type` is missing, malformed or not one of the accepted formats
+                BadContentType(::axum::http::Method),
+                /// `Content-Encoding` is not one of the enabled codings
+                UnsupportedContentEncoding(::std::string::String),
+                /// The body is not valid in its `Content-Encoding`
+                InvalidEncodedBody(::std::io::Error),
+                /// The body (decoded, if encoded) is larger than `max_size`
+                PayloadTooLarge,
+                FailedToBufferBody(::axum::Error),
                 #(#rejection_types)*
             }
 
             impl ::axum::response::IntoResponse for #rejection_ident {
                 fn into_response(self) -> ::axum::response::Response {
                     match self {
-                        #rejection_ident::BadContentType =>
-                            (::axum::http::StatusCode::BAD_REQUEST, "Unsupported Content-Type").into_response(),
+                        #rejection_ident::BadContentType(method) =>
+                            ::groom::extract::rejection::unsupported_media_type(&method, #accepted_mimes),
+
+                        #rejection_ident::UnsupportedContentEncoding(coding) =>
+                            ::groom::extract::content_encoding::unsupported_content_encoding(&coding),
+
+                        #rejection_ident::InvalidEncodedBody(e) =>
+                            ::groom::extract::content_encoding::invalid_encoded_body(e),
+
+                        #rejection_ident::PayloadTooLarge =>
+                            ::groom::extract::rejection::payload_too_large(#limit_display),
+
+                        #rejection_ident::FailedToBufferBody(e) =>
+                            ::groom::extract::rejection::failed_to_buffer_body(e),
 
                         #(#rejections_into_response)*
                     }
@@ -284,6 +560,10 @@ mod struct_impl {
             ::axum_extra::extract::Form::<#extract_ty>
         };
 
+        context.accepted_mimes.push("application/x-www-form-urlencoded");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::FormUrlEncoded });
+
         context.body_extractors.push(quote! {
             Some(::groom::content_negotiation::BodyContentType::FormUrlEncoded) => {
                 let dto = <#form_extractor>::from_request(req, state)
@@ -340,6 +620,10 @@ mod struct_impl {
         let extract_ty = &context.dto_fragments.extract_ty;
         let pack_dto = &context.dto_fragments.pack_dto;
 
+        context.accepted_mimes.push("application/json");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::Json });
+
         context.body_extractors.push(quote! {
             Some(::groom::content_negotiation::BodyContentType::Json) => {
                 let dto = ::axum::extract::Json::<#extract_ty>::from_request(req, state)
@@ -383,6 +667,435 @@ mod struct_impl {
             )
         });
     }
+
+    /// Makes AST fragment to support application/xml, text/xml and +xml suffixes
+    fn make_fragments_for_format_xml(context: &mut AllFragments) {
+        let rejection_ident = &context.rejection_ident;
+        let extract_ty = &context.dto_fragments.extract_ty;
+        let pack_dto = &context.dto_fragments.pack_dto;
+
+        context.accepted_mimes.push("application/xml");
+        context.accepted_mimes.push("text/xml");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::Xml });
+
+        context.body_extractors.push(quote! {
+            Some(::groom::content_negotiation::BodyContentType::Xml) => {
+                let dto = ::groom::xml::Xml::<#extract_ty>::from_request(req, state)
+                    .await
+                    .map_err(|e| #rejection_ident::XmlRejection(e))?
+                    .0
+                ;
+
+                Ok(#pack_dto)
+            },
+        });
+
+        context.rejection_types.push(quote! {
+            XmlRejection(::groom::xml::XmlRejection),
+        });
+
+        context.rejections_into_response.push(quote! {
+            #rejection_ident::XmlRejection(r) => r.into_response(),
+        });
+
+        context.openapi_generators.push(quote! {
+            .content(
+                ::groom::content_negotiation::APPLICATION_XML.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(::groom::xml::xml_schema::<#extract_ty>(c)))
+                    .build()
+            )
+        });
+    }
+
+    /// Makes AST fragment to support text/plain
+    fn make_fragments_for_format_plain_text(item_struct: &ItemStruct, context: &mut AllFragments) -> Result<(), TokenStream> {
+        let ident = &item_struct.ident;
+        let rejection_ident = &context.rejection_ident;
+        let extract_ty = &context.dto_fragments.extract_ty;
+        let pack_dto = &context.dto_fragments.pack_dto;
+
+        if !matches!(item_struct.fields, Fields::Unnamed(_)) {
+            return Err(syn::Error::new_spanned(
+                item_struct.into_token_stream(),
+                format!("error in #[RequestBody] annotation: `{ident}`: format(plain_text) requires a single-field tuple struct wrapping a `FromStr` type: `struct {ident}(T);`.")
+            ).into_compile_error());
+        }
+
+        context.accepted_mimes.push("text/plain");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::PlainText });
+
+        context.body_extractors.push(quote! {
+            Some(::groom::content_negotiation::BodyContentType::PlainText) => {
+                let dto = ::groom::plain_text::PlainText::<#extract_ty>::from_request(req, state)
+                    .await
+                    .map_err(|e| #rejection_ident::PlainTextRejection(e))?
+                    .0
+                ;
+
+                Ok(#pack_dto)
+            },
+        });
+
+        context.rejection_types.push(quote! {
+            PlainTextRejection(::groom::plain_text::PlainTextRejection),
+        });
+
+        context.rejections_into_response.push(quote! {
+            #rejection_ident::PlainTextRejection(r) => r.into_response(),
+        });
+
+        context.openapi_generators.push(quote! {
+            .content(
+                ::mime::TEXT_PLAIN.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(<::std::string::String as ::utoipa::PartialSchema>::schema()))
+                    .build()
+            )
+        });
+
+        context.type_assertions.push(quote! {
+            assert_impl_all!(#extract_ty: ::core::str::FromStr);
+        });
+
+        Ok(())
+    }
+
+    /// Makes AST fragment to support application/cbor
+    fn make_fragments_for_format_cbor(context: &mut AllFragments) {
+        let rejection_ident = &context.rejection_ident;
+        let extract_ty = &context.dto_fragments.extract_ty;
+        let pack_dto = &context.dto_fragments.pack_dto;
+
+        context.accepted_mimes.push("application/cbor");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::Cbor });
+
+        context.body_extractors.push(quote! {
+            Some(::groom::content_negotiation::BodyContentType::Cbor) => {
+                let dto = ::groom::cbor::Cbor::<#extract_ty>::from_request(req, state)
+                    .await
+                    .map_err(|e| #rejection_ident::CborRejection(e))?
+                    .0
+                ;
+
+                Ok(#pack_dto)
+            },
+        });
+
+        context.rejection_types.push(quote! {
+            CborRejection(::groom::cbor::CborRejection),
+        });
+
+        context.rejections_into_response.push(quote! {
+            #rejection_ident::CborRejection(r) => r.into_response(),
+        });
+
+        context.openapi_generators.push(quote! {
+            .content(
+                ::groom::content_negotiation::APPLICATION_CBOR.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(match <#extract_ty as ::utoipa::PartialSchema>::schema() {
+                        ::utoipa::openapi::RefOr::T(s) => Some(
+                            ::utoipa::openapi::RefOr::<utoipa::openapi::Schema>::Ref(
+                                ::utoipa::openapi::schema::RefBuilder::new()
+                                    .ref_location(format!(
+                                        "#/components/schemas/{}",
+                                        ::groom::json_ptr::escape_json_pointer(
+                                            <#extract_ty as ::utoipa::ToSchema>::name().as_ref()
+                                        )
+                                    ))
+                                    .build()
+                            )
+                        ),
+                        ::utoipa::openapi::RefOr::Ref(_) => panic!("Type `{}` schema for application/cbor is ref", stringify!(#extract_ty)),
+                    })
+                    .build()
+            )
+        });
+    }
+
+    /// Makes AST fragment to support application/msgpack
+    fn make_fragments_for_format_msgpack(context: &mut AllFragments) {
+        let rejection_ident = &context.rejection_ident;
+        let extract_ty = &context.dto_fragments.extract_ty;
+        let pack_dto = &context.dto_fragments.pack_dto;
+
+        context.accepted_mimes.push("application/msgpack");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::MsgPack });
+
+        context.body_extractors.push(quote! {
+            Some(::groom::content_negotiation::BodyContentType::MsgPack) => {
+                let dto = ::groom::msgpack::MsgPack::<#extract_ty>::from_request(req, state)
+                    .await
+                    .map_err(|e| #rejection_ident::MsgPackRejection(e))?
+                    .0
+                ;
+
+                Ok(#pack_dto)
+            },
+        });
+
+        context.rejection_types.push(quote! {
+            MsgPackRejection(::groom::msgpack::MsgPackRejection),
+        });
+
+        context.rejections_into_response.push(quote! {
+            #rejection_ident::MsgPackRejection(r) => r.into_response(),
+        });
+
+        context.openapi_generators.push(quote! {
+            .content(
+                ::mime::APPLICATION_MSGPACK.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(match <#extract_ty as ::utoipa::PartialSchema>::schema() {
+                        ::utoipa::openapi::RefOr::T(s) => Some(
+                            ::utoipa::openapi::RefOr::<utoipa::openapi::Schema>::Ref(
+                                ::utoipa::openapi::schema::RefBuilder::new()
+                                    .ref_location(format!(
+                                        "#/components/schemas/{}",
+                                        ::groom::json_ptr::escape_json_pointer(
+                                            <#extract_ty as ::utoipa::ToSchema>::name().as_ref()
+                                        )
+                                    ))
+                                    .build()
+                            )
+                        ),
+                        ::utoipa::openapi::RefOr::Ref(_) => panic!("Type `{}` schema for application/msgpack is ref", stringify!(#extract_ty)),
+                    })
+                    .build()
+            )
+        });
+    }
+
+    /// How many parts of the same name a multipart field accepts.
+    enum MultipartArity {
+        /// `T` — exactly one part
+        Required,
+
+        /// `Option<T>` — zero or one part
+        Optional,
+
+        /// `Vec<T>` — any number of parts
+        Repeated,
+    }
+
+    /// How the contents of a multipart part are read.
+    #[derive(PartialEq)]
+    enum MultipartPartKind {
+        /// `groom::extract::Upload` — file with its name and content type
+        Upload,
+
+        /// `Bytes` — raw contents of the part
+        Bytes,
+
+        /// Anything else — text parsed through `FromStr`
+        Text,
+    }
+
+    /// Returns the last path segment of a type, if it is a path type.
+    fn last_type_segment(ty: &Type) -> Option<&syn::PathSegment> {
+        match ty {
+            Type::Path(p) if p.qself.is_none() => p.path.segments.last(),
+            _ => None,
+        }
+    }
+
+    /// Splits `Option<T>` / `Vec<T>` / `T` into arity and `T`.
+    fn multipart_field_shape(ty: &Type) -> (MultipartArity, &Type) {
+        if let Some(segment) = last_type_segment(ty) {
+            let arity = match segment.ident.to_string().as_str() {
+                "Option" => Some(MultipartArity::Optional),
+                "Vec" => Some(MultipartArity::Repeated),
+                _ => None,
+            };
+
+            if let (Some(arity), PathArguments::AngleBracketed(args)) = (arity, &segment.arguments)
+                && let Some(GenericArgument::Type(inner)) = args.args.first()
+            {
+                return (arity, inner);
+            }
+        }
+
+        (MultipartArity::Required, ty)
+    }
+
+    fn multipart_part_kind(ty: &Type) -> MultipartPartKind {
+        match last_type_segment(ty).map(|s| s.ident.to_string()).as_deref() {
+            Some("Upload") => MultipartPartKind::Upload,
+            Some("Bytes") => MultipartPartKind::Bytes,
+            _ => MultipartPartKind::Text,
+        }
+    }
+
+    /// Makes AST fragment to support multipart/form-data
+    fn make_fragments_for_format_multipart(item_struct: &mut ItemStruct, context: &mut AllFragments) -> Result<(), TokenStream> {
+        let ident = item_struct.ident.clone();
+        let rejection_ident = &context.rejection_ident;
+
+        let Fields::Named(fields) = &mut item_struct.fields else {
+            return Err(syn::Error::new_spanned(
+                item_struct.into_token_stream(),
+                format!("error in #[RequestBody] annotation: `{ident}`: format(multipart) requires a named struct: `struct {ident} {{...}}`.")
+            ).into_compile_error());
+        };
+
+        let mut locals_init = Vec::new();
+        let mut part_readers = Vec::new();
+        let mut field_values = Vec::new();
+        let mut encodings = Vec::new();
+
+        for field in fields.named.iter_mut() {
+            let field_ident = field.ident.clone().unwrap();
+            let part_name = field_ident.unraw().to_string();
+            let local = format_ident!("__part_{}", part_name);
+
+            let (arity, inner_ty) = multipart_field_shape(&field.ty);
+            let kind = multipart_part_kind(inner_ty);
+
+            let read = match kind {
+                MultipartPartKind::Upload => quote! { ::groom::extract::multipart::read_upload(field) },
+                MultipartPartKind::Bytes => quote! { ::groom::extract::multipart::read_bytes(field) },
+                MultipartPartKind::Text => quote! { ::groom::extract::multipart::read_text::<#inner_ty>(field) },
+            };
+
+            let read = quote! { #read.await.map_err(|e| part_error(#part_name, e))? };
+
+            match arity {
+                MultipartArity::Required | MultipartArity::Optional => {
+                    locals_init.push(quote! { let mut #local = ::core::option::Option::None; });
+                    part_readers.push(quote! { Some(#part_name) => { #local = Some(#read); }, });
+                },
+                MultipartArity::Repeated => {
+                    locals_init.push(quote! { let mut #local = ::std::vec::Vec::new(); });
+                    part_readers.push(quote! { Some(#part_name) => { #local.push(#read); }, });
+                },
+            }
+
+            field_values.push(match arity {
+                MultipartArity::Required => quote! {
+                    #field_ident: #local.ok_or(#rejection_ident::MissingMultipartPart(#part_name))?,
+                },
+                MultipartArity::Optional | MultipartArity::Repeated => quote! {
+                    #field_ident: #local,
+                },
+            });
+
+            let part_content_type = if kind == MultipartPartKind::Text {
+                quote! { ::mime::TEXT_PLAIN.as_ref() }
+            } else {
+                quote! { ::mime::APPLICATION_OCTET_STREAM.as_ref() }
+            };
+
+            encodings.push(quote! {
+                .encoding(
+                    #part_name,
+                    ::utoipa::openapi::encoding::EncodingBuilder::new()
+                        .content_type(Some(#part_content_type))
+                        .build()
+                )
+            });
+
+            // file parts are documented as inline binary strings
+            match kind {
+                MultipartPartKind::Upload => field.attrs.push(syn::parse_quote! { #[schema(inline)] }),
+                MultipartPartKind::Bytes => {
+                    let value_ty = match arity {
+                        MultipartArity::Required => quote! { ::groom::extract::Upload },
+                        MultipartArity::Optional => quote! { Option<::groom::extract::Upload> },
+                        MultipartArity::Repeated => quote! { Vec<::groom::extract::Upload> },
+                    };
+                    field.attrs.push(syn::parse_quote! { #[schema(inline, value_type = #value_ty)] });
+                },
+                MultipartPartKind::Text => {},
+            }
+        }
+
+        // a struct without fields reads no parts
+        let part_error = if part_readers.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                let part_error = |part: &'static str, e: ::groom::extract::multipart::MultipartPartError| match e {
+                    ::groom::extract::multipart::MultipartPartError::Read(e) => #rejection_ident::MultipartError(e),
+                    ::groom::extract::multipart::MultipartPartError::Parse(e) => #rejection_ident::InvalidMultipartPart(part, e),
+                };
+            }
+        };
+
+        context.accepted_mimes.push("multipart/form-data");
+
+        context.body_content_types.push(quote! { ::groom::content_negotiation::BodyContentType::Multipart });
+
+        context.body_extractors.push(quote! {
+            Some(::groom::content_negotiation::BodyContentType::Multipart) => {
+                let mut multipart = ::axum::extract::Multipart::from_request(req, state)
+                    .await
+                    .map_err(|e| #rejection_ident::MultipartRejection(e))?
+                ;
+
+                #part_error
+
+                #(#locals_init)*
+
+                while let Some(field) = multipart.next_field().await.map_err(|e| #rejection_ident::MultipartError(e))? {
+                    match field.name() {
+                        #(#part_readers)*
+                        _ => {},
+                    }
+                }
+
+                Ok(#ident {
+                    #(#field_values)*
+                })
+            },
+        });
+
+        context.rejection_types.push(quote! {
+            MultipartRejection(::axum::extract::multipart::MultipartRejection),
+            MultipartError(::axum::extract::multipart::MultipartError),
+            MissingMultipartPart(&'static str),
+            InvalidMultipartPart(&'static str, String),
+        });
+
+        context.rejections_into_response.push(quote! {
+            #rejection_ident::MultipartRejection(r) => r.into_response(),
+            #rejection_ident::MultipartError(r) => r.into_response(),
+            #rejection_ident::MissingMultipartPart(part) =>
+                (::axum::http::StatusCode::UNPROCESSABLE_ENTITY, format!("Missing multipart part `{part}`")).into_response(),
+            #rejection_ident::InvalidMultipartPart(part, e) =>
+                (::axum::http::StatusCode::UNPROCESSABLE_ENTITY, format!("Failed to parse multipart part `{part}`: {e}")).into_response(),
+        });
+
+        context.openapi_generators.push(quote! {
+            .content(
+                ::mime::MULTIPART_FORM_DATA.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(match <#ident as ::utoipa::PartialSchema>::schema() {
+                        ::utoipa::openapi::RefOr::T(s) => Some(
+                            ::utoipa::openapi::RefOr::<utoipa::openapi::Schema>::Ref(
+                                ::utoipa::openapi::schema::RefBuilder::new()
+                                    .ref_location(format!(
+                                        "#/components/schemas/{}",
+                                        ::groom::json_ptr::escape_json_pointer(
+                                            <#ident as ::utoipa::ToSchema>::name().as_ref()
+                                        )
+                                    ))
+                                    .build()
+                            )
+                        ),
+                        ::utoipa::openapi::RefOr::Ref(_) => panic!("Type `{}` schema for multipart/form-data is ref", stringify!(#ident)),
+                    })
+                    #(#encodings)*
+                    .build()
+            )
+        });
+
+        Ok(())
+    }
 }
 
 //
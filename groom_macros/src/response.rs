@@ -1,6 +1,6 @@
-use axum::http::StatusCode;
+use axum::http::{HeaderName, StatusCode};
 use darling::FromMeta;
-use syn::{Attribute, Error, Item};
+use syn::{Attribute, Error, Field, Item, Type};
 use syn::parse2;
 use strum_macros::Display;
 use derive_more::{Deref, DerefMut};
@@ -8,7 +8,7 @@ use derive_more::{Deref, DerefMut};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 
-use crate::{annotation_attrs::{parse_attr, remove_attrs}, http::HTTPStatusCode};
+use crate::{annotation_attrs::{parse_attr, remove_attrs}, comments::get_docblock, features::ensure_format_feature, http::HTTPStatusCode, request_body::parse_byte_size};
 
 // region: Annotations -----------------------------------------------------------------------------
 //
@@ -21,6 +21,39 @@ pub(crate) struct ResponseArgsBase {
 
     #[darling(default)]
     pub(crate) default_format: Option<ResponseFormat>,
+
+    #[darling(default)]
+    pub(crate) compress: Option<CompressArgs>,
+}
+
+/// `compress` part from `#[Response(compress)]` or `#[Response(compress(min_size = "4KiB"))]`
+#[derive(FromMeta, Default, Clone)]
+#[darling(from_word = || Ok(Default::default()))]
+pub(crate) struct CompressArgs {
+    /// Smallest compressed body, e.g. `min_size = "4KiB"`
+    #[darling(default)]
+    pub(crate) min_size: Option<String>,
+
+    /// `binary_formats`: CBOR and MessagePack bodies are compressed too
+    #[darling(default)]
+    pub(crate) binary_formats: bool,
+}
+
+impl CompressArgs {
+    /// `::groom::response::Compression` with these settings.
+    fn compression_expr(&self) -> Result<TokenStream, String> {
+        let min_size = match &self.min_size {
+            Some(min_size) => {
+                let bytes = parse_byte_size(min_size)?;
+                Some(quote! { .min_size(#bytes) })
+            },
+            None => None,
+        };
+
+        let binary_formats = self.binary_formats.then(|| quote! { .binary_formats(true) });
+
+        Ok(quote! { ::groom::response::Compression::new() #min_size #binary_formats })
+    }
 }
 
 /// `#[Response(...)]` annotation args for `enum`
@@ -48,6 +81,16 @@ pub(crate) enum ResponseFormat {
     PlainText,
     Html,
     Json,
+    Xml,
+    Cbor,
+    #[darling(rename = "msgpack")]
+    #[strum(serialize = "msgpack")]
+    MsgPack,
+    EventStream,
+    #[darling(rename = "ndjson")]
+    #[strum(serialize = "ndjson")]
+    NdJson,
+    Binary,
 }
 
 /// `format` part from `#[Response(format(...))]`
@@ -61,12 +104,69 @@ pub(crate) struct ResponseFormatsList {
 
     #[darling(default)]
     pub(crate) json: bool,
+
+    #[darling(default)]
+    pub(crate) xml: bool,
+
+    #[darling(default)]
+    pub(crate) cbor: bool,
+
+    #[darling(default)]
+    pub(crate) msgpack: bool,
+
+    #[darling(default)]
+    pub(crate) event_stream: bool,
+
+    #[darling(default)]
+    pub(crate) ndjson: bool,
+
+    #[darling(default)]
+    pub(crate) binary: Option<BinaryMime>,
+}
+
+/// `binary` part from `#[Response(format(binary))]` (`application/octet-stream`)
+/// or `#[Response(format(binary = "application/pdf"))]`
+#[derive(Clone)]
+pub(crate) struct BinaryMime {
+    pub(crate) mime: String,
+    pub(crate) type_: String,
+    pub(crate) subtype: String,
 }
 
+impl FromMeta for BinaryMime {
+    fn from_word() -> darling::Result<Self> {
+        Self::from_string(mime::APPLICATION_OCTET_STREAM.as_ref())
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let parsed = value.parse::<mime::Mime>()
+            .map_err(|e| darling::Error::custom(format!("cannot parse binary mime `{value}`: {e}")))?;
+
+        if parsed.type_() == mime::STAR || parsed.subtype() == mime::STAR {
+            return Err(darling::Error::custom(format!("binary mime `{value}` should not contain wildcards")));
+        }
+
+        Ok(Self {
+            mime: parsed.as_ref().to_owned(),
+            type_: parsed.type_().as_str().to_owned(),
+            subtype: parsed.subtype().as_str().to_owned(),
+        })
+    }
+}
 
 impl ResponseFormatsList {
     pub(crate) fn is_any(&self) -> bool {
-        self.plain_text || self.html || self.json
+        self.plain_text || self.html || self.json || self.xml || self.cbor || self.msgpack || self.event_stream || self.ndjson || self.binary.is_some()
+    }
+
+    /// Only `binary`, `event_stream` and `ndjson` are listed: the payload is not serialized as a
+    /// whole and needs no schema of its own.
+    pub(crate) fn is_unserialized_only(&self) -> bool {
+        let unserialized = usize::from(self.event_stream)
+            + usize::from(self.ndjson)
+            + usize::from(self.binary.is_some());
+
+        unserialized > 0 && self.count() == unserialized
     }
 
     pub(crate) fn count(&self) -> usize {
@@ -84,6 +184,30 @@ impl ResponseFormatsList {
             result += 1;
         }
 
+        if self.xml {
+            result += 1;
+        }
+
+        if self.cbor {
+            result += 1;
+        }
+
+        if self.msgpack {
+            result += 1;
+        }
+
+        if self.event_stream {
+            result += 1;
+        }
+
+        if self.ndjson {
+            result += 1;
+        }
+
+        if self.binary.is_some() {
+            result += 1;
+        }
+
         result
     }
 
@@ -96,6 +220,18 @@ impl ResponseFormatsList {
             Some(ResponseFormat::Html)
         } else if self.json {
             Some(ResponseFormat::Json)
+        } else if self.xml {
+            Some(ResponseFormat::Xml)
+        } else if self.cbor {
+            Some(ResponseFormat::Cbor)
+        } else if self.msgpack {
+            Some(ResponseFormat::MsgPack)
+        } else if self.event_stream {
+            Some(ResponseFormat::EventStream)
+        } else if self.ndjson {
+            Some(ResponseFormat::NdJson)
+        } else if self.binary.is_some() {
+            Some(ResponseFormat::Binary)
         } else {
             panic!("bug in ResponseFormatsList::count() or ResponseFormatsList::get_single_value()")
         }
@@ -106,6 +242,12 @@ impl ResponseFormatsList {
             ResponseFormat::PlainText => self.plain_text,
             ResponseFormat::Html => self.html,
             ResponseFormat::Json => self.json,
+            ResponseFormat::Xml => self.xml,
+            ResponseFormat::Cbor => self.cbor,
+            ResponseFormat::MsgPack => self.msgpack,
+            ResponseFormat::EventStream => self.event_stream,
+            ResponseFormat::NdJson => self.ndjson,
+            ResponseFormat::Binary => self.binary.is_some(),
         }
     }
 }
@@ -127,6 +269,98 @@ impl ResponseVariantAnnotation {
     }
 }
 
+/// `#[Response]` annotation for fields of enum variants and structs
+#[derive(FromMeta)]
+pub(crate) struct ResponseFieldAnnotation {
+    pub(crate) header: Option<ResponseHeaderArgs>,
+    pub(crate) cookie: Option<ResponseCookieArgs>,
+}
+
+/// `header` part from `#[Response(header(name = "Location"))]`
+#[derive(FromMeta)]
+pub(crate) struct ResponseHeaderArgs {
+    pub(crate) name: String,
+}
+
+/// `cookie` part from `#[Response(cookie(name = "session", http_only, same_site = "lax"))]`
+#[derive(FromMeta)]
+pub(crate) struct ResponseCookieArgs {
+    pub(crate) name: String,
+
+    #[darling(default)]
+    pub(crate) path: Option<String>,
+
+    #[darling(default)]
+    pub(crate) domain: Option<String>,
+
+    /// Seconds; `0` asks the client to drop the cookie
+    #[darling(default)]
+    pub(crate) max_age: Option<i64>,
+
+    #[darling(default)]
+    pub(crate) secure: bool,
+
+    #[darling(default)]
+    pub(crate) http_only: bool,
+
+    /// `strict`, `lax` or `none`
+    #[darling(default)]
+    pub(crate) same_site: Option<String>,
+}
+
+impl ResponseCookieArgs {
+    /// Renders the attributes as a `; Path=/; HttpOnly`-like `Set-Cookie` suffix.
+    fn attributes(&self) -> Result<String, String> {
+        let is_token_char = |b: u8| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b);
+
+        if self.name.is_empty() || !self.name.bytes().all(is_token_char) {
+            return Err(format!("`{}` is not a valid cookie name", self.name));
+        }
+
+        let mut attributes = String::new();
+
+        for (attribute, value) in [("Path", &self.path), ("Domain", &self.domain)] {
+            if let Some(value) = value {
+                if value.is_empty() || !value.bytes().all(|b| b.is_ascii_graphic() && b != b';') {
+                    return Err(format!("`{value}` is not a valid cookie {attribute}"));
+                }
+
+                attributes.push_str(&format!("; {attribute}={value}"));
+            }
+        }
+
+        if let Some(max_age) = self.max_age {
+            if max_age < 0 {
+                return Err("cookie `max_age` can't be negative; use `max_age = 0` to drop the cookie".into());
+            }
+
+            attributes.push_str(&format!("; Max-Age={max_age}"));
+        }
+
+        if self.secure {
+            attributes.push_str("; Secure");
+        }
+
+        if self.http_only {
+            attributes.push_str("; HttpOnly");
+        }
+
+        if let Some(same_site) = &self.same_site {
+            let same_site = match same_site.to_ascii_lowercase().as_str() {
+                "strict" => "Strict",
+                "lax" => "Lax",
+                "none" if self.secure => "None",
+                "none" => return Err("cookie with `same_site = \"none\"` must be `secure`".into()),
+                _ => return Err(format!("`{same_site}` is not a valid `same_site`; expected `strict`, `lax` or `none`")),
+            };
+
+            attributes.push_str(&format!("; SameSite={same_site}"));
+        }
+
+        Ok(attributes)
+    }
+}
+
 
 //
 // endregion: Annotations --------------------------------------------------------------------------
@@ -138,7 +372,7 @@ impl ResponseVariantAnnotation {
 struct NewAstFragments {
     item_ident: Ident,
 
-    /// List of available content-types that can be produced from this Response (generated as a constant array):
+    /// List of available content-types that can be produced from this Response (generated as a static vector):
     supported_mimes: SupportedMimesTokenStreams,
     supported_mimes_ident: Ident,
 
@@ -148,6 +382,12 @@ struct NewAstFragments {
     into_response_text_plain_ident: Ident,
     into_response_text_html_ident: Ident,
     into_response_application_json_ident: Ident,
+    into_response_application_xml_ident: Ident,
+    into_response_application_cbor_ident: Ident,
+    into_response_application_msgpack_ident: Ident,
+    into_response_text_event_stream_ident: Ident,
+    into_response_application_ndjson_ident: Ident,
+    into_response_binary_ident: Ident,
 
     /// compile-time checks of trait implementation (for better error messages)
     type_assertions: Vec<TokenStream>,
@@ -181,6 +421,12 @@ impl NewAstFragments {
             into_response_text_plain_ident: format_ident!("into_response_text_plain"),
             into_response_text_html_ident: format_ident!("into_response_text_html"),
             into_response_application_json_ident: format_ident!("into_response_application_json"),
+            into_response_application_xml_ident: format_ident!("into_response_application_xml"),
+            into_response_application_cbor_ident: format_ident!("into_response_application_cbor"),
+            into_response_application_msgpack_ident: format_ident!("into_response_application_msgpack"),
+            into_response_text_event_stream_ident: format_ident!("into_response_text_event_stream"),
+            into_response_application_ndjson_ident: format_ident!("into_response_application_ndjson"),
+            into_response_binary_ident: format_ident!("into_response_binary"),
 
             type_assertions: Default::default(),
             check_response_codes_fn: Default::default(),
@@ -195,10 +441,29 @@ impl NewAstFragments {
     }
 }
 
-/// These token streams are used to build global const vectors of supported MIME types
+/// What a field of an enum variant or a struct is sent as.
+enum HeaderKind {
+    /// Header name as declared (used in OpenAPI)
+    Header(String),
+
+    /// `Set-Cookie` header with the cookie name and rendered attributes
+    Cookie { name: String, attributes: String },
+}
+
+/// Field of an enum variant or a struct that is sent as a response header or cookie.
+struct HeaderField {
+    kind: HeaderKind,
+    ty: Type,
+    description: Option<String>,
+
+    /// Reference to the field value, e.g. `&header_0` or `&self.location`
+    value: TokenStream,
+}
+
+/// These token streams are used to build global static vectors of supported MIME types
 /// for each `#[Response]`.
 ///
-/// The resulting global const vectors of MIME types are then used in runtime
+/// The resulting global static vectors of MIME types are then used in runtime
 /// to configure parsing of `Accept` header for handlers that return this `#[Response]`.
 #[derive(Deref, DerefMut, Default)]
 struct SupportedMimesTokenStreams(Vec<TokenStream>);
@@ -252,6 +517,223 @@ fn populate_supported_mimes(
             ::mime::APPLICATION_JSON,
         });
     }
+
+    // `application/xml` first: it is the one `default_format` and `*/*` resolve to
+    if content_types.xml {
+        supported_mimes.0.push(quote! {
+            ::groom::content_negotiation::APPLICATION_XML.clone(),
+            ::mime::TEXT_XML,
+        });
+    }
+
+    if content_types.cbor {
+        supported_mimes.0.push(quote! {
+            ::groom::content_negotiation::APPLICATION_CBOR.clone(),
+        });
+    }
+
+    // `application/msgpack` first: it is the one `default_format` and `*/*` resolve to
+    if content_types.msgpack {
+        supported_mimes.0.push(quote! {
+            ::mime::APPLICATION_MSGPACK,
+            ::groom::content_negotiation::APPLICATION_X_MSGPACK.clone(),
+        });
+    }
+
+    if content_types.event_stream {
+        supported_mimes.0.push(quote! {
+            ::mime::TEXT_EVENT_STREAM,
+        });
+    }
+
+    // `application/x-ndjson` first: it is the one `default_format` and `*/*` resolve to
+    if content_types.ndjson {
+        supported_mimes.0.push(quote! {
+            ::groom::content_negotiation::APPLICATION_NDJSON.clone(),
+            ::groom::content_negotiation::APPLICATION_NDJSON_UNPREFIXED.clone(),
+        });
+    }
+
+    if let Some(binary) = &content_types.binary {
+        let mime = &binary.mime;
+        supported_mimes.0.push(quote! {
+            ::groom::response::binary_response::binary_mime(#mime),
+        });
+    }
+}
+
+/// Takes `#[Response(header(...))]` or `#[Response(cookie(...))]` off a field; `None` for body
+/// fields.
+fn take_header_annotation(field: &mut Field) -> Result<Option<HeaderKind>, TokenStream> {
+    let annotation = match parse_attr::<ResponseFieldAnnotation>("Response", &field.attrs) {
+        Ok(Some(annotation)) => annotation,
+        Ok(None) => return Ok(None),
+        Err(error) => return Err(error.write_errors()),
+    };
+
+    remove_attrs("Response", &mut field.attrs);
+
+    let name = match annotation {
+        ResponseFieldAnnotation { header: Some(header), cookie: None } => header.name,
+
+        ResponseFieldAnnotation { header: None, cookie: Some(cookie) } => {
+            return match cookie.attributes() {
+                Ok(attributes) => Ok(Some(HeaderKind::Cookie { name: cookie.name, attributes })),
+                Err(message) => Err(syn::Error::new_spanned(
+                    &field,
+                    format!("error in `#[Response]` annotation: {message}")
+                ).into_compile_error()),
+            };
+        },
+
+        _ => return Err(syn::Error::new_spanned(
+            &field,
+            "error in `#[Response]` annotation: specify either `header(...)` or `cookie(...)` for a field"
+        ).into_compile_error()),
+    };
+
+    if HeaderName::from_bytes(name.as_bytes()).is_err() {
+        return Err(syn::Error::new_spanned(
+            &field,
+            format!("error in `#[Response]` annotation: `{name}` is not a valid header name")
+        ).into_compile_error());
+    }
+
+    if name.eq_ignore_ascii_case("content-type") || name.eq_ignore_ascii_case("content-length") {
+        return Err(syn::Error::new_spanned(
+            &field,
+            format!("error in `#[Response]` annotation: `{name}` header is set by the response format")
+        ).into_compile_error());
+    }
+
+    if name.eq_ignore_ascii_case("set-cookie") {
+        return Err(syn::Error::new_spanned(
+            &field,
+            "error in `#[Response]` annotation: declare cookies with `#[Response(cookie(name = \"...\"))]`"
+        ).into_compile_error());
+    }
+
+    Ok(Some(HeaderKind::Header(name)))
+}
+
+impl HeaderField {
+    fn new(kind: HeaderKind, field: &Field, value: TokenStream) -> Self {
+        Self {
+            kind,
+            ty: field.ty.clone(),
+            description: get_docblock(&field.attrs).unwrap_or_default(),
+            value,
+        }
+    }
+}
+
+/// `(code, body).into_response()`, with the header fields when there are any.
+fn response_expr(response_code_ts: &TokenStream, body: Option<TokenStream>, headers: &[HeaderField]) -> TokenStream {
+    if headers.is_empty() {
+        return match body {
+            Some(body) => quote! {
+                (
+                    #response_code_ts,
+                    #body
+                ).into_response()
+            },
+            None => quote! { (#response_code_ts).into_response() },
+        };
+    }
+
+    let values = headers.iter().map(|header| {
+        let ty = &header.ty;
+        let value = &header.value;
+
+        match &header.kind {
+            HeaderKind::Header(name) => {
+                let name = name.to_ascii_lowercase();
+
+                quote! {
+                    (
+                        ::axum::http::HeaderName::from_static(#name),
+                        <#ty as ::groom::response::ResponseHeader>::header_value(#value).map_err(::core::convert::Into::into)
+                    ),
+                }
+            },
+
+            HeaderKind::Cookie { name, attributes } => quote! {
+                (
+                    ::axum::http::header::SET_COOKIE,
+                    ::groom::response::cookies::set_cookie(
+                        #name,
+                        <#ty as ::groom::response::ResponseCookie>::cookie_value(#value),
+                        #attributes
+                    )
+                ),
+            },
+        }
+    });
+
+    let parts = match body {
+        Some(body) => quote! { (#response_code_ts, headers, #body) },
+        None => quote! { (#response_code_ts, headers) },
+    };
+
+    quote! {
+        match ::groom::response::headers::response_headers([#(#values)*]) {
+            Ok(headers) => #parts.into_response(),
+            Err(error) => ::axum::response::IntoResponse::into_response(error),
+        }
+    }
+}
+
+/// `.header(...)` calls of a `ResponseBuilder` documenting the header fields.
+///
+/// All cookie fields are documented as a single `Set-Cookie` header that lists them.
+fn openapi_headers(headers: &[HeaderField]) -> Vec<TokenStream> {
+    let mut cookies: Vec<String> = Vec::new();
+
+    let mut header_impls: Vec<TokenStream> = headers.iter()
+        .filter_map(|header| {
+            let ty = &header.ty;
+
+            let name = match &header.kind {
+                HeaderKind::Header(name) => name,
+                HeaderKind::Cookie { name, attributes } => {
+                    cookies.push(match &header.description {
+                        Some(d) => format!("`{name}=...{attributes}`: {d}"),
+                        None => format!("`{name}=...{attributes}`"),
+                    });
+
+                    return None;
+                },
+            };
+
+            let description = header.description.as_ref().map(|d| quote! { .description(Some(#d)) });
+
+            Some(quote! {
+                .header(
+                    #name,
+                    ::utoipa::openapi::HeaderBuilder::new()
+                        .schema(<#ty as ::groom::response::ResponseHeader>::header_schema())
+                        #description
+                        .build()
+                )
+            })
+        })
+        .collect();
+
+    if !cookies.is_empty() {
+        let description = cookies.join("\n\n");
+
+        header_impls.push(quote! {
+            .header(
+                "Set-Cookie",
+                ::utoipa::openapi::HeaderBuilder::new()
+                    .schema(<::std::string::String as ::utoipa::PartialSchema>::schema())
+                    .description(Some(#description))
+                    .build()
+            )
+        });
+    }
+
+    header_impls
 }
 
 /// Well-known status codes mapped to their `axum::http::StatusCode` constant names.
@@ -362,6 +844,7 @@ fn make_openapi_fragments_for_type(
     ty: TokenStream,
     description_tk: TokenStream,
     response_code_str: String,
+    headers: &[HeaderField],
     fragments: &mut NewAstFragments
 ) {
     let mut response_impls: Vec<TokenStream> = Vec::new();
@@ -401,14 +884,101 @@ fn make_openapi_fragments_for_type(
         });
     }
 
-    fragments.openapi_impls.push(quote! {
-        let op = op.response(
-            #response_code_str,
-            ::utoipa::openapi::ResponseBuilder::new()
-                .description(#description_tk)
-                #(#response_impls)*
-                .build()
-        );
+    if content_types.xml {
+        response_impls.push(quote! {
+            .content(
+                ::groom::content_negotiation::APPLICATION_XML.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(::groom::xml::xml_schema::<#ty>(components)))
+                    .build()
+            )
+        });
+    }
+
+    if content_types.cbor {
+        response_impls.push(quote! {
+            .content(
+                ::groom::content_negotiation::APPLICATION_CBOR.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(components.add_components::<#ty>()))
+                    .build()
+            )
+        });
+    }
+
+    if content_types.msgpack {
+        response_impls.push(quote! {
+            .content(
+                ::mime::APPLICATION_MSGPACK.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(components.add_components::<#ty>()))
+                    .build()
+            )
+            .content(
+                ::groom::content_negotiation::APPLICATION_X_MSGPACK.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(components.add_components::<#ty>()))
+                    .build()
+            )
+        });
+    }
+
+    if content_types.event_stream {
+        response_impls.push(quote! {
+            .content(
+                ::mime::TEXT_EVENT_STREAM.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(components.add_components::<<#ty as ::groom::response::EventStreamFormat>::Data>()))
+                    .build()
+            )
+        });
+    }
+
+    if content_types.ndjson {
+        response_impls.push(quote! {
+            .content(
+                ::groom::content_negotiation::APPLICATION_NDJSON.as_ref(),
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(components.add_components::<<#ty as ::groom::response::NdJsonFormat>::Item>()))
+                    .build()
+            )
+        });
+    }
+
+    if let Some(binary) = &content_types.binary {
+        let mime = &binary.mime;
+        response_impls.push(quote! {
+            .content(
+                #mime,
+                ::utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(::groom::extract::ComponentsRegistry::schema_or_ref::<::groom::response::Binary>(components)))
+                    .build()
+            )
+        });
+    }
+
+    let header_impls = openapi_headers(headers);
+
+    let response = quote! {
+        ::utoipa::openapi::ResponseBuilder::new()
+            .description(#description_tk)
+            #(#header_impls)*
+            #(#response_impls)*
+            .build()
+    };
+
+    // an invalid `min_size` is reported by `make_new_ast`
+    let compression = fragments.response_args.compress.as_ref().and_then(|c| c.compression_expr().ok());
+
+    fragments.openapi_impls.push(match compression {
+        Some(compression) => quote! {
+            let mut response = #response;
+            #compression.document_response(&mut response);
+            let op = op.response(#response_code_str, response);
+        },
+        None => quote! {
+            let op = op.response(#response_code_str, #response);
+        },
     });
 }
 
@@ -419,6 +989,26 @@ fn make_new_ast(fragments: NewAstFragments)
     let resp_args = &fragments.response_args;
     let resp_args_span = &fragments.response_args_t;
 
+    ensure_format_feature(resp_args.format.cbor, cfg!(feature = "cbor"), "cbor", "cbor", resp_args_span)?;
+    ensure_format_feature(resp_args.format.msgpack, cfg!(feature = "msgpack"), "msgpack", "msgpack", resp_args_span)?;
+    ensure_distinct_binary_mime(&resp_args.format, resp_args_span)?;
+
+    let compression_function = match &resp_args.compress {
+        Some(compress) => {
+            let compression = compress.compression_expr().map_err(|e| syn::Error::new_spanned(
+                resp_args_span,
+                format!("error in `#[Response]` annotation: invalid `min_size` of `compress`: {e}")
+            ).into_compile_error())?;
+
+            Some(quote! {
+                fn __groom_compression(&self) -> Option<::groom::response::Compression> {
+                    Some(#compression)
+                }
+            })
+        },
+        None => None,
+    };
+
     let supported_mimes_ident = &fragments.supported_mimes_ident;
     let supported_mimes = &fragments.supported_mimes;
 
@@ -470,9 +1060,10 @@ fn make_new_ast(fragments: NewAstFragments)
             #new_item_code
 
             #[allow(non_upper_case_globals)]
-            const #supported_mimes_ident: &[::mime::Mime] = &[
-                #(#supported_mimes)*
-            ];
+            static #supported_mimes_ident: ::std::sync::LazyLock<::std::vec::Vec<::mime::Mime>> =
+                ::std::sync::LazyLock::new(|| ::std::vec![
+                    #(#supported_mimes)*
+                ]);
 
             impl #item_ident {
                 #(#formatter_functions)*
@@ -498,6 +1089,8 @@ fn make_new_ast(fragments: NewAstFragments)
                 #check_response_codes_fn
 
                 #check_response_formats_fn
+
+                #compression_function
             }
 
             #(#type_assertions)*
@@ -505,6 +1098,38 @@ fn make_new_ast(fragments: NewAstFragments)
     )
 }
 
+/// Rejects a `binary` mime that another listed format already produces: negotiation would never
+/// pick the binary formatter.
+fn ensure_distinct_binary_mime(formats: &ResponseFormatsList, resp_args_span: &TokenStream) -> Result<(), TokenStream> {
+    let Some(binary) = &formats.binary else {
+        return Ok(());
+    };
+
+    let taken = [
+        (formats.plain_text, "plain_text", "text/plain"),
+        (formats.html, "html", "text/html"),
+        (formats.json, "json", "application/json"),
+        (formats.xml, "xml", "application/xml"),
+        (formats.xml, "xml", "text/xml"),
+        (formats.cbor, "cbor", "application/cbor"),
+        (formats.msgpack, "msgpack", "application/msgpack"),
+        (formats.msgpack, "msgpack", "application/x-msgpack"),
+        (formats.event_stream, "event_stream", "text/event-stream"),
+        (formats.ndjson, "ndjson", "application/x-ndjson"),
+        (formats.ndjson, "ndjson", "application/ndjson"),
+    ];
+
+    let essence = format!("{}/{}", binary.type_, binary.subtype);
+
+    match taken.iter().find(|(enabled, _, mime)| *enabled && *mime == essence) {
+        Some((_, format, _)) => Err(syn::Error::new_spanned(
+            resp_args_span,
+            format!("error in `#[Response]` annotation: binary mime `{}` is already produced by format `{format}`", binary.mime)
+        ).into_compile_error()),
+        None => Ok(()),
+    }
+}
+
 /// Makes `::groom::response::Response::__groom_into_response()` - the main function that
 /// converts this response's data into appropriate response headers & body.
 /// The negotiated mime is passed in from the pre-run `__groom_negotiate_content_type`
@@ -521,6 +1146,12 @@ fn make_groom_into_response_function(
     let fn_ident_for_text_plain = &fragments.into_response_text_plain_ident;
     let fn_ident_for_text_html = &fragments.into_response_text_html_ident;
     let fn_ident_for_application_json = &fragments.into_response_application_json_ident;
+    let fn_ident_for_application_xml = &fragments.into_response_application_xml_ident;
+    let fn_ident_for_application_cbor = &fragments.into_response_application_cbor_ident;
+    let fn_ident_for_application_msgpack = &fragments.into_response_application_msgpack_ident;
+    let fn_ident_for_text_event_stream = &fragments.into_response_text_event_stream_ident;
+    let fn_ident_for_application_ndjson = &fragments.into_response_application_ndjson_ident;
+    let fn_ident_for_binary = &fragments.into_response_binary_ident;
 
     let content_type_negotiation = if !resp_args.format.is_any() {
         quote! {
@@ -538,8 +1169,14 @@ fn make_groom_into_response_function(
 
         let default_content_response = match default_format {
             ResponseFormat::PlainText => quote! { self.#fn_ident_for_text_plain() },
-            ResponseFormat::Html => quote! { self.#fn_ident_for_text_html() },
+            ResponseFormat::Html => quote! { self.#fn_ident_for_text_html(ctx) },
             ResponseFormat::Json => quote! { self.#fn_ident_for_application_json() },
+            ResponseFormat::Xml => quote! { self.#fn_ident_for_application_xml() },
+            ResponseFormat::Cbor => quote! { self.#fn_ident_for_application_cbor() },
+            ResponseFormat::MsgPack => quote! { self.#fn_ident_for_application_msgpack() },
+            ResponseFormat::EventStream => quote! { self.#fn_ident_for_text_event_stream() },
+            ResponseFormat::NdJson => quote! { self.#fn_ident_for_application_ndjson() },
+            ResponseFormat::Binary => quote! { self.#fn_ident_for_binary() },
         };
 
         let mime_type_matches = make_mime_types_matches_for_content_negotiation(
@@ -575,7 +1212,7 @@ fn make_groom_into_response_function(
     };
 
     Ok(quote!{
-        fn __groom_into_response(self, negotiated: Option<&::mime::Mime>) -> ::axum::response::Response {
+        fn __groom_into_response(self, negotiated: Option<&::mime::Mime>, ctx: &::groom::response::RenderContext) -> ::axum::response::Response {
             #content_type_negotiation
         }
     })
@@ -596,7 +1233,7 @@ fn make_groom_negotiate_content_type_function(
     if !resp_args.format.is_any() {
         return Ok(quote! {
             fn __groom_negotiate_content_type(_accept: &::accept_header::Accept)
-                -> ::core::result::Result<Option<&'static ::mime::Mime>, ::axum::response::Response>
+                -> ::core::result::Result<Option<&'static ::mime::Mime>, ::groom::response::NotAcceptable>
             {
                 Ok(None)
             }
@@ -615,7 +1252,7 @@ fn make_groom_negotiate_content_type_function(
 
     Ok(quote! {
         fn __groom_negotiate_content_type(accept: &::accept_header::Accept)
-            -> ::core::result::Result<Option<&'static ::mime::Mime>, ::axum::response::Response>
+            -> ::core::result::Result<Option<&'static ::mime::Mime>, ::groom::response::NotAcceptable>
         {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -623,14 +1260,15 @@ fn make_groom_negotiate_content_type_function(
                 #default_mime_ref,
             ) {
                 Some(negotiated) => Ok(Some(negotiated)),
-                None => Err(::groom::response::not_acceptable(#supported_mimes_ident)),
+                None => Err(::groom::response::NotAcceptable(&#supported_mimes_ident)),
             }
         }
     })
 }
 
-/// Index of `default` in the `__GROOM_RESPONSE_SUPPORTED_MIMES_*` const, matching
-/// `populate_supported_mimes` order (plain_text, html, json).
+/// Index of `default` in the `__GROOM_RESPONSE_SUPPORTED_MIMES_*` static, matching
+/// `populate_supported_mimes` order (plain_text, html, json, xml, cbor, msgpack, event_stream,
+/// ndjson, binary).
 fn default_format_index_in_supported(
     resp_args: &ResponseArgsBase,
     default: ResponseFormat,
@@ -652,6 +1290,43 @@ fn default_format_index_in_supported(
         if default == ResponseFormat::Json {
             return index;
         }
+        index += 1;
+    }
+    if resp_args.format.xml {
+        if default == ResponseFormat::Xml {
+            return index;
+        }
+        // application/xml and text/xml
+        index += 2;
+    }
+    if resp_args.format.cbor {
+        if default == ResponseFormat::Cbor {
+            return index;
+        }
+        index += 1;
+    }
+    if resp_args.format.msgpack {
+        if default == ResponseFormat::MsgPack {
+            return index;
+        }
+        // application/msgpack and application/x-msgpack
+        index += 2;
+    }
+    if resp_args.format.event_stream {
+        if default == ResponseFormat::EventStream {
+            return index;
+        }
+        index += 1;
+    }
+    if resp_args.format.ndjson {
+        if default == ResponseFormat::NdJson {
+            return index;
+        }
+        // application/x-ndjson and application/ndjson
+        index += 2;
+    }
+    if resp_args.format.binary.is_some() && default == ResponseFormat::Binary {
+        return index;
     }
     panic!(
         "bug in groom: default_format not in formats list after detect_default_format"
@@ -696,7 +1371,7 @@ fn make_mime_types_matches_for_content_negotiation(
     fragments: &NewAstFragments
 ) -> Vec<TokenStream>
 {
-    let mut result = Vec::with_capacity(3);
+    let mut result = Vec::with_capacity(9);
 
     if resp_args.format.plain_text {
         let formatter = &fragments.into_response_text_plain_ident;
@@ -708,7 +1383,7 @@ fn make_mime_types_matches_for_content_negotiation(
     if resp_args.format.html {
         let formatter = &fragments.into_response_text_html_ident;
         result.push(quote! {
-            (::mime::TEXT, ::mime::HTML) => self.#formatter(),
+            (::mime::TEXT, ::mime::HTML) => self.#formatter(ctx),
         });
     }
 
@@ -719,6 +1394,50 @@ fn make_mime_types_matches_for_content_negotiation(
         });
     }
 
+    if resp_args.format.xml {
+        let formatter = &fragments.into_response_application_xml_ident;
+        result.push(quote! {
+            (::mime::APPLICATION, ::mime::XML) => self.#formatter(),
+            (::mime::TEXT, ::mime::XML) => ::groom::xml::as_text_xml(self.#formatter()),
+        });
+    }
+
+    if resp_args.format.cbor {
+        let formatter = &fragments.into_response_application_cbor_ident;
+        result.push(quote! {
+            (::mime::APPLICATION, subtype) if subtype == "cbor" => self.#formatter(),
+        });
+    }
+
+    if resp_args.format.msgpack {
+        let formatter = &fragments.into_response_application_msgpack_ident;
+        result.push(quote! {
+            (::mime::APPLICATION, subtype) if subtype == ::mime::MSGPACK || subtype == "x-msgpack" => self.#formatter(),
+        });
+    }
+
+    if resp_args.format.event_stream {
+        let formatter = &fragments.into_response_text_event_stream_ident;
+        result.push(quote! {
+            (::mime::TEXT, ::mime::EVENT_STREAM) => self.#formatter(),
+        });
+    }
+
+    if resp_args.format.ndjson {
+        let formatter = &fragments.into_response_application_ndjson_ident;
+        result.push(quote! {
+            (::mime::APPLICATION, subtype) if subtype == "x-ndjson" || subtype == "ndjson" => self.#formatter(),
+        });
+    }
+
+    if let Some(binary) = &resp_args.format.binary {
+        let formatter = &fragments.into_response_binary_ident;
+        let (type_, subtype) = (&binary.type_, &binary.subtype);
+        result.push(quote! {
+            (type_, subtype) if type_ == #type_ && subtype == #subtype => self.#formatter(),
+        });
+    }
+
     result
 }
 
@@ -726,13 +1445,13 @@ fn make_mime_types_matches_for_content_negotiation(
 mod enum_impl {
     use darling::FromMeta;
     use proc_macro2::{Ident, TokenStream};
-    use syn::{Attribute, Field, ItemEnum, Variant};
-    use quote::{quote, ToTokens};
+    use syn::{Attribute, Field, Fields, ItemEnum, Type, Variant};
+    use quote::{format_ident, quote, ToTokens};
 
     use crate::comments::get_docblock;
     use crate::extract_macro_arguments;
     use crate::response;
-    use crate::response::{extract_response_code, make_openapi_fragments_for_type, NewAstFragments, populate_supported_mimes, ResponseFormatsList, ResponseVariantAnnotation};
+    use crate::response::{extract_response_code, make_openapi_fragments_for_type, openapi_headers, response_expr, take_header_annotation, HeaderField, NewAstFragments, populate_supported_mimes, ResponseFormatsList, ResponseVariantAnnotation};
 
     /// Each enum variant produces a list of matchers for each supported content type.
     /// Each matcher calls an appropriate `into_response_*` function
@@ -743,6 +1462,12 @@ mod enum_impl {
         match_enum_for_text_plain: Vec<TokenStream>,
         match_enum_for_text_html: Vec<TokenStream>,
         match_enum_for_application_json: Vec<TokenStream>,
+        match_enum_for_application_xml: Vec<TokenStream>,
+        match_enum_for_application_cbor: Vec<TokenStream>,
+        match_enum_for_application_msgpack: Vec<TokenStream>,
+        match_enum_for_text_event_stream: Vec<TokenStream>,
+        match_enum_for_application_ndjson: Vec<TokenStream>,
+        match_enum_for_binary: Vec<TokenStream>,
     }
 
     /// Entry point for generation of `#[Response]` code for `enum`.
@@ -762,6 +1487,7 @@ mod enum_impl {
         );
 
         let mut response_codes_checks: Vec<TokenStream> = Vec::new();
+        let mut has_header_fields = false;
 
         for mut variant in enum_impl.variants {
             let variant_annotation = extract_variant_annotation(&mut variant)?;
@@ -769,18 +1495,19 @@ mod enum_impl {
             let (response_code_u16, response_code_ts) =
                 extract_response_code(variant_annotation.code, &variant)?;
 
-            let response_body_field = extract_response_body_field(&variant)?;
+            let variant_fields = extract_response_fields(&mut variant)?;
+            has_header_fields |= !variant_fields.headers.is_empty();
 
             populate_content_type_matchers(
                 &variant.ident,
-                &response_body_field,
+                &variant_fields,
                 &response_code_ts,
                 &fragments.response_args.format,
                 &mut matchers,
             )?;
 
             populate_openapi_impls(
-                &response_body_field,
+                &variant_fields,
                 response_code_u16,
                 &variant.attrs,
                 &mut fragments,
@@ -796,9 +1523,16 @@ mod enum_impl {
 
         make_formatter_functions(&matchers, &mut fragments);
 
+        // binary payloads (`Bytes`, files, ...), event streams and header values have no schema
+        // of their own
+        let derive_to_schema = match fragments.response_args.format.is_unserialized_only() || has_header_fields {
+            true => quote! {},
+            false => quote! { #[derive(::utoipa::ToSchema)] },
+        };
+
         let vis = &enum_impl.vis;
         fragments.new_item_ast = quote! {
-            #[derive(::utoipa::ToSchema)]
+            #derive_to_schema
             #vis enum #ident {
                 #(#variants_ts)*
             }
@@ -839,110 +1573,152 @@ mod enum_impl {
         }
     }
 
-    /// If `variant` has a field that can be used as a response body, extracts this field AST.
-    fn extract_response_body_field(variant: &Variant) -> Result<Option<&Field>, TokenStream> {
-        match &variant.fields {
-            syn::Fields::Named(fields) => {
-                Err(syn::Error::new_spanned(fields, "error in `#[Response]` annotation: named fields are not supported").into_compile_error())
-                // todo: support something like http::response::Parts
-            },
+    /// Fields of an enum variant: at most one body field, the rest are header fields.
+    struct VariantFields {
+        /// `Self::Variant(body, header_1)`-like pattern binding the fields
+        pattern: TokenStream,
+        body: Option<Field>,
+        headers: Vec<HeaderField>,
+    }
 
-            syn::Fields::Unnamed(fields) => {
-                if fields.unnamed.len() != 1 {
-                    return Err(
-                        syn::Error::new_spanned(fields, "error in `#[Response]` annotation: only exactly one unnamed field is supported").into_compile_error()
-                    );
-                }
+    /// Splits variant fields into the response body field and header fields.
+    fn extract_response_fields(variant: &mut Variant) -> Result<VariantFields, TokenStream> {
+        let variant_ident = &variant.ident;
 
-                Ok(Some(fields.unnamed.first().expect("length is checked right above")))
-            },
+        let mut body: Option<Field> = None;
+        let mut headers: Vec<HeaderField> = Vec::new();
+        let mut bindings: Vec<TokenStream> = Vec::new();
 
-            syn::Fields::Unit =>
-                Ok(None),
-        }
-    }
+        for (i, field) in variant.fields.iter_mut().enumerate() {
+            let binding = match take_header_annotation(field)? {
+                Some(kind) => {
+                    let binding = format_ident!("header_{i}");
+                    headers.push(HeaderField::new(kind, field, quote! { &#binding }));
+                    binding
+                },
 
-    /// Unit-variant match arm shared by all format matchers (identical TokenStream).
-    fn unit_variant_into_response_arm(
-        variant_ident: &Ident,
-        response_code_ts: &TokenStream,
-    ) -> TokenStream {
-        quote! {
-            Self::#variant_ident => (#response_code_ts).into_response(),
+                None => {
+                    if body.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            &field,
+                            format!("error in `#[Response]` annotation: variant `{variant_ident}` has more than one body field; mark header fields with `#[Response(header(name = \"...\"))]` and cookie fields with `#[Response(cookie(name = \"...\"))]`")
+                        ).into_compile_error());
+                    }
+
+                    body = Some(field.clone());
+                    format_ident!("body")
+                },
+            };
+
+            bindings.push(match &field.ident {
+                Some(field_ident) => quote! { #field_ident: #binding },
+                None => quote! { #binding },
+            });
         }
+
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! { Self::#variant_ident { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { Self::#variant_ident(#(#bindings),*) },
+            Fields::Unit => quote! { Self::#variant_ident },
+        };
+
+        Ok(VariantFields { pattern, body, headers })
     }
 
     /// Makes matchers for content type negotiation.
     fn populate_content_type_matchers(
         variant_ident: &Ident,
-        response_body_field: &Option<&Field>,
+        variant_fields: &VariantFields,
         response_code_ts: &TokenStream,
         content_types: &ResponseFormatsList,
         matchers: &mut EnumMatchers,
     ) -> Result<(), TokenStream>
     {
+        let pattern = &variant_fields.pattern;
+        let headers = &variant_fields.headers;
+
+        // `#pattern => (code, body).into_response(),` for the body made by `make_body`
+        let arm = |make_body: &dyn Fn(&Type) -> TokenStream| {
+            let body = variant_fields.body.as_ref().map(|field| make_body(&field.ty));
+            let response = response_expr(response_code_ts, body, headers);
+
+            quote! {
+                #pattern => #response,
+            }
+        };
+
         // Let's make enum variants conversion for when no Accept header was supplied by the client.
         if !content_types.is_any() {
             // If no formats were specified for this Response, we need to ensure that we:
-            //  - either have only variants without fields in this enum and respond only with HTTP codes,
+            //  - either have only variants without body fields in this enum and respond only with HTTP codes and headers,
             //  - or raise a compile error telling the dev that they need to specify some response format.
-            matchers.match_enum_when_no_accept_header_found.push(match &response_body_field {
-                None => unit_variant_into_response_arm(variant_ident, response_code_ts),
+            if let Some(single_field) = &variant_fields.body {
+                return Err(
+                    syn::Error::new_spanned(
+                        single_field,
+                        format!("error in `#[Response]` annotation: specify at least one response format for enum `{variant_ident}` to be able to return data through it's variant `{variant_ident}` (e.g. #[Response(format(json))])")
+                    ).into_compile_error()
+                );
+            }
 
-                Some(single_field) => {
-                    return Err(
-                        syn::Error::new_spanned(
-                            single_field,
-                            format!("error in `#[Response]` annotation: specify at least one response format for enum `{variant_ident}` to be able to return data through it's variant `{variant_ident}` (e.g. #[Response(format(json))])")
-                        ).into_compile_error()
-                    );
-                }
-            });
+            matchers.match_enum_when_no_accept_header_found.push(arm(&|_| unreachable!("variant has no body")));
         }
 
         if content_types.plain_text {
-            matchers.match_enum_for_text_plain.push(match &response_body_field {
-                None => unit_variant_into_response_arm(variant_ident, response_code_ts),
-
-                Some(_single_field) =>
-                    quote! {
-                        Self::#variant_ident(body) => (
-                            #response_code_ts,
-                            Into::<String>::into(body)
-                        ).into_response(),
-                    },
-            });
+            matchers.match_enum_for_text_plain.push(arm(&|_| quote! {
+                Into::<String>::into(body)
+            }));
         }
 
         if content_types.html {
-            matchers.match_enum_for_text_html.push(match &response_body_field {
-                None => unit_variant_into_response_arm(variant_ident, response_code_ts),
+            matchers.match_enum_for_text_html.push(arm(&|ty| quote! {
+                <#ty as ::groom::response::HtmlFormat>::render_with(body, ctx)
+            }));
+        }
+
+        if content_types.json {
+            matchers.match_enum_for_application_json.push(arm(&|_| quote! {
+                ::axum::Json(body)
+            }));
+        }
 
-                Some(single_field) => {
-                    let ty = &single_field.ty;
+        if content_types.xml {
+            matchers.match_enum_for_application_xml.push(arm(&|_| quote! {
+                ::groom::xml::Xml(body)
+            }));
+        }
 
-                    quote! {
-                        Self::#variant_ident(body) => (
-                            #response_code_ts,
-                            <#ty as ::groom::response::HtmlFormat>::render(body)
-                        ).into_response(),
-                    }
-                },
-            });
+        if content_types.cbor {
+            matchers.match_enum_for_application_cbor.push(arm(&|_| quote! {
+                ::groom::cbor::Cbor(body)
+            }));
         }
 
-        if content_types.json {
-            matchers.match_enum_for_application_json.push(match &response_body_field {
-                None => unit_variant_into_response_arm(variant_ident, response_code_ts),
-
-                Some(_single_field) =>
-                    quote! {
-                        Self::#variant_ident(body) => (
-                            #response_code_ts,
-                            ::axum::Json(body)
-                        ).into_response(),
-                    },
-            });
+        if content_types.msgpack {
+            matchers.match_enum_for_application_msgpack.push(arm(&|_| quote! {
+                ::groom::msgpack::MsgPack(body)
+            }));
+        }
+
+        if content_types.event_stream {
+            matchers.match_enum_for_text_event_stream.push(arm(&|ty| quote! {
+                <#ty as ::groom::response::EventStreamFormat>::into_event_stream(body)
+            }));
+        }
+
+        if content_types.ndjson {
+            matchers.match_enum_for_application_ndjson.push(arm(&|ty| quote! {
+                <#ty as ::groom::response::NdJsonFormat>::into_ndjson(body)
+            }));
+        }
+
+        if let Some(binary) = &content_types.binary {
+            let mime = &binary.mime;
+
+            matchers.match_enum_for_binary.push(arm(&|ty| quote! {
+                <#ty as ::groom::response::BinaryFormat>::into_binary(body)
+                    .into_response_with_content_type(#mime)
+            }));
         }
 
         Ok(())
@@ -950,7 +1726,7 @@ mod enum_impl {
 
     /// Makes code to set up OpenAPI spec generation for this enum field.
     fn populate_openapi_impls(
-        response_body_field: &Option<&Field>,
+        variant_fields: &VariantFields,
         response_code_u16: u16,
         variant_attributes: &[Attribute],
         fragments: &mut NewAstFragments,
@@ -963,13 +1739,16 @@ mod enum_impl {
 
         let response_code_str = format!("{response_code_u16}");
 
-        match &response_body_field {
+        match &variant_fields.body {
             None => {
+                let header_impls = openapi_headers(&variant_fields.headers);
+
                 fragments.openapi_impls.push(quote! {
                         let op = op.response(
                             #response_code_str,
                             ::utoipa::openapi::ResponseBuilder::new()
                                 .description(#description_tk)
+                                #(#header_impls)*
                                 .build()
                         );
                     });
@@ -978,11 +1757,13 @@ mod enum_impl {
             Some(single_field) => {
                 let ty = &single_field.ty;
 
-                fragments.type_assertions.push(quote! {
-                    assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
-                });
+                if !fragments.response_args.format.is_unserialized_only() {
+                    fragments.type_assertions.push(quote! {
+                        assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
+                    });
+                }
 
-                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, fragments);
+                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, &variant_fields.headers, fragments);
             },
         };
     }
@@ -1018,7 +1799,14 @@ mod enum_impl {
         if resp_args.format.html {
             let formatter = &fragments.into_response_text_html_ident;
             let matcher = &matchers.match_enum_for_text_html;
-            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+            // templates may use the `RenderContext` of the request
+            fragments.formatter_functions.push(quote! {
+                fn #formatter(self, ctx: &::groom::response::RenderContext) -> ::axum::response::Response {
+                    match self {
+                        #(#matcher)*
+                    }
+                }
+            });
         }
 
         if resp_args.format.json {
@@ -1026,6 +1814,42 @@ mod enum_impl {
             let matcher = &matchers.match_enum_for_application_json;
             fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
         }
+
+        if resp_args.format.xml {
+            let formatter = &fragments.into_response_application_xml_ident;
+            let matcher = &matchers.match_enum_for_application_xml;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
+
+        if resp_args.format.cbor {
+            let formatter = &fragments.into_response_application_cbor_ident;
+            let matcher = &matchers.match_enum_for_application_cbor;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
+
+        if resp_args.format.msgpack {
+            let formatter = &fragments.into_response_application_msgpack_ident;
+            let matcher = &matchers.match_enum_for_application_msgpack;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
+
+        if resp_args.format.event_stream {
+            let formatter = &fragments.into_response_text_event_stream_ident;
+            let matcher = &matchers.match_enum_for_text_event_stream;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
+
+        if resp_args.format.ndjson {
+            let formatter = &fragments.into_response_application_ndjson_ident;
+            let matcher = &matchers.match_enum_for_application_ndjson;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
+
+        if resp_args.format.binary.is_some() {
+            let formatter = &fragments.into_response_binary_ident;
+            let matcher = &matchers.match_enum_for_binary;
+            fragments.formatter_functions.push(match_self_formatter(formatter, matcher));
+        }
     }
 }
 
@@ -1035,18 +1859,25 @@ mod struct_impl {
     use darling::FromMeta;
     use proc_macro2::TokenStream;
     use quote::quote;
-    use syn::{Fields, ItemStruct};
+    use syn::{Fields, FieldsUnnamed, ItemStruct, parse_quote};
     use crate::{extract_macro_arguments, response};
     use crate::comments::get_docblock;
-    use crate::response::{extract_response_code, make_openapi_fragments_for_type, NewAstFragments, populate_supported_mimes, ResponseArgsStruct};
+    use crate::response::{extract_response_code, make_openapi_fragments_for_type, response_expr, take_header_annotation, HeaderField, NewAstFragments, populate_supported_mimes, ResponseArgsStruct};
 
-    pub(crate) fn make_fragments_for_struct(struct_impl: ItemStruct, args: TokenStream) -> Result<NewAstFragments, TokenStream> {
+    pub(crate) fn make_fragments_for_struct(mut struct_impl: ItemStruct, args: TokenStream) -> Result<NewAstFragments, TokenStream> {
         let resp_args = extract_macro_arguments!(response::ResponseArgsStruct, &args)?;
         let mut fragments = NewAstFragments::new(&struct_impl.ident, resp_args.base_args.clone(), args);
 
-        fragments.new_item_ast = quote! {
-            #[DTO(response)]
-            #struct_impl
+        let is_dto = !resp_args.base_args.format.is_unserialized_only();
+        let headers = take_header_fields(&mut struct_impl, is_dto)?;
+
+        // binary payloads and event streams are not serialized, so such a struct is not a DTO
+        fragments.new_item_ast = match is_dto {
+            false => quote! { #struct_impl },
+            true => quote! {
+                #[DTO(response)]
+                #struct_impl
+            },
         };
 
         populate_supported_mimes(
@@ -1054,13 +1885,14 @@ mod struct_impl {
             &mut fragments.supported_mimes,
         );
 
-        make_formatter_functions(&resp_args, &struct_impl, &mut fragments)?;
+        make_formatter_functions(&resp_args, &struct_impl, &headers, &mut fragments)?;
 
         let code = resp_args.code.0;
 
         populate_openapi_impls(
             &struct_impl,
             code,
+            &headers,
             &mut fragments
         );
 
@@ -1070,7 +1902,46 @@ mod struct_impl {
         Ok(fragments)
     }
 
-    fn make_formatter_functions(resp_args: &ResponseArgsStruct, struct_impl: &ItemStruct, fragments: &mut NewAstFragments) -> Result<(), TokenStream> {
+    /// Takes header fields out of the struct body: their annotations are removed and, for DTOs,
+    /// they are skipped by serde (and therefore by the schema).
+    fn take_header_fields(struct_impl: &mut ItemStruct, is_dto: bool) -> Result<Vec<HeaderField>, TokenStream> {
+        let ident = struct_impl.ident.clone();
+        let mut headers = Vec::new();
+
+        match &mut struct_impl.fields {
+            Fields::Named(fields) => {
+                for field in fields.named.iter_mut() {
+                    let Some(kind) = take_header_annotation(field)? else {
+                        continue;
+                    };
+
+                    let field_ident = &field.ident;
+                    headers.push(HeaderField::new(kind, field, quote! { &self.#field_ident }));
+
+                    if is_dto {
+                        field.attrs.push(parse_quote! { #[serde(skip)] });
+                    }
+                }
+            },
+
+            Fields::Unnamed(fields) => {
+                for field in fields.unnamed.iter_mut() {
+                    if take_header_annotation(field)?.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format!("error in `#[Response]` annotation: header and cookie fields require named fields in struct `{ident}` (e.g. `struct {ident} {{ #[Response(header(name = \"Location\"))] location: String, ... }}`)")
+                        ).into_compile_error());
+                    }
+                }
+            },
+
+            Fields::Unit => {},
+        }
+
+        Ok(headers)
+    }
+
+    fn make_formatter_functions(resp_args: &ResponseArgsStruct, struct_impl: &ItemStruct, headers: &[HeaderField], fragments: &mut NewAstFragments) -> Result<(), TokenStream> {
         let (_, response_code_ts) = extract_response_code(resp_args.code, struct_impl)?;
 
         let ident = &struct_impl.ident;
@@ -1086,10 +1957,22 @@ mod struct_impl {
                 );
             }
 
+        let has_only_header_fields = matches!(&struct_impl.fields, Fields::Named(f) if f.named.len() == headers.len());
+
+        if has_only_header_fields && base_args.format.is_any() {
+            return Err(
+                syn::Error::new_spanned(
+                    struct_impl,
+                    format!("error in `#[Response]` annotation: no response formats are allowed for struct `{ident}` because all of its fields are headers; remove format definition (e.g. #[Response(code = 201)])")
+                ).into_compile_error()
+            );
+        }
+
         if !base_args.format.is_any() {
             let formatter = &fragments.into_response_any_content_type_ident;
 
             let resp = match struct_impl.fields {
+                Fields::Named(_) if has_only_header_fields => response_expr(&response_code_ts, None, headers),
                 Fields::Named(_) | Fields::Unnamed(_) => {
                     return Err(
                         syn::Error::new_spanned(
@@ -1110,87 +1993,147 @@ mod struct_impl {
             });
         };
 
-        if base_args.format.plain_text {
-            let formatter = &fragments.into_response_text_plain_ident;
-
-            let resp = match &struct_impl.fields {
-                Fields::Named(_) => {
-                    quote!{
-                        (
-                            #response_code_ts,
-                            Into::<String>::into(self)
-                        ).into_response()
-                    }
-                },
-                Fields::Unnamed(f) => {
-                    if f.unnamed.is_empty() {
-                        return Err(
-                            syn::Error::new_spanned(
-                                struct_impl,
-                                format!("error in `#[Response]` annotation: no fields are specified for struct `{ident}`. Please remove the parentheses (e.g. `struct {ident};`)")
-                            ).into_compile_error()
-                        );
-                    }
-
-                    if f.unnamed.len() > 1 {
-                        return Err(
-                            syn::Error::new_spanned(
-                                struct_impl,
-                                format!("error in `#[Response]` annotation: more then one unnamed field is specified for struct `{ident}`. Please remove the extra fields or give them names (e.g. `struct {ident} {{ field_0: String, ... }};`)")
-                            ).into_compile_error()
-                        );
-                    }
-
-                    quote! {
-                        (
-                            #response_code_ts,
-                            Into::<String>::into(self.0)
-                        ).into_response()
-                    }
-                },
-                Fields::Unit => {
-                    quote! {(#response_code_ts).into_response()}
-                }
-            };
+        // templates may use the `RenderContext` of the request
+        if base_args.format.html {
+            let formatter = &fragments.into_response_text_html_ident;
+            let resp = response_expr(&response_code_ts, Some(quote! {
+                <#ident as ::groom::response::HtmlFormat>::render_with(self, ctx)
+            }), headers);
 
             fragments.formatter_functions.push(quote! {
-                fn #formatter(self) -> ::axum::response::Response {
+                fn #formatter(self, ctx: &::groom::response::RenderContext) -> ::axum::response::Response {
                     #resp
                 }
             });
         }
 
-        if base_args.format.html {
-            let formatter = &fragments.into_response_text_html_ident;
+        // `fn into_response_*(self)` responding with `body`
+        let mut push_formatter = |formatter: &proc_macro2::Ident, body: TokenStream| {
+            let resp = response_expr(&response_code_ts, Some(body), headers);
+
             fragments.formatter_functions.push(quote! {
                 fn #formatter(self) -> ::axum::response::Response {
-                    (
-                        #response_code_ts,
-                        <#ident as ::groom::response::HtmlFormat>::render(self)
-                    ).into_response()
+                    #resp
                 }
             });
+        };
+
+        if base_args.format.plain_text {
+            let body = match &struct_impl.fields {
+                Fields::Unnamed(f) => {
+                    ensure_single_unnamed_field(f, struct_impl)?;
+
+                    quote! { Into::<String>::into(self.0) }
+                },
+                _ => quote! { Into::<String>::into(self) },
+            };
+
+            push_formatter(&fragments.into_response_text_plain_ident, body);
         }
 
         if base_args.format.json {
-            let formatter = &fragments.into_response_application_json_ident;
-            fragments.formatter_functions.push(quote! {
-                fn #formatter(self) -> ::axum::response::Response {
-                    (
-                        #response_code_ts,
-                        ::axum::Json(self)
-                    ).into_response()
-                }
+            push_formatter(&fragments.into_response_application_json_ident, quote! {
+                ::axum::Json(self)
+            });
+        }
+
+        if base_args.format.xml {
+            push_formatter(&fragments.into_response_application_xml_ident, quote! {
+                ::groom::xml::Xml(self)
+            });
+        }
+
+        if base_args.format.cbor {
+            push_formatter(&fragments.into_response_application_cbor_ident, quote! {
+                ::groom::cbor::Cbor(self)
+            });
+        }
+
+        if base_args.format.msgpack {
+            push_formatter(&fragments.into_response_application_msgpack_ident, quote! {
+                ::groom::msgpack::MsgPack(self)
+            });
+        }
+
+        if base_args.format.event_stream {
+            let event_stream = match &struct_impl.fields {
+                Fields::Unnamed(f) => {
+                    ensure_single_unnamed_field(f, struct_impl)?;
+
+                    let ty = &f.unnamed.first().expect("fields count is checked right above").ty;
+                    quote! { <#ty as ::groom::response::EventStreamFormat>::into_event_stream(self.0) }
+                },
+                _ => quote! { <#ident as ::groom::response::EventStreamFormat>::into_event_stream(self) },
+            };
+
+            push_formatter(&fragments.into_response_text_event_stream_ident, event_stream);
+        }
+
+        if base_args.format.ndjson {
+            let ndjson = match &struct_impl.fields {
+                Fields::Unnamed(f) => {
+                    ensure_single_unnamed_field(f, struct_impl)?;
+
+                    let ty = &f.unnamed.first().expect("fields count is checked right above").ty;
+                    quote! { <#ty as ::groom::response::NdJsonFormat>::into_ndjson(self.0) }
+                },
+                _ => quote! { <#ident as ::groom::response::NdJsonFormat>::into_ndjson(self) },
+            };
+
+            push_formatter(&fragments.into_response_application_ndjson_ident, ndjson);
+        }
+
+        if let Some(binary) = &base_args.format.binary {
+            let mime = &binary.mime;
+
+            let binary = match &struct_impl.fields {
+                Fields::Unnamed(f) => {
+                    ensure_single_unnamed_field(f, struct_impl)?;
+
+                    let ty = &f.unnamed.first().expect("fields count is checked right above").ty;
+                    quote! { <#ty as ::groom::response::BinaryFormat>::into_binary(self.0) }
+                },
+                _ => quote! { <#ident as ::groom::response::BinaryFormat>::into_binary(self) },
+            };
+
+            push_formatter(&fragments.into_response_binary_ident, quote! {
+                #binary.into_response_with_content_type(#mime)
             });
         }
 
         Ok(())
     }
 
+    /// Tuple structs are formatted through their only field.
+    fn ensure_single_unnamed_field(fields: &FieldsUnnamed, struct_impl: &ItemStruct) -> Result<(), TokenStream> {
+        let ident = &struct_impl.ident;
+
+        if fields.unnamed.is_empty() {
+            return Err(
+                syn::Error::new_spanned(
+                    struct_impl,
+                    format!("error in `#[Response]` annotation: no fields are specified for struct `{ident}`. Please remove the parentheses (e.g. `struct {ident};`)")
+                ).into_compile_error()
+            );
+        }
+
+        if fields.unnamed.len() > 1 {
+            return Err(
+                syn::Error::new_spanned(
+                    struct_impl,
+                    format!("error in `#[Response]` annotation: more then one unnamed field is specified for struct `{ident}`. Please remove the extra fields or give them names (e.g. `struct {ident} {{ field_0: String, ... }};`)")
+                ).into_compile_error()
+            );
+        }
+
+        Ok(())
+    }
+
     /// Makes code to set up OpenAPI spec generation for this struct.
     fn populate_openapi_impls(
         struct_impl: &ItemStruct,
         response_code_u16: u16,
+        headers: &[HeaderField],
         fragments: &mut NewAstFragments,
     )
     {
@@ -1217,20 +2160,24 @@ mod struct_impl {
                 let single_field = f.unnamed.first().expect("fields count is checked in make_formatter_functions()");
 
                 let ty = &single_field.ty;
-                fragments.type_assertions.push(quote! {
-                    assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
-                });
+                if !fragments.response_args.format.is_unserialized_only() {
+                    fragments.type_assertions.push(quote! {
+                        assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
+                    });
+                }
 
-                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, fragments);
+                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, headers, fragments);
             }
 
             Fields::Named(_) => {
                 let ty = &struct_impl.ident;
-                fragments.type_assertions.push(quote! {
-                    assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
-                });
+                if !fragments.response_args.format.is_unserialized_only() {
+                    fragments.type_assertions.push(quote! {
+                        assert_impl_any!(#ty: ::utoipa::PartialSchema, ::groom::DTO_Response);
+                    });
+                }
 
-                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, fragments);
+                make_openapi_fragments_for_type(quote!{#ty}, description_tk, response_code_str, headers, fragments);
             }
         }
     }
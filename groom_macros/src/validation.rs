@@ -0,0 +1,334 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Expr, Field, ItemStruct, LitStr, Type, parse_quote};
+use syn::ext::IdentExt;
+
+use crate::annotation_attrs::{parse_attr, remove_attrs};
+
+// region: Annotation args -------------------------------------------------------------------------
+//
+
+/// `#[validate(...)]` field attribute arguments.
+#[derive(FromMeta, Default)]
+pub(crate) struct ValidateArgs {
+    #[darling(default)]
+    length: Option<LengthArgs>,
+
+    #[darling(default)]
+    range: Option<RangeArgs>,
+
+    #[darling(default)]
+    pattern: Option<String>,
+
+    #[darling(default)]
+    email: bool,
+
+    #[darling(default)]
+    non_empty: bool,
+
+    /// checks the field with its own `DTO::__groom_validate`
+    #[darling(default)]
+    nested: bool,
+}
+
+/// `#[validate(length(min = 1, max = 100))]`
+#[derive(FromMeta, Default)]
+struct LengthArgs {
+    #[darling(default)]
+    min: Option<usize>,
+
+    #[darling(default)]
+    max: Option<usize>,
+}
+
+/// `#[validate(range(min = 0, max = 130))]`
+#[derive(FromMeta, Default)]
+struct RangeArgs {
+    #[darling(default)]
+    min: Option<Expr>,
+
+    #[darling(default)]
+    max: Option<Expr>,
+}
+
+//
+// endregion: Annotation args ----------------------------------------------------------------------
+
+// region: AST parsing and generation --------------------------------------------------------------
+//
+
+/// Takes `#[validate(...)]` attributes off the fields of a struct.
+///
+/// Each constraint is mirrored into a `#[schema(...)]` attribute (and `#[param(...)]` when the
+/// struct derives `IntoParams`) so that OpenAPI documents it. Errors name fields as serde
+/// deserializes them. Returns the body of `__groom_validate`, or `None` if no field is
+/// constrained.
+pub(crate) fn take_field_constraints(item_struct: &mut ItemStruct, with_params: bool) -> Result<Option<TokenStream>, TokenStream> {
+    let rename_all = serde_rename_all(&item_struct.attrs).map_err(|e| e.into_compile_error())?;
+    let mut checks = Vec::new();
+
+    for field in item_struct.fields.iter_mut() {
+        let args = parse_attr::<ValidateArgs>("validate", &field.attrs).map_err(|e| e.write_errors())?;
+        remove_attrs("validate", &mut field.attrs);
+
+        let Some(args) = args else {
+            continue;
+        };
+
+        let Some(field_ident) = field.ident.clone() else {
+            return Err(syn::Error::new_spanned(
+                field.into_token_stream(),
+                "error in #[validate] annotation: constraints are only supported on named fields"
+            ).into_compile_error());
+        };
+
+        let (optional, inner_ty) = option_inner(&field.ty);
+        let is_collection = last_segment_is(inner_ty, "Vec");
+        let field_name = serde_field_name(field, &field_ident.unraw().to_string(), rename_all.as_deref())
+            .map_err(|e| e.into_compile_error())?;
+
+        let mut field_checks = Vec::new();
+        let mut schema_features = Vec::new();
+
+        let mut min_length = args.length.as_ref().and_then(|l| l.min);
+        let max_length = args.length.as_ref().and_then(|l| l.max);
+
+        if let Some(length) = &args.length {
+            let min = option_tokens(length.min.map(|v| quote! { #v }));
+            let max = option_tokens(length.max.map(|v| quote! { #v }));
+
+            field_checks.push(quote! {
+                ::groom::validation::length(&mut errors, #field_name, value, #min, #max);
+            });
+        }
+
+        if args.non_empty {
+            min_length = Some(min_length.unwrap_or(1).max(1));
+
+            field_checks.push(quote! {
+                ::groom::validation::non_empty(&mut errors, #field_name, value);
+            });
+        }
+
+        let (min_feature, max_feature) = match is_collection {
+            true => (quote! { min_items }, quote! { max_items }),
+            false => (quote! { min_length }, quote! { max_length }),
+        };
+
+        if let Some(v) = min_length {
+            schema_features.push(quote! { #min_feature = #v });
+        }
+
+        if let Some(v) = max_length {
+            schema_features.push(quote! { #max_feature = #v });
+        }
+
+        if let Some(range) = &args.range {
+            let min = option_tokens(range.min.as_ref().map(|v| quote! { #v }));
+            let max = option_tokens(range.max.as_ref().map(|v| quote! { #v }));
+
+            field_checks.push(quote! {
+                ::groom::validation::range(&mut errors, #field_name, value, #min, #max);
+            });
+
+            if let Some(v) = &range.min {
+                schema_features.push(quote! { minimum = #v });
+            }
+
+            if let Some(v) = &range.max {
+                schema_features.push(quote! { maximum = #v });
+            }
+        }
+
+        if let Some(pattern) = &args.pattern {
+            if let Err(e) = regex::Regex::new(pattern) {
+                return Err(syn::Error::new_spanned(
+                    field.into_token_stream(),
+                    format!("error in #[validate] annotation: invalid `pattern` of field `{field_name}`: {e}")
+                ).into_compile_error());
+            }
+
+            field_checks.push(quote! {
+                {
+                    static PATTERN: ::std::sync::LazyLock<::groom::validation::Regex> =
+                        ::std::sync::LazyLock::new(|| ::groom::validation::Regex::new(#pattern).unwrap());
+
+                    ::groom::validation::pattern(&mut errors, #field_name, value, &PATTERN);
+                }
+            });
+
+            schema_features.push(quote! { pattern = #pattern });
+        }
+
+        if args.email {
+            field_checks.push(quote! {
+                ::groom::validation::email(&mut errors, #field_name, value);
+            });
+
+            schema_features.push(quote! { format = Email });
+        }
+
+        if args.nested {
+            field_checks.push(match is_collection {
+                true => quote! { ::groom::validation::nested_items(&mut errors, #field_name, value); },
+                false => quote! { ::groom::validation::nested(&mut errors, #field_name, value); },
+            });
+        }
+
+        if !schema_features.is_empty() {
+            field.attrs.push(parse_quote! { #[schema(#(#schema_features),*)] });
+
+            if with_params {
+                field.attrs.push(parse_quote! { #[param(#(#schema_features),*)] });
+            }
+        }
+
+        checks.push(match optional {
+            true => quote! {
+                if let ::core::option::Option::Some(value) = &self.#field_ident {
+                    #(#field_checks)*
+                }
+            },
+            false => quote! {
+                {
+                    let value = &self.#field_ident;
+                    #(#field_checks)*
+                }
+            },
+        });
+    }
+
+    if checks.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(quote! {
+        let mut errors = ::groom::validation::ValidationErrors::new();
+        #(#checks)*
+        errors.into_result()
+    }))
+}
+
+/// `rename_all` rule of `#[serde(rename_all = "...")]` or its `deserialize` part.
+fn serde_rename_all(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    serde_deserialize_name(attrs, "rename_all")
+}
+
+/// Name of a field in the deserialized input: its `#[serde(rename)]`, or its name under the
+/// `rename_all` rule of the struct.
+fn serde_field_name(field: &Field, name: &str, rename_all: Option<&str>) -> syn::Result<String> {
+    if let Some(rename) = serde_deserialize_name(&field.attrs, "rename")? {
+        return Ok(rename);
+    }
+
+    Ok(match rename_all {
+        Some(rule) => apply_rename_rule(rule, name),
+        None => name.to_owned(),
+    })
+}
+
+/// Value of `#[serde(key = "...")]`, or of `deserialize` in `#[serde(key(deserialize = "..."))]`.
+fn serde_deserialize_name(attrs: &[Attribute], key: &str) -> syn::Result<Option<String>> {
+    let mut value = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(key) {
+                // skip the value of other serde attributes
+                if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    let _content;
+                    syn::parenthesized!(_content in meta.input);
+                }
+                return Ok(());
+            }
+
+            if meta.input.peek(syn::Token![=]) {
+                value = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else {
+                meta.parse_nested_meta(|nested| {
+                    let lit = nested.value()?.parse::<LitStr>()?;
+                    if nested.path.is_ident("deserialize") {
+                        value = Some(lit.value());
+                    }
+                    Ok(())
+                })?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(value)
+}
+
+/// Renames a snake_case field the way serde's `rename_all` does.
+fn apply_rename_rule(rule: &str, field: &str) -> String {
+    let pascal = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+
+        for ch in field.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+
+        pascal
+    };
+
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            }
+        },
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_owned(),
+    }
+}
+
+fn option_tokens(value: Option<TokenStream>) -> TokenStream {
+    match value {
+        Some(v) => quote! { ::core::option::Option::Some(#v) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last().is_some_and(|s| s.ident == name),
+        _ => false,
+    }
+}
+
+/// Splits `Option<T>` into `(true, T)`; other types are `(false, ty)`.
+fn option_inner(ty: &Type) -> (bool, &Type) {
+    if let Type::Path(p) = ty
+        && p.qself.is_none()
+        && let Some(segment) = p.path.segments.last()
+        && segment.ident == "Option"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return (true, inner);
+    }
+
+    (false, ty)
+}
+
+//
+// endregion: AST parsing and generation -----------------------------------------------------------
@@ -15,6 +15,7 @@ pub mod api_root {
     }
     async fn __groom_wrapper_get_root(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -24,13 +25,31 @@ pub mod api_root {
             None => None,
             Some(accept) => {
                 match <GetRootResponse>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = get_root().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     /// HTTP handler: POST /
     pub async fn post_root() -> GetRootResponse {
@@ -38,6 +57,7 @@ pub mod api_root {
     }
     async fn __groom_wrapper_post_root(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -47,13 +67,31 @@ pub mod api_root {
             None => None,
             Some(accept) => {
                 match <GetRootResponse>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = post_root().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     fn sync_util_fn(s: String) -> String {
         s
@@ -74,6 +112,7 @@ pub mod api_root {
     }
     async fn __groom_wrapper_rq_cons_query_struct(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
         input0: Query<RqConsQueryStruct>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
@@ -84,13 +123,40 @@ pub mod api_root {
             None => None,
             Some(accept) => {
                 match <RqConsQueryResponse>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
+        let mut validation_errors = ::groom::validation::ValidationErrors::new();
+        if let ::core::result::Result::Err(errors) = ::groom::extract::GroomExtractor::__groom_validate(
+            &input0,
+        ) {
+            validation_errors.append(errors);
+        }
+        if !validation_errors.is_empty() {
+            return ::axum::response::IntoResponse::into_response(validation_errors);
+        }
         let result = rq_cons_query_struct(input0).await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     /// Path<struct>
     ///
@@ -102,6 +168,7 @@ pub mod api_root {
     }
     async fn __groom_wrapper_rq_cons_path_struct(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
         input0: Path<RqConsPathStruct>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
@@ -112,13 +179,40 @@ pub mod api_root {
             None => None,
             Some(accept) => {
                 match <RqConsPathResponse>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
+        let mut validation_errors = ::groom::validation::ValidationErrors::new();
+        if let ::core::result::Result::Err(errors) = ::groom::extract::GroomExtractor::__groom_validate(
+            &input0,
+        ) {
+            validation_errors.append(errors);
+        }
+        if !validation_errors.is_empty() {
+            return ::axum::response::IntoResponse::into_response(validation_errors);
+        }
         let result = rq_cons_path_struct(input0).await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     /// HTTP handler: GET /json
     pub async fn resp_json() -> RespJsonResponse {
@@ -126,6 +220,7 @@ pub mod api_root {
     }
     async fn __groom_wrapper_resp_json(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -135,13 +230,31 @@ pub mod api_root {
             None => None,
             Some(accept) => {
                 match <RespJsonResponse>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = resp_json().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     async fn not_a_handler() {
         let a = 1;
@@ -156,10 +269,77 @@ pub mod api_root {
         /// You shall not pass!
         Forbidden,
     }
+    impl utoipa::__dev::ComposeSchema for GetRootResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(3usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                )
+                                .description(Some("There you go mate.")),
+                        )
+                        .required("Ok")
+                        .description(Some("There you go mate.")),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "BadRequest",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                )
+                                .description(Some("Are you insane?\n\nBad request.")),
+                        )
+                        .required("BadRequest")
+                        .description(Some("Are you insane?\n\nBad request.")),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["Forbidden"]))
+                        .description(Some("You shall not pass!")),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for GetRootResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("GetRootResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_GetRootResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_GetRootResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl GetRootResponse {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -417,6 +597,7 @@ pub mod api_root {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -519,7 +700,7 @@ pub mod api_root {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -529,8 +710,8 @@ pub mod api_root {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_GetRootResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_GetRootResponse,
                         ),
                     )
                 }
@@ -645,6 +826,40 @@ pub mod api_root {
     pub struct RqConsQueryStruct {
         name: String,
     }
+    impl utoipa::__dev::ComposeSchema for RqConsQueryStruct {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "name",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::String,
+                                ),
+                            ),
+                    )
+                    .required("name");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RqConsQueryStruct {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RqConsQueryStruct")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -659,7 +874,7 @@ pub mod api_root {
         impl<'de> _serde::Deserialize<'de> for RqConsQueryStruct {
             fn deserialize<__D>(
                 __deserializer: __D,
-            ) -> _serde::__private228::Result<Self, __D::Error>
+            ) -> _serde::__private229::Result<Self, __D::Error>
             where
                 __D: _serde::Deserializer<'de>,
             {
@@ -676,9 +891,9 @@ pub mod api_root {
                     type Value = __Field;
                     fn expecting(
                         &self,
-                        __formatter: &mut _serde::__private228::Formatter,
-                    ) -> _serde::__private228::fmt::Result {
-                        _serde::__private228::Formatter::write_str(
+                        __formatter: &mut _serde::__private229::Formatter,
+                    ) -> _serde::__private229::fmt::Result {
+                        _serde::__private229::Formatter::write_str(
                             __formatter,
                             "field identifier",
                         )
@@ -686,37 +901,37 @@ pub mod api_root {
                     fn visit_u64<__E>(
                         self,
                         __value: u64,
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            0u64 => _serde::__private228::Ok(__Field::__field0),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            0u64 => _serde::__private229::Ok(__Field::__field0),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                     fn visit_str<__E>(
                         self,
                         __value: &str,
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            "name" => _serde::__private228::Ok(__Field::__field0),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            "name" => _serde::__private229::Ok(__Field::__field0),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                     fn visit_bytes<__E>(
                         self,
                         __value: &[u8],
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            b"name" => _serde::__private228::Ok(__Field::__field0),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            b"name" => _serde::__private229::Ok(__Field::__field0),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                 }
@@ -725,7 +940,7 @@ pub mod api_root {
                     #[inline]
                     fn deserialize<__D>(
                         __deserializer: __D,
-                    ) -> _serde::__private228::Result<Self, __D::Error>
+                    ) -> _serde::__private229::Result<Self, __D::Error>
                     where
                         __D: _serde::Deserializer<'de>,
                     {
@@ -737,17 +952,17 @@ pub mod api_root {
                 }
                 #[doc(hidden)]
                 struct __Visitor<'de> {
-                    marker: _serde::__private228::PhantomData<RqConsQueryStruct>,
-                    lifetime: _serde::__private228::PhantomData<&'de ()>,
+                    marker: _serde::__private229::PhantomData<RqConsQueryStruct>,
+                    lifetime: _serde::__private229::PhantomData<&'de ()>,
                 }
                 #[automatically_derived]
                 impl<'de> _serde::de::Visitor<'de> for __Visitor<'de> {
                     type Value = RqConsQueryStruct;
                     fn expecting(
                         &self,
-                        __formatter: &mut _serde::__private228::Formatter,
-                    ) -> _serde::__private228::fmt::Result {
-                        _serde::__private228::Formatter::write_str(
+                        __formatter: &mut _serde::__private229::Formatter,
+                    ) -> _serde::__private229::fmt::Result {
+                        _serde::__private229::Formatter::write_str(
                             __formatter,
                             "struct RqConsQueryStruct",
                         )
@@ -756,16 +971,16 @@ pub mod api_root {
                     fn visit_seq<__A>(
                         self,
                         mut __seq: __A,
-                    ) -> _serde::__private228::Result<Self::Value, __A::Error>
+                    ) -> _serde::__private229::Result<Self::Value, __A::Error>
                     where
                         __A: _serde::de::SeqAccess<'de>,
                     {
                         let __field0 = match _serde::de::SeqAccess::next_element::<
                             String,
                         >(&mut __seq)? {
-                            _serde::__private228::Some(__value) => __value,
-                            _serde::__private228::None => {
-                                return _serde::__private228::Err(
+                            _serde::__private229::Some(__value) => __value,
+                            _serde::__private229::None => {
+                                return _serde::__private229::Err(
                                     _serde::de::Error::invalid_length(
                                         0usize,
                                         &"struct RqConsQueryStruct with 1 element",
@@ -773,7 +988,7 @@ pub mod api_root {
                                 );
                             }
                         };
-                        _serde::__private228::Ok(RqConsQueryStruct {
+                        _serde::__private229::Ok(RqConsQueryStruct {
                             name: __field0,
                         })
                     }
@@ -781,22 +996,22 @@ pub mod api_root {
                     fn visit_map<__A>(
                         self,
                         mut __map: __A,
-                    ) -> _serde::__private228::Result<Self::Value, __A::Error>
+                    ) -> _serde::__private229::Result<Self::Value, __A::Error>
                     where
                         __A: _serde::de::MapAccess<'de>,
                     {
-                        let mut __field0: _serde::__private228::Option<String> = _serde::__private228::None;
-                        while let _serde::__private228::Some(__key) = _serde::de::MapAccess::next_key::<
+                        let mut __field0: _serde::__private229::Option<String> = _serde::__private229::None;
+                        while let _serde::__private229::Some(__key) = _serde::de::MapAccess::next_key::<
                             __Field,
                         >(&mut __map)? {
                             match __key {
                                 __Field::__field0 => {
-                                    if _serde::__private228::Option::is_some(&__field0) {
-                                        return _serde::__private228::Err(
+                                    if _serde::__private229::Option::is_some(&__field0) {
+                                        return _serde::__private229::Err(
                                             <__A::Error as _serde::de::Error>::duplicate_field("name"),
                                         );
                                     }
-                                    __field0 = _serde::__private228::Some(
+                                    __field0 = _serde::__private229::Some(
                                         _serde::de::MapAccess::next_value::<String>(&mut __map)?,
                                     );
                                 }
@@ -808,12 +1023,12 @@ pub mod api_root {
                             }
                         }
                         let __field0 = match __field0 {
-                            _serde::__private228::Some(__field0) => __field0,
-                            _serde::__private228::None => {
-                                _serde::__private228::de::missing_field("name")?
+                            _serde::__private229::Some(__field0) => __field0,
+                            _serde::__private229::None => {
+                                _serde::__private229::de::missing_field("name")?
                             }
                         };
-                        _serde::__private228::Ok(RqConsQueryStruct {
+                        _serde::__private229::Ok(RqConsQueryStruct {
                             name: __field0,
                         })
                     }
@@ -825,8 +1040,8 @@ pub mod api_root {
                     "RqConsQueryStruct",
                     FIELDS,
                     __Visitor {
-                        marker: _serde::__private228::PhantomData::<RqConsQueryStruct>,
-                        lifetime: _serde::__private228::PhantomData,
+                        marker: _serde::__private229::PhantomData::<RqConsQueryStruct>,
+                        lifetime: _serde::__private229::PhantomData,
                     },
                 )
             }
@@ -838,10 +1053,63 @@ pub mod api_root {
         Ok(String),
         BadRequest(String),
     }
+    impl utoipa::__dev::ComposeSchema for RqConsQueryResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "BadRequest",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("BadRequest"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RqConsQueryResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RqConsQueryResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsQueryResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsQueryResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl RqConsQueryResponse {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -1024,6 +1292,7 @@ pub mod api_root {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -1119,7 +1388,7 @@ pub mod api_root {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -1129,8 +1398,8 @@ pub mod api_root {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsQueryResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RqConsQueryResponse,
                         ),
                     )
                 }
@@ -1242,6 +1511,58 @@ pub mod api_root {
         user_id: String,
         team_id: i32,
     }
+    impl utoipa::__dev::ComposeSchema for RqConsPathStruct {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "user_id",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::String,
+                                ),
+                            ),
+                    )
+                    .required("user_id");
+                object = object
+                    .property(
+                        "team_id",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Integer,
+                                ),
+                            )
+                            .format(
+                                Some(
+                                    utoipa::openapi::schema::SchemaFormat::KnownFormat(
+                                        utoipa::openapi::schema::KnownFormat::Int32,
+                                    ),
+                                ),
+                            ),
+                    )
+                    .required("team_id");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RqConsPathStruct {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RqConsPathStruct")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -1256,7 +1577,7 @@ pub mod api_root {
         impl<'de> _serde::Deserialize<'de> for RqConsPathStruct {
             fn deserialize<__D>(
                 __deserializer: __D,
-            ) -> _serde::__private228::Result<Self, __D::Error>
+            ) -> _serde::__private229::Result<Self, __D::Error>
             where
                 __D: _serde::Deserializer<'de>,
             {
@@ -1274,9 +1595,9 @@ pub mod api_root {
                     type Value = __Field;
                     fn expecting(
                         &self,
-                        __formatter: &mut _serde::__private228::Formatter,
-                    ) -> _serde::__private228::fmt::Result {
-                        _serde::__private228::Formatter::write_str(
+                        __formatter: &mut _serde::__private229::Formatter,
+                    ) -> _serde::__private229::fmt::Result {
+                        _serde::__private229::Formatter::write_str(
                             __formatter,
                             "field identifier",
                         )
@@ -1284,40 +1605,40 @@ pub mod api_root {
                     fn visit_u64<__E>(
                         self,
                         __value: u64,
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            0u64 => _serde::__private228::Ok(__Field::__field0),
-                            1u64 => _serde::__private228::Ok(__Field::__field1),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            0u64 => _serde::__private229::Ok(__Field::__field0),
+                            1u64 => _serde::__private229::Ok(__Field::__field1),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                     fn visit_str<__E>(
                         self,
                         __value: &str,
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            "user_id" => _serde::__private228::Ok(__Field::__field0),
-                            "team_id" => _serde::__private228::Ok(__Field::__field1),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            "user_id" => _serde::__private229::Ok(__Field::__field0),
+                            "team_id" => _serde::__private229::Ok(__Field::__field1),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                     fn visit_bytes<__E>(
                         self,
                         __value: &[u8],
-                    ) -> _serde::__private228::Result<Self::Value, __E>
+                    ) -> _serde::__private229::Result<Self::Value, __E>
                     where
                         __E: _serde::de::Error,
                     {
                         match __value {
-                            b"user_id" => _serde::__private228::Ok(__Field::__field0),
-                            b"team_id" => _serde::__private228::Ok(__Field::__field1),
-                            _ => _serde::__private228::Ok(__Field::__ignore),
+                            b"user_id" => _serde::__private229::Ok(__Field::__field0),
+                            b"team_id" => _serde::__private229::Ok(__Field::__field1),
+                            _ => _serde::__private229::Ok(__Field::__ignore),
                         }
                     }
                 }
@@ -1326,7 +1647,7 @@ pub mod api_root {
                     #[inline]
                     fn deserialize<__D>(
                         __deserializer: __D,
-                    ) -> _serde::__private228::Result<Self, __D::Error>
+                    ) -> _serde::__private229::Result<Self, __D::Error>
                     where
                         __D: _serde::Deserializer<'de>,
                     {
@@ -1338,17 +1659,17 @@ pub mod api_root {
                 }
                 #[doc(hidden)]
                 struct __Visitor<'de> {
-                    marker: _serde::__private228::PhantomData<RqConsPathStruct>,
-                    lifetime: _serde::__private228::PhantomData<&'de ()>,
+                    marker: _serde::__private229::PhantomData<RqConsPathStruct>,
+                    lifetime: _serde::__private229::PhantomData<&'de ()>,
                 }
                 #[automatically_derived]
                 impl<'de> _serde::de::Visitor<'de> for __Visitor<'de> {
                     type Value = RqConsPathStruct;
                     fn expecting(
                         &self,
-                        __formatter: &mut _serde::__private228::Formatter,
-                    ) -> _serde::__private228::fmt::Result {
-                        _serde::__private228::Formatter::write_str(
+                        __formatter: &mut _serde::__private229::Formatter,
+                    ) -> _serde::__private229::fmt::Result {
+                        _serde::__private229::Formatter::write_str(
                             __formatter,
                             "struct RqConsPathStruct",
                         )
@@ -1357,16 +1678,16 @@ pub mod api_root {
                     fn visit_seq<__A>(
                         self,
                         mut __seq: __A,
-                    ) -> _serde::__private228::Result<Self::Value, __A::Error>
+                    ) -> _serde::__private229::Result<Self::Value, __A::Error>
                     where
                         __A: _serde::de::SeqAccess<'de>,
                     {
                         let __field0 = match _serde::de::SeqAccess::next_element::<
                             String,
                         >(&mut __seq)? {
-                            _serde::__private228::Some(__value) => __value,
-                            _serde::__private228::None => {
-                                return _serde::__private228::Err(
+                            _serde::__private229::Some(__value) => __value,
+                            _serde::__private229::None => {
+                                return _serde::__private229::Err(
                                     _serde::de::Error::invalid_length(
                                         0usize,
                                         &"struct RqConsPathStruct with 2 elements",
@@ -1377,9 +1698,9 @@ pub mod api_root {
                         let __field1 = match _serde::de::SeqAccess::next_element::<
                             i32,
                         >(&mut __seq)? {
-                            _serde::__private228::Some(__value) => __value,
-                            _serde::__private228::None => {
-                                return _serde::__private228::Err(
+                            _serde::__private229::Some(__value) => __value,
+                            _serde::__private229::None => {
+                                return _serde::__private229::Err(
                                     _serde::de::Error::invalid_length(
                                         1usize,
                                         &"struct RqConsPathStruct with 2 elements",
@@ -1387,7 +1708,7 @@ pub mod api_root {
                                 );
                             }
                         };
-                        _serde::__private228::Ok(RqConsPathStruct {
+                        _serde::__private229::Ok(RqConsPathStruct {
                             user_id: __field0,
                             team_id: __field1,
                         })
@@ -1396,37 +1717,37 @@ pub mod api_root {
                     fn visit_map<__A>(
                         self,
                         mut __map: __A,
-                    ) -> _serde::__private228::Result<Self::Value, __A::Error>
+                    ) -> _serde::__private229::Result<Self::Value, __A::Error>
                     where
                         __A: _serde::de::MapAccess<'de>,
                     {
-                        let mut __field0: _serde::__private228::Option<String> = _serde::__private228::None;
-                        let mut __field1: _serde::__private228::Option<i32> = _serde::__private228::None;
-                        while let _serde::__private228::Some(__key) = _serde::de::MapAccess::next_key::<
+                        let mut __field0: _serde::__private229::Option<String> = _serde::__private229::None;
+                        let mut __field1: _serde::__private229::Option<i32> = _serde::__private229::None;
+                        while let _serde::__private229::Some(__key) = _serde::de::MapAccess::next_key::<
                             __Field,
                         >(&mut __map)? {
                             match __key {
                                 __Field::__field0 => {
-                                    if _serde::__private228::Option::is_some(&__field0) {
-                                        return _serde::__private228::Err(
+                                    if _serde::__private229::Option::is_some(&__field0) {
+                                        return _serde::__private229::Err(
                                             <__A::Error as _serde::de::Error>::duplicate_field(
                                                 "user_id",
                                             ),
                                         );
                                     }
-                                    __field0 = _serde::__private228::Some(
+                                    __field0 = _serde::__private229::Some(
                                         _serde::de::MapAccess::next_value::<String>(&mut __map)?,
                                     );
                                 }
                                 __Field::__field1 => {
-                                    if _serde::__private228::Option::is_some(&__field1) {
-                                        return _serde::__private228::Err(
+                                    if _serde::__private229::Option::is_some(&__field1) {
+                                        return _serde::__private229::Err(
                                             <__A::Error as _serde::de::Error>::duplicate_field(
                                                 "team_id",
                                             ),
                                         );
                                     }
-                                    __field1 = _serde::__private228::Some(
+                                    __field1 = _serde::__private229::Some(
                                         _serde::de::MapAccess::next_value::<i32>(&mut __map)?,
                                     );
                                 }
@@ -1438,18 +1759,18 @@ pub mod api_root {
                             }
                         }
                         let __field0 = match __field0 {
-                            _serde::__private228::Some(__field0) => __field0,
-                            _serde::__private228::None => {
-                                _serde::__private228::de::missing_field("user_id")?
+                            _serde::__private229::Some(__field0) => __field0,
+                            _serde::__private229::None => {
+                                _serde::__private229::de::missing_field("user_id")?
                             }
                         };
                         let __field1 = match __field1 {
-                            _serde::__private228::Some(__field1) => __field1,
-                            _serde::__private228::None => {
-                                _serde::__private228::de::missing_field("team_id")?
+                            _serde::__private229::Some(__field1) => __field1,
+                            _serde::__private229::None => {
+                                _serde::__private229::de::missing_field("team_id")?
                             }
                         };
-                        _serde::__private228::Ok(RqConsPathStruct {
+                        _serde::__private229::Ok(RqConsPathStruct {
                             user_id: __field0,
                             team_id: __field1,
                         })
@@ -1462,8 +1783,8 @@ pub mod api_root {
                     "RqConsPathStruct",
                     FIELDS,
                     __Visitor {
-                        marker: _serde::__private228::PhantomData::<RqConsPathStruct>,
-                        lifetime: _serde::__private228::PhantomData,
+                        marker: _serde::__private229::PhantomData::<RqConsPathStruct>,
+                        lifetime: _serde::__private229::PhantomData,
                     },
                 )
             }
@@ -1474,10 +1795,50 @@ pub mod api_root {
     pub enum RqConsPathResponse {
         Ok(String),
     }
+    impl utoipa::__dev::ComposeSchema for RqConsPathResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(1usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RqConsPathResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RqConsPathResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsPathResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsPathResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl RqConsPathResponse {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -1574,6 +1935,7 @@ pub mod api_root {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -1650,7 +2012,7 @@ pub mod api_root {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -1660,8 +2022,8 @@ pub mod api_root {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RqConsPathResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RqConsPathResponse,
                         ),
                     )
                 }
@@ -1726,6 +2088,40 @@ pub mod api_root {
     pub struct StructJson {
         success: bool,
     }
+    impl utoipa::__dev::ComposeSchema for StructJson {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "success",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Boolean,
+                                ),
+                            ),
+                    )
+                    .required("success");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for StructJson {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("StructJson")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -1741,7 +2137,7 @@ pub mod api_root {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -1764,10 +2160,69 @@ pub mod api_root {
     pub enum RespJsonResponse {
         Ok(StructJson),
     }
+    impl utoipa::__dev::ComposeSchema for RespJsonResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(1usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!(
+                                                "{0}", < StructJson as utoipa::ToSchema >::name(),
+                                            ),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespJsonResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespJsonResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!(
+                                        "{0}", < StructJson as utoipa::ToSchema >::name(),
+                                    ),
+                                )
+                            }),
+                        ),
+                        <StructJson as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <StructJson as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: &[::mime::Mime] = &[
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::APPLICATION_JSON],
+        ),
+    ));
     impl RespJsonResponse {
         fn into_response_application_json(self) -> ::axum::response::Response {
             match self {
@@ -1864,6 +2319,7 @@ pub mod api_root {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
@@ -1936,7 +2392,7 @@ pub mod api_root {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -1946,8 +2402,8 @@ pub mod api_root {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse,
                         ),
                     )
                 }
@@ -2082,16 +2538,20 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Summary"))
-                        .description(Some("Description"))
-                        .operation_id(Some("getRoot"));
+                        .description(Some("Description"));
                     op_builder = <GetRootResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("getRoot".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2102,16 +2562,20 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("postRoot"));
+                        .description(None as Option<String>);
                     op_builder = <GetRootResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Post,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("postRoot".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2122,8 +2586,7 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Query<struct>"))
-                        .description(None as Option<String>)
-                        .operation_id(Some("rqConsQueryStruct"));
+                        .description(None as Option<String>);
                     op_builder = <Query<
                         RqConsQueryStruct,
                     >>::__openapi_modify_operation(op_builder, &mut components);
@@ -2131,10 +2594,17 @@ pub mod api_root {
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some(
+                                    "rqConsQueryStruct".to_owned(),
+                                );
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2145,8 +2615,7 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Path<struct>"))
-                        .description(None as Option<String>)
-                        .operation_id(Some("rqConsPathStruct"));
+                        .description(None as Option<String>);
                     op_builder = <Path<
                         RqConsPathStruct,
                     >>::__openapi_modify_operation(op_builder, &mut components);
@@ -2154,10 +2623,17 @@ pub mod api_root {
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some(
+                                    "rqConsPathStruct".to_owned(),
+                                );
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2168,25 +2644,55 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("respJson"));
+                        .description(None as Option<String>);
                     op_builder = <RespJsonResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("respJson".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
             ));
+        let __groom_path_parameters = ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::groom::router::PathParameters::new(
+                        "/greet",
+                        "rq_cons_query_struct",
+                        [
+                            <Query<
+                                RqConsQueryStruct,
+                            > as ::groom::extract::GroomExtractor>::__groom_path_parameters(),
+                        ],
+                    ),
+                    ::groom::router::PathParameters::new(
+                        "/team/:team_id/user/:user_id",
+                        "rq_cons_path_struct",
+                        [
+                            <Path<
+                                RqConsPathStruct,
+                            > as ::groom::extract::GroomExtractor>::__groom_path_parameters(),
+                        ],
+                    ),
+                ],
+            ),
+        );
         ::groom::router::GroomRouter::from_controller_parts(
-            this_router,
-            components,
-            __groom_paths,
-        )
+                this_router,
+                components,
+                __groom_paths,
+            )
+            .with_path_parameters(__groom_path_parameters)
     }
     pub fn merge_into_router(
         other: impl Into<::groom::router::GroomRouter<()>>,
@@ -2214,16 +2720,20 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Summary"))
-                        .description(Some("Description"))
-                        .operation_id(Some("getRoot"));
+                        .description(Some("Description"));
                     op_builder = <GetRootResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("getRoot".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2234,16 +2744,20 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("postRoot"));
+                        .description(None as Option<String>);
                     op_builder = <GetRootResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Post,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("postRoot".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2254,8 +2768,7 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Query<struct>"))
-                        .description(None as Option<String>)
-                        .operation_id(Some("rqConsQueryStruct"));
+                        .description(None as Option<String>);
                     op_builder = <Query<
                         RqConsQueryStruct,
                     >>::__openapi_modify_operation(op_builder, &mut components);
@@ -2263,10 +2776,17 @@ pub mod api_root {
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some(
+                                    "rqConsQueryStruct".to_owned(),
+                                );
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2277,8 +2797,7 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(Some("Path<struct>"))
-                        .description(None as Option<String>)
-                        .operation_id(Some("rqConsPathStruct"));
+                        .description(None as Option<String>);
                     op_builder = <Path<
                         RqConsPathStruct,
                     >>::__openapi_modify_operation(op_builder, &mut components);
@@ -2286,10 +2805,17 @@ pub mod api_root {
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some(
+                                    "rqConsPathStruct".to_owned(),
+                                );
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2300,25 +2826,55 @@ pub mod api_root {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("respJson"));
+                        .description(None as Option<String>);
                     op_builder = <RespJsonResponse>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("respJson".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
             ));
-        let __groom_this = ::groom::router::GroomRouter::from_controller_parts(
-            this_router,
-            components,
-            __groom_paths,
+        let __groom_path_parameters = ::alloc::boxed::box_assume_init_into_vec_unsafe(
+            ::alloc::intrinsics::write_box_via_move(
+                ::alloc::boxed::Box::new_uninit(),
+                [
+                    ::groom::router::PathParameters::new(
+                        "/greet",
+                        "rq_cons_query_struct",
+                        [
+                            <Query<
+                                RqConsQueryStruct,
+                            > as ::groom::extract::GroomExtractor>::__groom_path_parameters(),
+                        ],
+                    ),
+                    ::groom::router::PathParameters::new(
+                        "/team/:team_id/user/:user_id",
+                        "rq_cons_path_struct",
+                        [
+                            <Path<
+                                RqConsPathStruct,
+                            > as ::groom::extract::GroomExtractor>::__groom_path_parameters(),
+                        ],
+                    ),
+                ],
+            ),
         );
+        let __groom_this = ::groom::router::GroomRouter::from_controller_parts(
+                this_router,
+                components,
+                __groom_paths,
+            )
+            .with_path_parameters(__groom_path_parameters);
         let __groom_other = other.into();
         __groom_other.merge(__groom_this)
     }
@@ -2356,8 +2912,36 @@ mod options_connect {
     pub enum OptionsResult {
         Ok,
     }
+    impl utoipa::__dev::ComposeSchema for OptionsResult {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::Object::builder()
+                .schema_type(
+                    utoipa::openapi::schema::SchemaType::new(
+                        utoipa::openapi::schema::Type::String,
+                    ),
+                )
+                .enum_values::<[&str; 1usize], &str>(Some(["Ok"]))
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for OptionsResult {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("OptionsResult")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_OptionsResult: &[::mime::Mime] = &[];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_OptionsResult: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::vec::Vec::new());
     impl OptionsResult {
         fn into_response_any_content_type(self) -> ::axum::response::Response {
             match self {
@@ -2443,6 +3027,7 @@ mod options_connect {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             self.into_response_any_content_type()
         }
@@ -2461,7 +3046,7 @@ mod options_connect {
             _accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             Ok(None)
         }
@@ -2485,6 +3070,7 @@ mod options_connect {
     }
     async fn __groom_wrapper_options_route(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -2494,13 +3080,31 @@ mod options_connect {
             None => None,
             Some(accept) => {
                 match <OptionsResult>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = options_route().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     /// HTTP handler: CONNECT /tunnel
     pub async fn connect_route() -> OptionsResult {
@@ -2508,6 +3112,7 @@ mod options_connect {
     }
     async fn __groom_wrapper_connect_route(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -2517,13 +3122,31 @@ mod options_connect {
             None => None,
             Some(accept) => {
                 match <OptionsResult>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = connect_route().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     fn __groom_runtime_checks() {
         let context = "Groom runtime check of mod `options_connect`";
@@ -2566,16 +3189,20 @@ mod options_connect {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("optionsRoute"));
+                        .description(None as Option<String>);
                     op_builder = <OptionsResult>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Options,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("optionsRoute".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2609,16 +3236,20 @@ mod options_connect {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("optionsRoute"));
+                        .description(None as Option<String>);
                     op_builder = <OptionsResult>::__openapi_modify_operation(
                         op_builder,
                         &mut components,
                     );
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Options,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("optionsRoute".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -2,13 +2,43 @@
 //! Each case is put into its own `mod` to make it easier to inspect expansion result.
 #[macro_use]
 extern crate groom_macros;
+#[macro_use]
+extern crate static_assertions;
 mod no_content_type {
     pub enum RespJsonResponse {
         Accepted,
         NotFound,
     }
+    impl utoipa::__dev::ComposeSchema for RespJsonResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::schema::Object::builder()
+                .schema_type(
+                    utoipa::openapi::schema::SchemaType::new(
+                        utoipa::openapi::schema::Type::String,
+                    ),
+                )
+                .enum_values::<[&str; 2usize], &str>(Some(["Accepted", "NotFound"]))
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespJsonResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespJsonResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: &[::mime::Mime] = &[];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::vec::Vec::new());
     impl RespJsonResponse {
         fn into_response_any_content_type(self) -> ::axum::response::Response {
             match self {
@@ -169,6 +199,7 @@ mod no_content_type {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             self.into_response_any_content_type()
         }
@@ -192,7 +223,7 @@ mod no_content_type {
             _accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             Ok(None)
         }
@@ -225,10 +256,59 @@ mod plaintext_only {
         Ok(String),
         NotFound,
     }
+    impl utoipa::__dev::ComposeSchema for RespPlaintextResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["NotFound"])),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespPlaintextResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespPlaintextResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespPlaintextResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespPlaintextResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl RespPlaintextResponse {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -400,6 +480,7 @@ mod plaintext_only {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -481,7 +562,7 @@ mod plaintext_only {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -491,8 +572,8 @@ mod plaintext_only {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RespPlaintextResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RespPlaintextResponse,
                         ),
                     )
                 }
@@ -521,6 +602,47 @@ mod plaintext_only {
                 );
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<String, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<String, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
 }
 mod html_only {
     use groom::response::html_format;
@@ -528,6 +650,40 @@ mod html_only {
     pub struct Struct {
         success: bool,
     }
+    impl utoipa::__dev::ComposeSchema for Struct {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "success",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Boolean,
+                                ),
+                            ),
+                    )
+                    .required("success");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Struct {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Struct")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -543,7 +699,7 @@ mod html_only {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -579,12 +735,79 @@ mod html_only {
         Ok(Struct),
         NotFound,
     }
+    impl utoipa::__dev::ComposeSchema for RespHtmlResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!("{0}", < Struct as utoipa::ToSchema >::name()),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["NotFound"])),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespHtmlResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespHtmlResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!("{0}", < Struct as utoipa::ToSchema >::name()),
+                                )
+                            }),
+                        ),
+                        <Struct as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <Struct as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespHtmlResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_HTML_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespHtmlResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_HTML_UTF_8],
+        ),
+    ));
     impl RespHtmlResponse {
-        fn into_response_text_html(self) -> ::axum::response::Response {
+        fn into_response_text_html(
+            self,
+            ctx: &::groom::response::RenderContext,
+        ) -> ::axum::response::Response {
             match self {
                 Self::Ok(body) => {
                     (
@@ -668,7 +891,7 @@ mod html_only {
                                 );
                             }
                         },
-                        <Struct as ::groom::response::HtmlFormat>::render(body),
+                        <Struct as ::groom::response::HtmlFormat>::render_with(body, ctx),
                     )
                         .into_response()
                 }
@@ -754,12 +977,13 @@ mod html_only {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
-                None => self.into_response_text_html(),
+                None => self.into_response_text_html(ctx),
                 Some(negotiated) => {
                     match (negotiated.type_(), negotiated.subtype()) {
-                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(),
+                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(ctx),
                         _ => {
                             if true {
                                 if !false {
@@ -835,7 +1059,7 @@ mod html_only {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -845,8 +1069,8 @@ mod html_only {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RespHtmlResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RespHtmlResponse,
                         ),
                     )
                 }
@@ -871,11 +1095,86 @@ mod html_only {
             formats.record(context, &__GROOM_RESPONSE_SUPPORTED_MIMES_RespHtmlResponse);
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Struct, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Struct, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
 }
 mod json_only {
     pub struct StructJson {
         success: bool,
     }
+    impl utoipa::__dev::ComposeSchema for StructJson {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "success",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Boolean,
+                                ),
+                            ),
+                    )
+                    .required("success");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for StructJson {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("StructJson")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -891,7 +1190,7 @@ mod json_only {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -915,10 +1214,78 @@ mod json_only {
         Ok(StructJson),
         NotFound,
     }
+    impl utoipa::__dev::ComposeSchema for RespJsonResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!(
+                                                "{0}", < StructJson as utoipa::ToSchema >::name(),
+                                            ),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["NotFound"])),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespJsonResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespJsonResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!(
+                                        "{0}", < StructJson as utoipa::ToSchema >::name(),
+                                    ),
+                                )
+                            }),
+                        ),
+                        <StructJson as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <StructJson as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: &[::mime::Mime] = &[
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::APPLICATION_JSON],
+        ),
+    ));
     impl RespJsonResponse {
         fn into_response_application_json(self) -> ::axum::response::Response {
             match self {
@@ -1090,6 +1457,7 @@ mod json_only {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
@@ -1167,7 +1535,7 @@ mod json_only {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -1177,8 +1545,8 @@ mod json_only {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse,
                         ),
                     )
                 }
@@ -1203,6 +1571,47 @@ mod json_only {
             formats.record(context, &__GROOM_RESPONSE_SUPPORTED_MIMES_RespJsonResponse);
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<StructJson, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<StructJson, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
 }
 mod multiple_content_types {
     use groom::response::html_format;
@@ -1210,6 +1619,40 @@ mod multiple_content_types {
     pub struct Struct {
         success: bool,
     }
+    impl utoipa::__dev::ComposeSchema for Struct {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "success",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Boolean,
+                                ),
+                            ),
+                    )
+                    .required("success");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Struct {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Struct")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -1225,7 +1668,7 @@ mod multiple_content_types {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -1261,12 +1704,74 @@ mod multiple_content_types {
         Ok(Struct),
         NotFound,
     }
+    impl utoipa::__dev::ComposeSchema for RespMultipleTypesResponse {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Ok",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!("{0}", < Struct as utoipa::ToSchema >::name()),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("Ok"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["NotFound"])),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for RespMultipleTypesResponse {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("RespMultipleTypesResponse")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!("{0}", < Struct as utoipa::ToSchema >::name()),
+                                )
+                            }),
+                        ),
+                        <Struct as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <Struct as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_RespMultipleTypesResponse: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-        ::mime::TEXT_HTML_UTF_8,
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_RespMultipleTypesResponse: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8, ::mime::TEXT_HTML_UTF_8, ::mime::APPLICATION_JSON],
+        ),
+    ));
     impl RespMultipleTypesResponse {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -1433,7 +1938,10 @@ mod multiple_content_types {
                 }
             }
         }
-        fn into_response_text_html(self) -> ::axum::response::Response {
+        fn into_response_text_html(
+            self,
+            ctx: &::groom::response::RenderContext,
+        ) -> ::axum::response::Response {
             match self {
                 Self::Ok(body) => {
                     (
@@ -1517,7 +2025,7 @@ mod multiple_content_types {
                                 );
                             }
                         },
-                        <Struct as ::groom::response::HtmlFormat>::render(body),
+                        <Struct as ::groom::response::HtmlFormat>::render_with(body, ctx),
                     )
                         .into_response()
                 }
@@ -1768,13 +2276,14 @@ mod multiple_content_types {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
                 Some(negotiated) => {
                     match (negotiated.type_(), negotiated.subtype()) {
                         (::mime::TEXT, ::mime::PLAIN) => self.into_response_text_plain(),
-                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(),
+                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(ctx),
                         (::mime::APPLICATION, ::mime::JSON) => {
                             self.into_response_application_json()
                         }
@@ -1871,7 +2380,7 @@ mod multiple_content_types {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -1881,8 +2390,8 @@ mod multiple_content_types {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_RespMultipleTypesResponse,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_RespMultipleTypesResponse,
                         ),
                     )
                 }
@@ -1913,6 +2422,47 @@ mod multiple_content_types {
                 );
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Struct, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Struct, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
 }
 mod named_struct_response {
     use groom::response::html_format;
@@ -1920,6 +2470,40 @@ mod named_struct_response {
     pub struct Named {
         success: bool,
     }
+    impl utoipa::__dev::ComposeSchema for Named {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "success",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::Boolean,
+                                ),
+                            ),
+                    )
+                    .required("success");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Named {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Named")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -1935,7 +2519,7 @@ mod named_struct_response {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -1956,12 +2540,19 @@ mod named_struct_response {
     impl ::groom::DTO for Named {}
     impl ::groom::DTO_Response for Named {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Named: &[::mime::Mime] = &[
-        ::mime::TEXT_HTML_UTF_8,
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Named: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_HTML_UTF_8, ::mime::APPLICATION_JSON],
+        ),
+    ));
     impl Named {
-        fn into_response_text_html(self) -> ::axum::response::Response {
+        fn into_response_text_html(
+            self,
+            ctx: &::groom::response::RenderContext,
+        ) -> ::axum::response::Response {
             (
                 match 200u16 {
                     200u16 => ::axum::http::StatusCode::OK,
@@ -2031,7 +2622,7 @@ mod named_struct_response {
                         );
                     }
                 },
-                <Named as ::groom::response::HtmlFormat>::render(self),
+                <Named as ::groom::response::HtmlFormat>::render_with(self, ctx),
             )
                 .into_response()
         }
@@ -2114,12 +2705,13 @@ mod named_struct_response {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
                 Some(negotiated) => {
                     match (negotiated.type_(), negotiated.subtype()) {
-                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(),
+                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(ctx),
                         (::mime::APPLICATION, ::mime::JSON) => {
                             self.into_response_application_json()
                         }
@@ -2199,7 +2791,7 @@ mod named_struct_response {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -2209,8 +2801,8 @@ mod named_struct_response {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Named,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Named,
                         ),
                     )
                 }
@@ -2229,6 +2821,47 @@ mod named_struct_response {
             formats.record(context, &__GROOM_RESPONSE_SUPPORTED_MIMES_Named);
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Named, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<Named, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
     impl ::groom::response::HtmlFormat for Named {
         fn render(self) -> ::axum::response::Html<axum::body::Body> {
             ::axum::response::Html(
@@ -2246,6 +2879,31 @@ mod unnamed_struct_response {
     use groom::response::html_format;
     use groom::response::HtmlFormat;
     pub struct Unnamed(String);
+    impl utoipa::__dev::ComposeSchema for Unnamed {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::ObjectBuilder::new()
+                .schema_type(
+                    utoipa::openapi::schema::SchemaType::new(
+                        utoipa::openapi::schema::Type::String,
+                    ),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Unnamed {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Unnamed")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -2261,7 +2919,7 @@ mod unnamed_struct_response {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -2276,12 +2934,19 @@ mod unnamed_struct_response {
     impl ::groom::DTO for Unnamed {}
     impl ::groom::DTO_Response for Unnamed {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Unnamed: &[::mime::Mime] = &[
-        ::mime::TEXT_HTML_UTF_8,
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Unnamed: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_HTML_UTF_8, ::mime::APPLICATION_JSON],
+        ),
+    ));
     impl Unnamed {
-        fn into_response_text_html(self) -> ::axum::response::Response {
+        fn into_response_text_html(
+            self,
+            ctx: &::groom::response::RenderContext,
+        ) -> ::axum::response::Response {
             (
                 match 200u16 {
                     200u16 => ::axum::http::StatusCode::OK,
@@ -2351,7 +3016,7 @@ mod unnamed_struct_response {
                         );
                     }
                 },
-                <Unnamed as ::groom::response::HtmlFormat>::render(self),
+                <Unnamed as ::groom::response::HtmlFormat>::render_with(self, ctx),
             )
                 .into_response()
         }
@@ -2434,12 +3099,13 @@ mod unnamed_struct_response {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
                 Some(negotiated) => {
                     match (negotiated.type_(), negotiated.subtype()) {
-                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(),
+                        (::mime::TEXT, ::mime::HTML) => self.into_response_text_html(ctx),
                         (::mime::APPLICATION, ::mime::JSON) => {
                             self.into_response_application_json()
                         }
@@ -2519,7 +3185,7 @@ mod unnamed_struct_response {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -2529,8 +3195,8 @@ mod unnamed_struct_response {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Unnamed,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Unnamed,
                         ),
                     )
                 }
@@ -2550,6 +3216,47 @@ mod unnamed_struct_response {
             formats.record(context, &__GROOM_RESPONSE_SUPPORTED_MIMES_Unnamed);
         }
     }
+    const _: fn() = || {
+        use ::static_assertions::_core::marker::PhantomData;
+        use ::static_assertions::_core::ops::Deref;
+        let previous = AssertImplAnyFallback;
+        struct AssertImplAnyFallback;
+        struct ActualAssertImplAnyToken;
+        trait AssertImplAnyToken {}
+        impl AssertImplAnyToken for ActualAssertImplAnyToken {}
+        fn assert_impl_any_token<T: AssertImplAnyToken>(_: T) {}
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::utoipa::PartialSchema, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<String, _>(PhantomData, previous)
+        };
+        let previous = {
+            struct Wrapper<T, N>(PhantomData<T>, N);
+            impl<T, N> Deref for Wrapper<T, N> {
+                type Target = N;
+                fn deref(&self) -> &Self::Target {
+                    &self.1
+                }
+            }
+            impl<T: ::groom::DTO_Response, N> Wrapper<T, N> {
+                fn _static_assertions_impl_any(&self) -> ActualAssertImplAnyToken {
+                    ActualAssertImplAnyToken
+                }
+            }
+            Wrapper::<String, _>(PhantomData, previous)
+        };
+        assert_impl_any_token(previous._static_assertions_impl_any());
+    };
     impl ::groom::response::HtmlFormat for Unnamed {
         fn render(self) -> ::axum::response::Html<axum::body::Body> {
             ::axum::response::Html(
@@ -2567,6 +3274,28 @@ mod unnamed_struct_response {
 }
 mod unit_struct_response {
     pub struct Unit;
+    impl utoipa::__dev::ComposeSchema for Unit {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::Object::builder()
+                .schema_type(utoipa::openapi::schema::SchemaType::AnyValue)
+                .default(Some(utoipa::gen::serde_json::Value::Null))
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Unit {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Unit")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -2582,7 +3311,7 @@ mod unit_struct_response {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -2593,7 +3322,9 @@ mod unit_struct_response {
     impl ::groom::DTO for Unit {}
     impl ::groom::DTO_Response for Unit {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Unit: &[::mime::Mime] = &[];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Unit: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::vec::Vec::new());
     impl Unit {
         fn into_response_any_content_type(self) -> ::axum::response::Response {
             (match 200u16 {
@@ -2671,6 +3402,7 @@ mod unit_struct_response {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             self.into_response_any_content_type()
         }
@@ -2689,7 +3421,7 @@ mod unit_struct_response {
             _accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             Ok(None)
         }
@@ -2710,6 +3442,28 @@ mod unit_struct_response {
 mod result_struct_struct {
     use ::static_assertions::{assert_impl_all, assert_impl_any};
     pub struct Success;
+    impl utoipa::__dev::ComposeSchema for Success {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::Object::builder()
+                .schema_type(utoipa::openapi::schema::SchemaType::AnyValue)
+                .default(Some(utoipa::gen::serde_json::Value::Null))
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Success {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Success")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -2725,7 +3479,7 @@ mod result_struct_struct {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -2736,7 +3490,9 @@ mod result_struct_struct {
     impl ::groom::DTO for Success {}
     impl ::groom::DTO_Response for Success {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Success: &[::mime::Mime] = &[];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Success: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::vec::Vec::new());
     impl Success {
         fn into_response_any_content_type(self) -> ::axum::response::Response {
             (match 200u16 {
@@ -2814,6 +3570,7 @@ mod result_struct_struct {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             self.into_response_any_content_type()
         }
@@ -2832,7 +3589,7 @@ mod result_struct_struct {
             _accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             Ok(None)
         }
@@ -2851,6 +3608,28 @@ mod result_struct_struct {
         }
     }
     pub struct Error;
+    impl utoipa::__dev::ComposeSchema for Error {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::Object::builder()
+                .schema_type(utoipa::openapi::schema::SchemaType::AnyValue)
+                .default(Some(utoipa::gen::serde_json::Value::Null))
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Error {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Error")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -2866,7 +3645,7 @@ mod result_struct_struct {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -2877,7 +3656,9 @@ mod result_struct_struct {
     impl ::groom::DTO for Error {}
     impl ::groom::DTO_Response for Error {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Error: &[::mime::Mime] = &[];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Error: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::vec::Vec::new());
     impl Error {
         fn into_response_any_content_type(self) -> ::axum::response::Response {
             (match 404u16 {
@@ -2955,6 +3736,7 @@ mod result_struct_struct {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             self.into_response_any_content_type()
         }
@@ -2973,7 +3755,7 @@ mod result_struct_struct {
             _accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             Ok(None)
         }
@@ -2996,6 +3778,7 @@ mod result_struct_struct {
     }
     async fn __groom_wrapper_foo(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -3005,13 +3788,31 @@ mod result_struct_struct {
             None => None,
             Some(accept) => {
                 match <Result<Success, Error>>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = foo().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     fn __groom_runtime_checks() {
         let context = "Groom runtime check of mod `result_struct_struct`";
@@ -3046,16 +3847,20 @@ mod result_struct_struct {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Success,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -3085,16 +3890,20 @@ mod result_struct_struct {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Success,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -3115,6 +3924,31 @@ mod result_struct_struct {
 mod result_struct_enum {
     use ::static_assertions::{assert_impl_all, assert_impl_any};
     pub struct Success(String);
+    impl utoipa::__dev::ComposeSchema for Success {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            utoipa::openapi::ObjectBuilder::new()
+                .schema_type(
+                    utoipa::openapi::schema::SchemaType::new(
+                        utoipa::openapi::schema::Type::String,
+                    ),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Success {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Success")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -3130,7 +3964,7 @@ mod result_struct_enum {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -3145,9 +3979,14 @@ mod result_struct_enum {
     impl ::groom::DTO for Success {}
     impl ::groom::DTO_Response for Success {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Success: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Success: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl Success {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             (
@@ -3228,6 +4067,7 @@ mod result_struct_enum {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -3304,7 +4144,7 @@ mod result_struct_enum {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -3314,8 +4154,8 @@ mod result_struct_enum {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Success,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Success,
                         ),
                     )
                 }
@@ -3380,10 +4220,59 @@ mod result_struct_enum {
         NotFound,
         NoAccess(String),
     }
+    impl utoipa::__dev::ComposeSchema for Error {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["NotFound"])),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "NoAccess",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("NoAccess"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Error {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Error")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Error: &[::mime::Mime] = &[
-        ::mime::TEXT_PLAIN_UTF_8,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Error: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::TEXT_PLAIN_UTF_8],
+        ),
+    ));
     impl Error {
         fn into_response_text_plain(self) -> ::axum::response::Response {
             match self {
@@ -3555,6 +4444,7 @@ mod result_struct_enum {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_text_plain(),
@@ -3636,7 +4526,7 @@ mod result_struct_enum {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -3646,8 +4536,8 @@ mod result_struct_enum {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Error,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Error,
                         ),
                     )
                 }
@@ -3723,6 +4613,7 @@ mod result_struct_enum {
     }
     async fn __groom_wrapper_foo(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -3732,13 +4623,31 @@ mod result_struct_enum {
             None => None,
             Some(accept) => {
                 match <Result<Success, Error>>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = foo().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     fn __groom_runtime_checks() {
         let context = "Groom runtime check of mod `result_struct_enum`";
@@ -3773,16 +4682,20 @@ mod result_struct_enum {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Success,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -3812,16 +4725,20 @@ mod result_struct_enum {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Success,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -3846,6 +4763,72 @@ mod wrapped_enum {
         UnnamedStructVariant(String),
         NamedStructVariant { value: String },
     }
+    impl utoipa::__dev::ComposeSchema for EnumValueObject {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(3usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .schema_type(
+                            utoipa::openapi::schema::SchemaType::new(
+                                utoipa::openapi::schema::Type::String,
+                            ),
+                        )
+                        .enum_values::<[&str; 1usize], &str>(Some(["UnitVariant"])),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "UnnamedStructVariant",
+                            utoipa::openapi::ObjectBuilder::new()
+                                .schema_type(
+                                    utoipa::openapi::schema::SchemaType::new(
+                                        utoipa::openapi::schema::Type::String,
+                                    ),
+                                ),
+                        )
+                        .required("UnnamedStructVariant"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "NamedStructVariant",
+                            {
+                                let mut object = utoipa::openapi::ObjectBuilder::new();
+                                object = object
+                                    .property(
+                                        "value",
+                                        utoipa::openapi::ObjectBuilder::new()
+                                            .schema_type(
+                                                utoipa::openapi::schema::SchemaType::new(
+                                                    utoipa::openapi::schema::Type::String,
+                                                ),
+                                            ),
+                                    )
+                                    .required("value");
+                                object
+                            },
+                        )
+                        .required("NamedStructVariant"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for EnumValueObject {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("EnumValueObject")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -3861,7 +4844,7 @@ mod wrapped_enum {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -3907,6 +4890,59 @@ mod wrapped_enum {
     pub struct WrapperStruct {
         pub v: EnumValueObject,
     }
+    impl utoipa::__dev::ComposeSchema for WrapperStruct {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "v",
+                        utoipa::openapi::schema::RefBuilder::new()
+                            .ref_location_from_schema_name(
+                                ::alloc::__export::must_use({
+                                    ::alloc::fmt::format(
+                                        format_args!(
+                                            "{0}", < EnumValueObject as utoipa::ToSchema >::name(),
+                                        ),
+                                    )
+                                }),
+                            ),
+                    )
+                    .required("v");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for WrapperStruct {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("WrapperStruct")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!(
+                                        "{0}", < EnumValueObject as utoipa::ToSchema >::name(),
+                                    ),
+                                )
+                            }),
+                        ),
+                        <EnumValueObject as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <EnumValueObject as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -3922,7 +4958,7 @@ mod wrapped_enum {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -3946,10 +4982,99 @@ mod wrapped_enum {
         Enum(EnumValueObject),
         StructWithEnum(WrapperStruct),
     }
+    impl utoipa::__dev::ComposeSchema for Resp {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            Into::<
+                utoipa::openapi::schema::OneOfBuilder,
+            >::into(utoipa::openapi::OneOf::with_capacity(2usize))
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "Enum",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!(
+                                                "{0}", < EnumValueObject as utoipa::ToSchema >::name(),
+                                            ),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("Enum"),
+                )
+                .item(
+                    utoipa::openapi::schema::Object::builder()
+                        .property(
+                            "StructWithEnum",
+                            utoipa::openapi::schema::RefBuilder::new()
+                                .ref_location_from_schema_name(
+                                    ::alloc::__export::must_use({
+                                        ::alloc::fmt::format(
+                                            format_args!(
+                                                "{0}", < WrapperStruct as utoipa::ToSchema >::name(),
+                                            ),
+                                        )
+                                    }),
+                                ),
+                        )
+                        .required("StructWithEnum"),
+                )
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Resp {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Resp")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas
+                .extend([
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!(
+                                        "{0}", < EnumValueObject as utoipa::ToSchema >::name(),
+                                    ),
+                                )
+                            }),
+                        ),
+                        <EnumValueObject as utoipa::PartialSchema>::schema(),
+                    ),
+                    (
+                        String::from(
+                            ::alloc::__export::must_use({
+                                ::alloc::fmt::format(
+                                    format_args!(
+                                        "{0}", < WrapperStruct as utoipa::ToSchema >::name(),
+                                    ),
+                                )
+                            }),
+                        ),
+                        <WrapperStruct as utoipa::PartialSchema>::schema(),
+                    ),
+                ]);
+            <EnumValueObject as utoipa::ToSchema>::schemas(schemas);
+            <WrapperStruct as utoipa::ToSchema>::schemas(schemas);
+        }
+    }
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Resp: &[::mime::Mime] = &[
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Resp: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::APPLICATION_JSON],
+        ),
+    ));
     impl Resp {
         fn into_response_application_json(self) -> ::axum::response::Response {
             match self {
@@ -4132,6 +5257,7 @@ mod wrapped_enum {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
@@ -4219,7 +5345,7 @@ mod wrapped_enum {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -4229,8 +5355,8 @@ mod wrapped_enum {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Resp,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Resp,
                         ),
                     )
                 }
@@ -4340,6 +5466,40 @@ mod wrapped_enum {
     pub struct Error {
         pub message: &'static str,
     }
+    impl utoipa::__dev::ComposeSchema for Error {
+        fn compose(
+            mut generics: Vec<utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>>,
+        ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+            {
+                let mut object = utoipa::openapi::ObjectBuilder::new();
+                object = object
+                    .property(
+                        "message",
+                        utoipa::openapi::ObjectBuilder::new()
+                            .schema_type(
+                                utoipa::openapi::schema::SchemaType::new(
+                                    utoipa::openapi::schema::Type::String,
+                                ),
+                            ),
+                    )
+                    .required("message");
+                object
+            }
+                .into()
+        }
+    }
+    impl utoipa::ToSchema for Error {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Error")
+        }
+        fn schemas(
+            schemas: &mut Vec<
+                (String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>),
+            >,
+        ) {
+            schemas.extend([]);
+        }
+    }
     #[doc(hidden)]
     #[allow(
         non_upper_case_globals,
@@ -4355,7 +5515,7 @@ mod wrapped_enum {
             fn serialize<__S>(
                 &self,
                 __serializer: __S,
-            ) -> _serde::__private228::Result<__S::Ok, __S::Error>
+            ) -> _serde::__private229::Result<__S::Ok, __S::Error>
             where
                 __S: _serde::Serializer,
             {
@@ -4376,9 +5536,14 @@ mod wrapped_enum {
     impl ::groom::DTO for Error {}
     impl ::groom::DTO_Response for Error {}
     #[allow(non_upper_case_globals)]
-    const __GROOM_RESPONSE_SUPPORTED_MIMES_Error: &[::mime::Mime] = &[
-        ::mime::APPLICATION_JSON,
-    ];
+    static __GROOM_RESPONSE_SUPPORTED_MIMES_Error: ::std::sync::LazyLock<
+        ::std::vec::Vec<::mime::Mime>,
+    > = ::std::sync::LazyLock::new(|| ::alloc::boxed::box_assume_init_into_vec_unsafe(
+        ::alloc::intrinsics::write_box_via_move(
+            ::alloc::boxed::Box::new_uninit(),
+            [::mime::APPLICATION_JSON],
+        ),
+    ));
     impl Error {
         fn into_response_application_json(self) -> ::axum::response::Response {
             (
@@ -4459,6 +5624,7 @@ mod wrapped_enum {
         fn __groom_into_response(
             self,
             negotiated: Option<&::mime::Mime>,
+            ctx: &::groom::response::RenderContext,
         ) -> ::axum::response::Response {
             match negotiated {
                 None => self.into_response_application_json(),
@@ -4531,7 +5697,7 @@ mod wrapped_enum {
             accept: &::accept_header::Accept,
         ) -> ::core::result::Result<
             Option<&'static ::mime::Mime>,
-            ::axum::response::Response,
+            ::groom::response::NotAcceptable,
         > {
             match ::groom::content_negotiation::negotiate_parameter_insensitive(
                 accept,
@@ -4541,8 +5707,8 @@ mod wrapped_enum {
                 Some(negotiated) => Ok(Some(negotiated)),
                 None => {
                     Err(
-                        ::groom::response::not_acceptable(
-                            __GROOM_RESPONSE_SUPPORTED_MIMES_Error,
+                        ::groom::response::NotAcceptable(
+                            &__GROOM_RESPONSE_SUPPORTED_MIMES_Error,
                         ),
                     )
                 }
@@ -4614,6 +5780,7 @@ mod wrapped_enum {
     }
     async fn __groom_wrapper_foo(
         headers: ::axum::http::header::HeaderMap,
+        locale: ::core::option::Option<::groom::locale::Locale>,
     ) -> impl ::axum::response::IntoResponse {
         let accept = match ::groom::content_negotiation::parse_accept_header(&headers) {
             Err(_) => return ::groom::response::bad_accept_header(),
@@ -4623,13 +5790,31 @@ mod wrapped_enum {
             None => None,
             Some(accept) => {
                 match <Result<Resp, Error>>::__groom_negotiate_content_type(&accept) {
-                    Err(response) => return response,
+                    Err(not_acceptable) => {
+                        return ::axum::response::IntoResponse::into_response(
+                            not_acceptable,
+                        );
+                    }
                     Ok(negotiated) => negotiated,
                 }
             }
         };
         let result = foo().await;
-        result.__groom_into_response(negotiated)
+        let compression = ::groom::response::Response::__groom_compression(&result);
+        let response = result
+            .__groom_into_response(
+                negotiated,
+                &::groom::response::RenderContext::new(locale),
+            );
+        match compression {
+            None => response,
+            Some(compression) => {
+                let coding = ::groom::response::compression::negotiate_encoding(
+                    &headers,
+                );
+                compression.compress(coding, response).await
+            }
+        }
     }
     fn __groom_runtime_checks() {
         let context = "Groom runtime check of mod `wrapped_enum`";
@@ -4664,16 +5849,20 @@ mod wrapped_enum {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Resp,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
@@ -4703,16 +5892,20 @@ mod wrapped_enum {
                 {
                     let mut op_builder = ::utoipa::openapi::path::OperationBuilder::new()
                         .summary(None as Option<String>)
-                        .description(None as Option<String>)
-                        .operation_id(Some("foo"));
+                        .description(None as Option<String>);
                     op_builder = <Result<
                         Resp,
                         Error,
                     >>::__openapi_modify_operation(op_builder, &mut components);
+                    let operation = op_builder.build();
                     ::utoipa::openapi::path::PathItemBuilder::new()
                         .operation(
                             ::utoipa::openapi::path::HttpMethod::Get,
-                            op_builder.build(),
+                            {
+                                let mut operation = operation.clone();
+                                operation.operation_id = Some("foo".to_owned());
+                                operation
+                            },
                         )
                         .build()
                 },
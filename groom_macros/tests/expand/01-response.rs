@@ -3,6 +3,8 @@
 
 #[macro_use]
 extern crate groom_macros;
+#[macro_use]
+extern crate static_assertions;
 
 mod no_content_type {
     #[Response()]
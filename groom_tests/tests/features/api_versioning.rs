@@ -0,0 +1,575 @@
+use groom::router::{GroomRouter, Versioning};
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod tasks {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    /// Health of the service
+    #[Route(method = "get", path = "/health")]
+    pub async fn health() -> TextResponse {
+        TextResponse::Ok("ok".into())
+    }
+
+    /// Tasks, as a comma-separated list
+    #[Route(method = "get", path = "/tasks", until = "v2")]
+    pub async fn list_tasks() -> TextResponse {
+        TextResponse::Ok("v1 tasks".into())
+    }
+}
+
+#[Controller(since = "v2")]
+mod tasks_v2 {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[Response()]
+    pub enum DeletedResponse {
+        #[Response(code = 204)]
+        Deleted,
+    }
+
+    /// Tasks, one per line
+    #[Route(method = "get", path = "/tasks")]
+    pub async fn list_tasks() -> TextResponse {
+        TextResponse::Ok("v2 tasks".into())
+    }
+
+    /// Deletes every task
+    #[Route(method = "delete", path = "/tasks")]
+    pub async fn delete_tasks() -> DeletedResponse {
+        DeletedResponse::Deleted
+    }
+}
+
+#[Controller()]
+mod archive {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[Response()]
+    pub enum DeletedResponse {
+        #[Response(code = 204)]
+        Deleted,
+    }
+
+    /// Archived tasks
+    #[Route(method = "get", path = "/archive")]
+    pub async fn archived_tasks() -> TextResponse {
+        TextResponse::Ok("archived tasks".into())
+    }
+
+    /// Empties the archive
+    #[Route(method = "delete", path = "/archive", since = "v2")]
+    pub async fn empty_archive() -> DeletedResponse {
+        DeletedResponse::Deleted
+    }
+}
+
+#[derive(Clone)]
+pub struct Greeting {
+    text: &'static str,
+}
+
+#[Controller(state_type = Greeting, since = "v2")]
+mod greetings {
+    use super::Greeting;
+
+    use axum::{extract::State, response::IntoResponse};
+
+    use groom::{
+        response::Response,
+        extract::GroomExtractor
+    };
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    /// Greeting of the state
+    #[Route(method = "get", path = "/greeting")]
+    pub async fn greeting(greeting: State<Greeting>) -> TextResponse {
+        TextResponse::Ok(greeting.text.into())
+    }
+}
+
+fn router() -> GroomRouter {
+    tasks::into_router().merge(tasks_v2::into_router()).unwrap()
+}
+
+/// Tests that every route is served under the prefix of each version it exists in
+#[tokio::test]
+pub async fn test_url_prefix() {
+    let r = router()
+        .with_versioning(Versioning::url_prefix(&["v1", "v2"]))
+        .validate().unwrap()
+        .to_axum_router();
+
+    Req::get("/v1/tasks").call(&r).await
+        .assert_status(200)
+        .assert_body("v1 tasks")
+    ;
+
+    Req::get("/v2/tasks").call(&r).await
+        .assert_status(200)
+        .assert_body("v2 tasks")
+    ;
+
+    Req::get("/v1/health").call(&r).await.assert_status(200);
+    Req::get("/v2/health").call(&r).await.assert_status(200);
+
+    Req::delete("/v1/tasks").call(&r).await.assert_status(405);
+    Req::delete("/v2/tasks").call(&r).await.assert_status(204);
+
+    Req::get("/tasks").call(&r).await.assert_status(404);
+}
+
+/// Tests that the version header selects the routes, the last version being the default
+#[tokio::test]
+pub async fn test_header() {
+    let r = router()
+        .with_versioning(Versioning::header("Api-Version", &["v1", "v2"]))
+        .validate().unwrap()
+        .to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_header("vary", "Api-Version")
+        .assert_body("v2 tasks")
+    ;
+
+    Req::get("/tasks").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(200)
+        .assert_body("v1 tasks")
+    ;
+
+    Req::delete("/tasks").with_headers([("api-version", "v1")]).call(&r).await.assert_status(405);
+
+    Req::get("/tasks").with_headers([("api-version", "v3")]).call(&r).await
+        .assert_status(400)
+        .assert_body("Unsupported API version `v3`; supported versions: v1, v2")
+    ;
+
+    Req::get("/health").with_headers([("api-version", "v3")]).call(&r).await.assert_status(200);
+}
+
+/// Tests that the version parameter of the accepted media type selects the routes
+#[tokio::test]
+pub async fn test_media_type_parameter() {
+    let r = router()
+        .with_versioning(Versioning::media_type_parameter("version", &["v1", "v2"]).default_version("v1"))
+        .validate().unwrap()
+        .to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_header("vary", "accept")
+        .assert_body("v1 tasks")
+    ;
+
+    Req::get("/tasks").accept("text/plain; version=v2").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/plain; charset=utf-8")
+        .assert_body("v2 tasks")
+    ;
+}
+
+/// Tests that routes selected by header get their state bound with the router
+#[tokio::test]
+pub async fn test_header_with_state() {
+    let versioning = Versioning::header("Api-Version", &["v1", "v2"]);
+
+    let r: axum::Router = greetings::into_router()
+        .with_versioning(versioning)
+        .validate().unwrap()
+        .to_axum_router_with_state(Greeting { text: "hello" });
+
+    Req::get("/greeting").call(&r).await
+        .assert_status(200)
+        .assert_body("hello")
+    ;
+
+    Req::get("/greeting").with_headers([("api-version", "v1")]).call(&r).await.assert_status(404);
+
+    let r = greetings::into_router()
+        .with_state(Greeting { text: "hi" })
+        .with_versioning(versioning)
+        .validate().unwrap()
+        .to_axum_router();
+
+    Req::get("/greeting").call(&r).await
+        .assert_status(200)
+        .assert_body("hi")
+    ;
+
+    let r = greetings::into_router()
+        .with_versioning(versioning)
+        .validate().unwrap()
+        .to_axum_router()
+        .with_state(Greeting { text: "hey" });
+
+    Req::get("/greeting").call(&r).await
+        .assert_status(200)
+        .assert_body("hey")
+    ;
+}
+
+/// Tests that synthesized `HEAD`, `OPTIONS` and `405` responses list the methods of each version
+#[tokio::test]
+pub async fn test_auto_methods() {
+    let r = router()
+        .merge(archive::into_router()).unwrap()
+        .with_versioning(Versioning::header("Api-Version", &["v1", "v2"]))
+        .validate().unwrap()
+        .with_auto_head()
+        .with_auto_options()
+        .with_method_not_allowed()
+        .to_axum_router();
+
+    Req::head("/tasks").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(200)
+        .assert_no_body()
+    ;
+
+    Req::options("/tasks").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(204)
+        .assert_header("allow", "GET, HEAD, OPTIONS")
+    ;
+
+    Req::options("/tasks").call(&r).await
+        .assert_status(204)
+        .assert_header("allow", "GET, HEAD, DELETE, OPTIONS")
+    ;
+
+    Req::put("/tasks").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, OPTIONS")
+    ;
+
+    Req::put("/tasks").call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, DELETE, OPTIONS")
+    ;
+
+    Req::get("/archive").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(200)
+        .assert_body("archived tasks")
+    ;
+
+    Req::delete("/archive").with_headers([("api-version", "v1")]).call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, OPTIONS")
+    ;
+
+    Req::options("/archive").call(&r).await
+        .assert_status(204)
+        .assert_header("allow", "GET, HEAD, DELETE, OPTIONS")
+    ;
+
+    Req::put("/health").call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, OPTIONS")
+    ;
+
+    let r = router()
+        .merge(archive::into_router()).unwrap()
+        .with_versioning(Versioning::url_prefix(&["v1", "v2"]))
+        .validate().unwrap()
+        .with_auto_head()
+        .with_auto_options()
+        .with_method_not_allowed()
+        .to_axum_router();
+
+    Req::options("/v1/archive").call(&r).await
+        .assert_status(204)
+        .assert_header("allow", "GET, HEAD, OPTIONS")
+    ;
+
+    Req::put("/v2/tasks").call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, DELETE, OPTIONS")
+    ;
+}
+
+/// Tests that versioned routes get synthesized `HEAD` and `OPTIONS` operations, and a `405` with
+/// the methods of their versions
+#[test]
+pub fn test_openapi_auto_methods() {
+    let openapi = |version| serde_json::to_value(
+        router()
+            .merge(archive::into_router()).unwrap()
+            .with_versioning(Versioning::header("Api-Version", &["v1", "v2"]))
+            .validate().unwrap()
+            .with_auto_head()
+            .with_auto_options()
+            .document_method_not_allowed()
+            .to_openapi_for_version(version, Default::default())
+    ).unwrap();
+
+    let v1 = openapi("v1");
+    assert_eq!(v1.pointer("/paths/~1tasks/head/operationId"), Some(&json!("listTasksHead")));
+    assert_eq!(v1.pointer("/paths/~1tasks/options/responses/204/headers/Allow/description"), Some(&json!("`GET, HEAD, OPTIONS`")));
+    assert_eq!(v1.pointer("/paths/~1tasks/get/responses/405/headers/Allow/description"), Some(&json!("`GET, HEAD, OPTIONS`")));
+    assert_eq!(
+        v1.pointer("/paths/~1archive/get/responses/405/headers/Allow/description"),
+        Some(&json!("`GET, HEAD, OPTIONS` in v1; `GET, HEAD, DELETE, OPTIONS` in v2")),
+    );
+
+    let v2 = openapi("v2");
+    assert_eq!(v2.pointer("/paths/~1tasks/options/responses/204/headers/Allow/description"), Some(&json!("`GET, HEAD, DELETE, OPTIONS`")));
+    assert_eq!(v2.pointer("/paths/~1archive/delete/responses/405/headers/Allow/description"), Some(&json!("`GET, HEAD, DELETE, OPTIONS`")));
+}
+
+/// Tests that versioned routes need versioning, and must not overlap within a version
+#[test]
+pub fn test_validation() {
+    let unversioned = router().validate();
+    assert!(unversioned.is_err(), "versioned routes need versioning");
+
+    let overlapping = router()
+        .merge(tasks_v2::into_router())
+        .unwrap()
+        .with_versioning(Versioning::url_prefix(&["v1", "v2"]))
+        .validate();
+    assert!(overlapping.is_err(), "routes of the same version must not overlap");
+}
+
+/// Tests that the document of a version has the routes existing in it, under its prefix
+#[test]
+pub fn test_openapi_url_prefix() {
+    assert_openapi_doc(
+        |api| router()
+            .with_versioning(Versioning::url_prefix(&["v1", "v2"]))
+            .validate().unwrap()
+            .to_openapi_for_version("v1", api),
+        json!({
+            "components": {},
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/v1/health": {
+                    "get": {
+                        "operationId": "health",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                        "summary": "Health of the service",
+                    },
+                },
+                "/v1/tasks": {
+                    "get": {
+                        "operationId": "listTasks",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                        "summary": "Tasks, as a comma-separated list",
+                    },
+                },
+            },
+        }),
+    );
+}
+
+/// Tests that the document of a version documents the version header of versioned routes
+#[test]
+pub fn test_openapi_header() {
+    assert_openapi_doc(
+        |api| router()
+            .with_versioning(Versioning::header("Api-Version", &["v1", "v2"]).default_version("v1"))
+            .validate().unwrap()
+            .to_openapi_for_version("v2", api),
+        json!({
+            "components": {},
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/health": {
+                    "get": {
+                        "operationId": "health",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                        "summary": "Health of the service",
+                    },
+                },
+                "/tasks": {
+                    "delete": {
+                        "operationId": "deleteTasks",
+                        "parameters": [
+                            {
+                                "description": "API version",
+                                "in": "header",
+                                "name": "Api-Version",
+                                "required": true,
+                                "schema": {
+                                    "enum": [
+                                        "v2",
+                                    ],
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "summary": "Deletes every task",
+                    },
+                    "get": {
+                        "operationId": "listTasks",
+                        "parameters": [
+                            {
+                                "description": "API version",
+                                "in": "header",
+                                "name": "Api-Version",
+                                "required": true,
+                                "schema": {
+                                    "enum": [
+                                        "v2",
+                                    ],
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                        "summary": "Tasks, one per line",
+                    },
+                },
+            },
+        }),
+    );
+}
@@ -0,0 +1,184 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+/// Responses only documented through `default_responses`
+#[allow(dead_code)]
+pub mod errors {
+    use axum::response::IntoResponse;
+    use static_assertions::assert_impl_any;
+
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum InternalErrorResponse {
+        /// Unexpected failure
+        #[Response(code = 500)]
+        InternalError(String),
+    }
+
+    #[Response()]
+    pub enum UnauthorizedResponse {
+        /// Missing or invalid credentials
+        #[Response(code = 401)]
+        Unauthorized,
+    }
+}
+
+/// Tasks of a board
+///
+/// Create, list and remove tasks.
+#[Controller(tag = "tasks", default_responses(super::errors::InternalErrorResponse, super::errors::UnauthorizedResponse))]
+mod tasks_controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TaskResponse {
+        /// Task title
+        #[Response()]
+        Ok(String),
+
+        /// Task is locked by another user
+        #[allow(dead_code)]
+        #[Response(code = 401)]
+        Locked,
+    }
+
+    #[Route(method = "get", path = "/tasks")]
+    async fn get_task() -> TaskResponse {
+        TaskResponse::Ok("ship".into())
+    }
+}
+
+/// Not used as a tag description: `tasks` already has one
+#[Controller(tag = "tasks")]
+mod archive_controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response()]
+    pub enum ArchiveResponse {
+        #[Response(code = 204)]
+        Archived,
+    }
+
+    #[Route(method = "post", path = "/tasks/archive")]
+    async fn archive_tasks() -> ArchiveResponse {
+        ArchiveResponse::Archived
+    }
+}
+
+/// Tests that tags and default responses do not change request handling
+#[tokio::test]
+pub async fn test_handlers() {
+    let r = tasks_controller::into_router().merge(archive_controller::into_router()).unwrap()
+        .validate().unwrap()
+        .to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_body("ship")
+    ;
+
+    Req::post("/tasks/archive").call(&r).await
+        .assert_status(204)
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| tasks_controller::into_router().merge(archive_controller::into_router()).unwrap()
+            .validate().unwrap()
+            .to_openapi(api),
+        json!({
+            "components": {
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/tasks": {
+                    "get": {
+                        "operationId": "getTask",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Task title",
+                            },
+                            "401": {
+                                "description": "Task is locked by another user",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "500": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Unexpected failure",
+                            },
+                        },
+                        "tags": [
+                            "tasks",
+                        ],
+                    },
+                },
+                "/tasks/archive": {
+                    "post": {
+                        "operationId": "archiveTasks",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "tags": [
+                            "tasks",
+                        ],
+                    },
+                },
+            },
+            "tags": [
+                {
+                    "description": "Tasks of a board\n\nCreate, list and remove tasks.",
+                    "name": "tasks",
+                },
+            ],
+        })
+    );
+}
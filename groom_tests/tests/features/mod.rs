@@ -1,14 +1,17 @@
-///! Tests of separate features
+//! Tests of separate features
 
 mod test_utils;
 
 // region: project structuring
 //
 
+mod api_versioning;
+mod controller_tags;
 mod dependency_injection;
 mod multiple_controllers;
 mod nesting_controllers;
 mod openapi_spec_layer;
+mod route_openapi_metadata;
 
 //
 // endregion: project structuring
@@ -16,11 +19,14 @@ mod openapi_spec_layer;
 // region: requests
 //
 
+mod request_auto_methods;
 mod request_axum_request_extractor;
 mod request_body;
+mod request_body_ndjson;
 mod request_headers;
 mod request_methods;
 mod request_path_params;
+mod request_validation;
 mod request_query_params;
 
 //
@@ -31,12 +37,23 @@ mod request_query_params;
 
 mod response_content_negotiation;
 
+mod response_compression;
+mod response_cookies;
+mod response_headers;
+mod response_localization;
+
 mod response_struct;
 
+mod response_type_binary;
+mod response_type_cbor;
+mod response_type_event_stream;
+mod response_type_msgpack;
+mod response_type_ndjson;
 mod response_type_html;
 mod response_type_json;
 mod response_type_plaintext;
 mod response_type_result;
+mod response_type_xml;
 
 //
 // endregion: responses
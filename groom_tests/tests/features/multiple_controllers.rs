@@ -219,7 +219,6 @@ mod disallow_overlaps {
             response::Response
         };
         use groom_macros::{DTO, Response};
-        use utoipa::PartialSchema;
 
         #[DTO(response)]
         pub struct RespData {
@@ -243,11 +242,9 @@ mod disallow_overlaps {
         use axum::{response::IntoResponse};
 
         use groom::{
-            extract::GroomExtractor,
             response::Response
         };
         use groom_macros::{DTO, Response};
-        use utoipa::PartialSchema;
 
         #[DTO(response)]
         pub struct RespData {
@@ -304,7 +301,6 @@ mod allow_shared_components {
 
         use super::RespData;
 
-        use utoipa::PartialSchema;
 
         #[Response(format(json))]
         pub enum HelloResult {
@@ -346,6 +342,17 @@ mod allow_shared_components {
         }
     }
 
+    /// Tests that the response only documented by `controller1` answers with its code
+    #[test]
+    fn test_documented_only_response() {
+        use groom::response::{RenderContext, Response};
+
+        let response = controller1::HelloResult::Ok2(RespData2 { v2: 7 })
+            .__groom_into_response(None, &RenderContext::new(None));
+
+        assert_eq!(response.status(), 202);
+    }
+
     #[test]
     fn test_openapi_doc() {
         assert_openapi_doc(
@@ -469,3 +476,103 @@ mod allow_shared_components {
         )
     }
 }
+
+/// Report routes axum cannot tell apart from `validate()` rather than panicking on merge.
+mod ambiguous_routes {
+    use groom::router::{RouteOverlap, RouterValidationError};
+    use groom_macros::Controller;
+
+    #[Controller()]
+    pub mod tasks {
+        use axum::response::IntoResponse;
+
+        use groom::response::Response;
+        use groom_macros::Response;
+
+        #[Response(format(plain_text))]
+        pub enum TextResponse {
+            #[Response()]
+            Ok(String),
+        }
+
+        #[Route(method = "get", path = "/tasks/{id}")]
+        async fn get_task() -> TextResponse {
+            TextResponse::Ok("task".into())
+        }
+    }
+
+    #[Controller()]
+    pub mod task_admin {
+        use axum::response::IntoResponse;
+
+        use groom::response::Response;
+        use groom_macros::Response;
+
+        #[Response()]
+        pub enum DeletedResponse {
+            #[Response(code = 204)]
+            Deleted,
+        }
+
+        #[Route(method = "delete", path = "/tasks/{task_id}")]
+        async fn delete_task() -> DeletedResponse {
+            DeletedResponse::Deleted
+        }
+    }
+
+    #[Controller()]
+    pub mod task_export {
+        use axum::response::IntoResponse;
+
+        use groom::response::Response;
+        use groom_macros::Response;
+
+        #[Response(format(plain_text))]
+        pub enum TextResponse {
+            #[Response()]
+            Ok(String),
+        }
+
+        #[Route(method = "get", path = "/tasks/export")]
+        async fn export_tasks() -> TextResponse {
+            TextResponse::Ok("export".into())
+        }
+    }
+
+    #[test]
+    fn test_parameter_names_are_ignored() {
+        let result = tasks::into_router()
+            .merge(task_admin::into_router())
+            .unwrap()
+            .validate();
+
+        assert!(matches!(
+            result.err(),
+            Some(RouterValidationError::AmbiguousRoute { path, conflicts_with })
+                if path == "/tasks/{task_id}" && conflicts_with == "/tasks/{id}"
+        ));
+    }
+
+    #[test]
+    fn test_nested_route_shadow() {
+        let result = groom::router::GroomRouter::new()
+            .nest("/api", tasks::into_router())
+            .unwrap()
+            .nest("/api", tasks::into_router())
+            .unwrap()
+            .validate();
+
+        assert!(matches!(result.err(), Some(RouterValidationError::RouteShadow { path, .. }) if path == "/api/tasks/{id}"));
+    }
+
+    #[test]
+    fn test_static_segments_overlap() {
+        let router = tasks::into_router()
+            .merge(task_export::into_router())
+            .unwrap()
+            .validate()
+            .unwrap();
+
+        assert_eq!(router.route_overlaps(), vec![RouteOverlap { path: "/tasks/{id}".into(), other: "/tasks/export".into() }]);
+    }
+}
@@ -0,0 +1,491 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::{DTO, Response};
+
+    /// Task of a board
+    #[DTO(response)]
+    pub struct Task {
+        pub title: String,
+    }
+
+    #[Response(format(json))]
+    pub enum TaskResponse {
+        /// Task of a board
+        #[Response()]
+        Ok(
+            /// Revision of the task
+            #[Response(header(name = "X-Revision"))]
+            u32,
+
+            Task,
+        ),
+    }
+
+    #[Response()]
+    pub enum UpdateResponse {
+        #[Response(code = 204)]
+        Updated,
+    }
+
+    #[Route(method = "get", path = "/tasks")]
+    pub async fn get_task() -> TaskResponse {
+        TaskResponse::Ok(3, Task { title: "ship".into() })
+    }
+
+    #[Route(method = "put", path = "/tasks")]
+    pub async fn put_task() -> UpdateResponse {
+        UpdateResponse::Updated
+    }
+
+    #[Route(method = "post", path = "/queue")]
+    pub async fn enqueue() -> UpdateResponse {
+        UpdateResponse::Updated
+    }
+
+    #[Route(method = "options", path = "/queue")]
+    pub async fn queue_options() -> UpdateResponse {
+        UpdateResponse::Updated
+    }
+}
+
+/// Tests that `HEAD` is served by the `GET` handler, without a body
+#[tokio::test]
+pub async fn test_head() {
+    let r = controller::into_router().validate().unwrap().with_auto_head().to_axum_router();
+
+    Req::head("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/json")
+        .assert_header("x-revision", "3")
+        .assert_no_body()
+    ;
+}
+
+/// Tests that `OPTIONS` answers with `Allow`, unless the path has its own `OPTIONS` handler
+#[tokio::test]
+pub async fn test_options() {
+    let r = controller::into_router().validate().unwrap().with_auto_options().to_axum_router();
+
+    Req::options("/tasks").call(&r).await
+        .assert_status(204)
+        .assert_header("allow", "GET, HEAD, PUT, OPTIONS")
+        .assert_no_body()
+    ;
+
+    Req::options("/queue").call(&r).await
+        .assert_status(204)
+        .assert_no_body()
+    ;
+
+    assert!(!Req::options("/queue").call(&r).await.headers.contains_key("allow"));
+}
+
+/// Tests that unserved methods get `405` with `Allow` and a negotiated body
+#[tokio::test]
+pub async fn test_method_not_allowed() {
+    let r = controller::into_router().validate().unwrap()
+        .with_auto_head()
+        .with_auto_options()
+        .with_method_not_allowed()
+        .to_axum_router();
+
+    Req::delete("/tasks").call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "GET, HEAD, PUT, OPTIONS")
+        .assert_content_type("application/json")
+        .assert_body("{\"error\":\"Method not allowed\",\"allowed\":[\"GET\",\"HEAD\",\"PUT\",\"OPTIONS\"]}")
+    ;
+
+    Req::get("/queue").accept("text/plain").call(&r).await
+        .assert_status(405)
+        .assert_header("allow", "POST, OPTIONS")
+        .assert_content_type("text/plain; charset=utf-8")
+        .assert_body("Method not allowed; allowed methods: POST, OPTIONS")
+    ;
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap()
+            .with_auto_head()
+            .with_auto_options()
+            .document_method_not_allowed()
+            .to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "Task": {
+                        "description": "Task of a board",
+                        "properties": {
+                            "title": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "title",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/queue": {
+                    "options": {
+                        "operationId": "queueOptions",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`POST, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                    "post": {
+                        "operationId": "enqueue",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`POST, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/tasks": {
+                    "get": {
+                        "operationId": "getTask",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Task of a board",
+                                "headers": {
+                                    "X-Revision": {
+                                        "description": "Revision of the task",
+                                        "schema": {
+                                            "format": "int32",
+                                            "minimum": 0,
+                                            "type": "integer",
+                                        },
+                                    },
+                                },
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`GET, HEAD, PUT, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                    "head": {
+                        "operationId": "getTaskHead",
+                        "responses": {
+                            "200": {
+                                "description": "Task of a board",
+                                "headers": {
+                                    "X-Revision": {
+                                        "description": "Revision of the task",
+                                        "schema": {
+                                            "format": "int32",
+                                            "minimum": 0,
+                                            "type": "integer",
+                                        },
+                                    },
+                                },
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`GET, HEAD, PUT, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                    "options": {
+                        "responses": {
+                            "204": {
+                                "description": "Methods allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`GET, HEAD, PUT, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`GET, HEAD, PUT, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                        "summary": "Methods allowed on this path",
+                    },
+                    "put": {
+                        "operationId": "putTask",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                            "405": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "properties": {
+                                                "allowed": {
+                                                    "items": {
+                                                        "type": "string",
+                                                    },
+                                                    "type": "array",
+                                                },
+                                                "error": {
+                                                    "type": "string",
+                                                },
+                                            },
+                                            "required": [
+                                                "error",
+                                                "allowed",
+                                            ],
+                                            "type": "object",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The method is not allowed on this path",
+                                "headers": {
+                                    "Allow": {
+                                        "description": "`GET, HEAD, PUT, OPTIONS`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
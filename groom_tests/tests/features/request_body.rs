@@ -82,6 +82,12 @@ mod controller {
         )))
     }
 
+    /// Partially updates data in JSON or URL-encoded
+    #[Route(method = "patch", path = "/multi_format")]
+    async fn rq_patch_multi_format_body(body: MultiFormatRequestBody) -> StringResponse {
+        StringResponse::Ok(format!("renamed to {}", body.name))
+    }
+
     // ---
 
     /// Some DTO
@@ -105,6 +111,46 @@ mod controller {
 
     // ---
 
+    /// Temperature in degrees Celsius
+    #[RequestBody(format(plain_text))]
+    pub struct Temperature(i32);
+
+    #[Route(method = "post", path = "/plain_text")]
+    async fn rq_cons_plain_text(Temperature(t): Temperature) -> StringResponse {
+        StringResponse::Ok(format!("{t} °C"))
+    }
+
+    /// A point on the map
+    #[DTO(request)]
+    pub struct GeoPoint {
+        lat: f64,
+        lon: f64,
+    }
+
+    impl std::str::FromStr for GeoPoint {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (lat, lon) = s.split_once(',').ok_or("expected `lat,lon`")?;
+
+            Ok(GeoPoint {
+                lat: lat.trim().parse().map_err(|_| "invalid latitude")?,
+                lon: lon.trim().parse().map_err(|_| "invalid longitude")?,
+            })
+        }
+    }
+
+    /// Location as JSON, a form or `lat,lon` text
+    #[RequestBody(format(json, url_encoded, plain_text))]
+    pub struct Location(GeoPoint);
+
+    #[Route(method = "post", path = "/plain_text_multi_format")]
+    async fn rq_cons_plain_text_multi_format(Location(p): Location) -> StringResponse {
+        StringResponse::Ok(format!("{} {}", p.lat, p.lon))
+    }
+
+    // ---
+
     #[RequestBody(format(url_encoded))]
     pub struct StatusFilter {
         status: Vec<Status>,
@@ -144,7 +190,94 @@ mod controller {
         }
     }
 
-    // todo: Multipart (for multipart/form-data)
+    // ---
+
+    /// Partner order in XML
+    #[RequestBody(format(xml))]
+    pub struct PartnerOrder {
+        /// Ordered item
+        item: String,
+
+        /// Amount of items
+        amount: u32,
+    }
+
+    #[Route(method = "post", path = "/xml")]
+    async fn rq_cons_xml(body: PartnerOrder) -> StringResponse {
+        StringResponse::Ok(format!("{} x {}", body.amount, body.item))
+    }
+
+    // ---
+
+    /// Sensor reading from a constrained device
+    #[RequestBody(format(json, cbor))]
+    pub struct SensorReading {
+        sensor: String,
+        value: i32,
+    }
+
+    #[Route(method = "post", path = "/cbor")]
+    async fn rq_cons_cbor(body: SensorReading) -> StringResponse {
+        StringResponse::Ok(format!("{} = {}", body.sensor, body.value))
+    }
+
+    // ---
+
+    /// Meter reading sent by a gateway
+    #[RequestBody(format(msgpack))]
+    pub struct MeterReading {
+        meter: String,
+        kwh: u32,
+    }
+
+    #[Route(method = "post", path = "/msgpack")]
+    async fn rq_cons_msgpack(body: MeterReading) -> StringResponse {
+        StringResponse::Ok(format!("{}: {} kWh", body.meter, body.kwh))
+    }
+
+    // ---
+
+    /// Short note, limited in size
+    #[RequestBody(format(json), max_size = "64B")]
+    pub struct ShortNote {
+        text: String,
+    }
+
+    #[Route(method = "post", path = "/limited")]
+    async fn rq_cons_limited(body: ShortNote) -> StringResponse {
+        StringResponse::Ok(body.text)
+    }
+
+    // ---
+
+    /// Avatar upload
+    #[RequestBody(format(multipart))]
+    pub struct AvatarUpload {
+        /// Title of the picture
+        title: String,
+
+        /// Picture width
+        width: Option<u16>,
+
+        /// Picture itself
+        picture: groom::extract::Upload,
+
+        /// Thumbnails
+        thumbnails: Vec<axum::body::Bytes>,
+    }
+
+    #[Route(method = "post", path = "/multipart")]
+    async fn rq_cons_multipart(body: AvatarUpload) -> StringResponse {
+        StringResponse::Ok(format!(
+            "{} ({}): {} bytes of {} named {}, {} thumbnails",
+            body.title,
+            body.width.map_or("unknown width".into(), |w| format!("{w}px")),
+            body.picture.bytes.len(),
+            body.picture.content_type.unwrap_or_default(),
+            body.picture.file_name.unwrap_or_default(),
+            body.thumbnails.len(),
+        ))
+    }
 }
 
 #[tokio::test]
@@ -436,223 +569,1466 @@ pub async fn test_url_encoded_opt_vec_of_enums() {
 }
 
 
-/// Tests that openapi definition is correctly generated
-#[test]
-pub fn test_openapi() {
-    assert_openapi_doc(
-        |api| controller::into_router().validate().unwrap().to_openapi(api),
-        json!({
-            "components": {
-                "schemas": {
-                    "MultiFormatDto": {
-                        "description": "Some DTO",
-                        "properties": {
-                            "age": {
-                                "format": "int32",
-                                "minimum": 0,
-                                "type": [
-                                    "integer",
-                                    "null",
-                                ],
-                            },
-                            "name": {
-                                "type": "string",
-                            },
-                        },
-                        "required":  [
-                            "name",
-                        ],
-                        "type": "object",
-                    },
-                    "MultiFormatRequestBody": {
-                        "description": "Request body as a named struct.",
-                        "properties": {
-                            "age": {
-                                "description": "Person's age",
-                                "format": "int32",
-                                "minimum": 0,
-                                "type": [
-                                    "integer",
-                                    "null",
-                                ],
-                            },
-                            "name": {
-                                "description": "Person's name",
-                                "type": "string",
-                            },
-                        },
-                        "required": [
-                            "name",
-                        ],
-                        "type": "object",
-                    },
-                    "OptStatusFilter": {
-                        "properties": {
-                            "status": {
-                                "items": {
-                                    "$ref": "#/components/schemas/Status",
-                                },
-                                "type": [
-                                    "array",
-                                    "null",
-                                ],
-                            },
-                        },
-                        "type": "object",
-                    },
-                    "Status": {
-                        "enum": [
-                            "New",
-                            "Closed",
-                        ],
-                        "type": "string",
-                    },
-                    "StatusFilter": {
-                        "properties": {
-                            "status": {
-                                "items": {
-                                    "$ref": "#/components/schemas/Status",
-                                },
-                                "type": "array",
-                            },
-                        },
-                        "required": [
-                            "status",
-                        ],
-                        "type": "object",
-                    },
-                },
-            },
-            "info": {
-                "contact": {
-                    "email": "mail@example.com",
-                    "name": "name",
-                },
-                "description": "d",
-                "license": {
-                    "name": "n",
-                },
-                "title": "t",
-                "version": "0.0.0",
-            },
-            "openapi": "3.1.0",
-            "paths": {
-                "/bytes_body": {
-                    "post": {
-                        "operationId": ("rqConsBytesBody"),
-                        "requestBody": {
-                            "content": {
-                                "application/octet-stream": {
-                                    "schema": {
-                                        "format": "binary",
-                                        "type": "string",
-                                    },
-                                },
-                            },
-                            "required": true,
-                        },
-                        "responses": {
-                            "200": {
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                                "description": "Everything is ok",
-                            },
-                            "400": {
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                                "description": "Something in the request is not ok",
-                            },
-                            "406": {
-                                "description": "The requested content type is not supported",
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                            },
-                        },
-                        "summary": "Request consumption: Bytes body",
-                    },
-                },
-                "/image_body": {
-                    "post": {
-                        "operationId": ("rqConsImageBody"),
-                        "requestBody": {
-                            "content": {
-                                "image/jpeg": {
-                                    "schema": {
-                                        "format": "binary",
-                                        "type": "string",
-                                    },
-                                },
-                            },
-                            "required": true,
-                        },
-                        "responses": {
-                            "200": {
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                                "description": "Everything is ok",
-                            },
-                            "400": {
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                                "description": "Something in the request is not ok",
-                            },
-                            "406": {
-                                "description": "The requested content type is not supported",
-                                "content": {
-                                    "text/plain; charset=utf-8": {
-                                        "schema": {
-                                            "type": "string",
-                                        },
-                                    },
-                                },
-                            },
-                        },
-                        "summary": "Request consumption: ImageJpeg body",
-                    },
-                },
-                "/multi_format": {
-                    "post": {
-                        "operationId": ("rqConsMultiFormatBody"),
-                        "requestBody": {
+// Request body XML
+#[tokio::test]
+pub async fn test_post_xml() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    for content_type in ["application/xml", "text/xml; charset=utf-8", "application/vnd.partner+xml"] {
+        Req::post("/xml")
+            .with_body(
+                ReqBody::new("<PartnerOrder><item>widget</item><amount>3</amount></PartnerOrder>")
+                    .with_content_type(content_type)
+            )
+            .call(&r)
+            .await
+            .assert_status(200)
+            .assert_body("3 x widget")
+        ;
+    }
+
+    Req::post("/xml")
+        .with_body(
+            ReqBody::new("<PartnerOrder><item>widget</item></PartnerOrder>")
+                .with_content_type("application/xml")
+        )
+        .call(&r)
+        .await
+        .assert_status(422)
+    ;
+
+    Req::post("/xml")
+        .with_body(
+            ReqBody::new("{\"item\": \"widget\", \"amount\": 3}")
+                .with_content_type("application/json")
+        )
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-post", "application/xml, text/xml")
+        .assert_body("Unsupported Content-Type")
+    ;
+}
+
+// Request body CBOR
+#[tokio::test]
+pub async fn test_post_cbor() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let mut body = Vec::new();
+    ciborium::into_writer(&json!({"sensor": "t1", "value": -4}), &mut body).unwrap();
+
+    Req::post("/cbor")
+        .with_body(ReqBody::new(body).with_content_type("application/cbor"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("t1 = -4")
+    ;
+
+    let mut body = Vec::new();
+    ciborium::into_writer(&json!({"sensor": "t1"}), &mut body).unwrap();
+
+    Req::post("/cbor")
+        .with_body(ReqBody::new(body).with_content_type("application/cbor"))
+        .call(&r)
+        .await
+        .assert_status(422)
+    ;
+
+    Req::post("/cbor")
+        .with_body(ReqBody::new(vec![0xa1u8]).with_content_type("application/cbor"))
+        .call(&r)
+        .await
+        .assert_status(400)
+    ;
+}
+
+// Unsupported request body content types
+#[tokio::test]
+pub async fn test_unsupported_media_type() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::patch("/multi_format")
+        .with_body(ReqBody::new("{\"name\": \"Ford\"}").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("renamed to Ford")
+    ;
+
+    Req::patch("/multi_format")
+        .with_body(ReqBody::new("Ford").with_content_type("text/plain"))
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-patch", "application/json, application/x-www-form-urlencoded")
+        .assert_body("Unsupported Content-Type")
+    ;
+
+    let result = Req::post("/multi_format")
+        .with_body(ReqBody::new("{\"name\": \"Ford\"}"))
+        .call(&r)
+        .await;
+
+    result
+        .assert_status(415)
+        .assert_header("accept-post", "application/json, application/x-www-form-urlencoded")
+    ;
+    assert!(result.headers.get("accept-patch").is_none());
+}
+
+// Request body text/plain
+#[tokio::test]
+pub async fn test_post_plain_text() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/plain_text")
+        .with_body(ReqBody::new("-12").with_content_type("text/plain"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("-12 °C")
+    ;
+
+    Req::post("/plain_text")
+        .with_body(ReqBody::new("warm").with_content_type("text/plain; charset=utf-8"))
+        .call(&r)
+        .await
+        .assert_status(400)
+        .assert_body("Failed to parse the text/plain body: invalid digit found in string")
+    ;
+
+    Req::post("/plain_text")
+        .with_body(ReqBody::new("7").with_content_type("text/plain; charset=koi8-r"))
+        .call(&r)
+        .await
+        .assert_status(400)
+        .assert_body("Unsupported charset of the text/plain body: koi8-r")
+    ;
+
+    Req::post("/plain_text")
+        .with_body(ReqBody::new("7").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-post", "text/plain")
+        .assert_body("Unsupported Content-Type")
+    ;
+}
+
+// Request body text/plain alongside other formats
+#[tokio::test]
+pub async fn test_post_plain_text_multi_format() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/plain_text_multi_format")
+        .with_body(ReqBody::new("51.5, -0.12").with_content_type("text/plain; charset=us-ascii"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("51.5 -0.12")
+    ;
+
+    Req::post("/plain_text_multi_format")
+        .with_body(ReqBody::new("{\"lat\": 51.5, \"lon\": -0.12}").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("51.5 -0.12")
+    ;
+
+    Req::post("/plain_text_multi_format")
+        .with_body(ReqBody::new("lat=51.5&lon=-0.12").with_content_type("application/x-www-form-urlencoded"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("51.5 -0.12")
+    ;
+
+    Req::post("/plain_text_multi_format")
+        .with_body(ReqBody::new("51.5").with_content_type("text/plain"))
+        .call(&r)
+        .await
+        .assert_status(400)
+        .assert_body("Failed to parse the text/plain body: expected `lat,lon`")
+    ;
+}
+
+// Request body MessagePack
+#[tokio::test]
+pub async fn test_post_msgpack() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let body = rmp_serde::to_vec_named(&json!({"meter": "m7", "kwh": 12})).unwrap();
+
+    Req::post("/msgpack")
+        .with_body(ReqBody::new(body.clone()).with_content_type("application/msgpack"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("m7: 12 kWh")
+    ;
+
+    Req::post("/msgpack")
+        .with_body(ReqBody::new(body).with_content_type("application/x-msgpack"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("m7: 12 kWh")
+    ;
+
+    let body = rmp_serde::to_vec_named(&json!({"meter": "m7"})).unwrap();
+
+    Req::post("/msgpack")
+        .with_body(ReqBody::new(body).with_content_type("application/msgpack"))
+        .call(&r)
+        .await
+        .assert_status(422)
+    ;
+
+    Req::post("/msgpack")
+        .with_body(ReqBody::new(vec![0x82u8]).with_content_type("application/msgpack"))
+        .call(&r)
+        .await
+        .assert_status(400)
+    ;
+}
+
+// Request body size limit
+#[tokio::test]
+pub async fn test_post_limited() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/limited")
+        .with_body(ReqBody::new(r#"{"text": "fits"}"#).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("fits")
+    ;
+
+    let body = format!(r#"{{"text": "{}"}}"#, "x".repeat(64));
+
+    Req::post("/limited")
+        .with_body(ReqBody::new(body).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(413)
+        .assert_body("Payload Too Large: the request body must not exceed 64B")
+    ;
+
+    // declared length is checked before reading the body
+    Req::post("/limited")
+        .with_headers([("content-length", "65")])
+        .with_body(ReqBody::new(r#"{"text": "fits"}"#).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(413)
+    ;
+
+    Req::post("/limited")
+        .with_body(ReqBody::new(r#"{"text": "fits"}"#).with_content_type("application/xml"))
+        .call(&r)
+        .await
+        .assert_status(415)
+    ;
+
+    // the media type is checked before the size
+    Req::post("/limited")
+        .with_body(ReqBody::new(format!("<text>{}</text>", "x".repeat(64))).with_content_type("application/xml"))
+        .call(&r)
+        .await
+        .assert_status(415)
+    ;
+}
+
+#[Controller()]
+mod large_limit {
+    use axum::response::IntoResponse;
+
+    use groom::{
+        response::Response,
+        extract::GroomExtractor
+    };
+    use groom_macros::{RequestBody, Response};
+
+    #[Response(format(plain_text))]
+    pub enum LengthResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[RequestBody(format(json), max_size = "4MiB")]
+    pub struct LongNote {
+        text: String,
+    }
+
+    #[Route(method = "post", path = "/notes")]
+    async fn create_note(body: LongNote) -> LengthResponse {
+        LengthResponse::Ok(body.text.len().to_string())
+    }
+}
+
+// A size limit above axum's default body limit of 2 MiB replaces it
+#[tokio::test]
+pub async fn test_post_limited_above_default_limit() {
+    let r = large_limit::into_router().validate().unwrap().to_axum_router();
+
+    let text = "x".repeat(3 << 20);
+
+    Req::post("/notes")
+        .with_body(ReqBody::new(format!(r#"{{"text": "{text}"}}"#)).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body((3 << 20).to_string())
+    ;
+
+    let text = "x".repeat(4 << 20);
+
+    Req::post("/notes")
+        .with_body(ReqBody::new(format!(r#"{{"text": "{text}"}}"#)).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(413)
+    ;
+}
+
+fn encode(coding: &str, body: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+
+    match coding {
+        "gzip" => {
+            let mut e = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            e.write_all(body).unwrap();
+            e.finish().unwrap()
+        },
+        "deflate" => {
+            let mut e = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            e.write_all(body).unwrap();
+            e.finish().unwrap()
+        },
+        "br" => {
+            let mut out = Vec::new();
+            brotli::CompressorWriter::new(&mut out, 4096, 5, 22).write_all(body).unwrap();
+            out
+        },
+        "zstd" => zstd::encode_all(body, 0).unwrap(),
+        _ => unreachable!(),
+    }
+}
+
+// Request body Content-Encoding
+#[tokio::test]
+pub async fn test_post_encoded() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    for coding in ["gzip", "deflate", "br", "zstd"] {
+        Req::post("/multi_format")
+            .with_headers([("content-encoding", coding)])
+            .with_body(
+                ReqBody::new(encode(coding, br#"{"name": "Mark", "age": 20}"#))
+                    .with_content_type("application/json")
+            )
+            .call(&r)
+            .await
+            .assert_status(200)
+            .assert_body("someone named Mark is 20 years old")
+        ;
+    }
+
+    // codings are undone in reverse order
+    Req::post("/multi_format")
+        .with_headers([("content-encoding", "gzip, br")])
+        .with_body(
+            ReqBody::new(encode("br", &encode("gzip", b"name=Mark")))
+                .with_content_type("application/x-www-form-urlencoded")
+        )
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("someone named Mark is who knows how many years old")
+    ;
+
+    Req::post("/multi_format")
+        .with_headers([("content-encoding", "compress")])
+        .with_body(ReqBody::new("{}").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-encoding", "gzip, deflate, br, zstd")
+        .assert_body("Unsupported Content-Encoding `compress`")
+    ;
+
+    Req::post("/multi_format")
+        .with_headers([("content-encoding", "gzip")])
+        .with_body(ReqBody::new("{}").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(400)
+    ;
+
+    // the limit applies to the decoded body
+    let body = format!(r#"{{"text": "{}"}}"#, "x".repeat(1000));
+
+    Req::post("/limited")
+        .with_headers([("content-encoding", "gzip")])
+        .with_body(ReqBody::new(encode("gzip", body.as_bytes())).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(413)
+    ;
+}
+
+// Request body multipart/form-data
+#[tokio::test]
+pub async fn test_post_multipart() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/multipart")
+        .with_body(
+            ReqBody::new(concat!(
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"title\"\r\n\r\n",
+                "Me\r\n",
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"picture\"; filename=\"me.png\"\r\n",
+                "Content-Type: image/png\r\n\r\n",
+                "PNG\r\n",
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"thumbnails\"\r\n\r\n",
+                "P\r\n",
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"thumbnails\"\r\n\r\n",
+                "N\r\n",
+                "--X--\r\n",
+            ))
+            .with_content_type("multipart/form-data; boundary=X")
+        )
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_content_type("text/plain; charset=utf-8")
+        .assert_body("Me (unknown width): 3 bytes of image/png named me.png, 2 thumbnails")
+    ;
+
+    Req::post("/multipart")
+        .with_body(
+            ReqBody::new(concat!(
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"title\"\r\n\r\n",
+                "Me\r\n",
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"width\"\r\n\r\n",
+                "64\r\n",
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"picture\"\r\n\r\n",
+                "PNG\r\n",
+                "--X--\r\n",
+            ))
+            .with_content_type("multipart/form-data; boundary=X")
+        )
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("Me (64px): 3 bytes of  named , 0 thumbnails")
+    ;
+
+    // missing required part
+    Req::post("/multipart")
+        .with_body(
+            ReqBody::new(concat!(
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"title\"\r\n\r\n",
+                "Me\r\n",
+                "--X--\r\n",
+            ))
+            .with_content_type("multipart/form-data; boundary=X")
+        )
+        .call(&r)
+        .await
+        .assert_status(422)
+        .assert_body("Missing multipart part `picture`")
+    ;
+
+    // text part that does not parse into the field type
+    Req::post("/multipart")
+        .with_body(
+            ReqBody::new(concat!(
+                "--X\r\n",
+                "Content-Disposition: form-data; name=\"width\"\r\n\r\n",
+                "wide\r\n",
+                "--X--\r\n",
+            ))
+            .with_content_type("multipart/form-data; boundary=X")
+        )
+        .call(&r)
+        .await
+        .assert_status(422)
+        .assert_body("Failed to parse multipart part `width`: invalid digit found in string")
+    ;
+
+    // multipart is not accepted by other request bodies
+    Req::post("/multi_format")
+        .with_body(
+            ReqBody::new("--X--\r\n")
+                .with_content_type("multipart/form-data; boundary=X")
+        )
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-post", "application/json, application/x-www-form-urlencoded")
+    ;
+}
+
+/// Tests that openapi definition is correctly generated
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "AvatarUpload": {
+                        "description": "Avatar upload",
+                        "properties": {
+                            "picture": {
+                                "description": "Picture itself",
+                                "oneOf": [
+                                    {
+                                        "format": "binary",
+                                        "type": "string",
+                                    },
+                                ],
+                            },
+                            "thumbnails": {
+                                "description": "Thumbnails",
+                                "items": {
+                                    "format": "binary",
+                                    "type": "string",
+                                },
+                                "type": "array",
+                            },
+                            "title": {
+                                "description": "Title of the picture",
+                                "type": "string",
+                            },
+                            "width": {
+                                "description": "Picture width",
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": [
+                                    "integer",
+                                    "null",
+                                ],
+                            },
+                        },
+                        "required": [
+                            "title",
+                            "picture",
+                            "thumbnails",
+                        ],
+                        "type": "object",
+                    },
+                    "MeterReading": {
+                        "description": "Meter reading sent by a gateway",
+                        "properties": {
+                            "kwh": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                            "meter": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "meter",
+                            "kwh",
+                        ],
+                        "type": "object",
+                    },
+                    "GeoPoint": {
+                        "description": "A point on the map",
+                        "properties": {
+                            "lat": {
+                                "format": "double",
+                                "type": "number",
+                            },
+                            "lon": {
+                                "format": "double",
+                                "type": "number",
+                            },
+                        },
+                        "required": [
+                            "lat",
+                            "lon",
+                        ],
+                        "type": "object",
+                    },
+                    "MultiFormatDto": {
+                        "description": "Some DTO",
+                        "properties": {
+                            "age": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": [
+                                    "integer",
+                                    "null",
+                                ],
+                            },
+                            "name": {
+                                "type": "string",
+                            },
+                        },
+                        "required":  [
+                            "name",
+                        ],
+                        "type": "object",
+                    },
+                    "MultiFormatRequestBody": {
+                        "description": "Request body as a named struct.",
+                        "properties": {
+                            "age": {
+                                "description": "Person's age",
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": [
+                                    "integer",
+                                    "null",
+                                ],
+                            },
+                            "name": {
+                                "description": "Person's name",
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "name",
+                        ],
+                        "type": "object",
+                    },
+                    "OptStatusFilter": {
+                        "properties": {
+                            "status": {
+                                "items": {
+                                    "$ref": "#/components/schemas/Status",
+                                },
+                                "type": [
+                                    "array",
+                                    "null",
+                                ],
+                            },
+                        },
+                        "type": "object",
+                    },
+                    "SensorReading": {
+                        "description": "Sensor reading from a constrained device",
+                        "properties": {
+                            "sensor": {
+                                "type": "string",
+                            },
+                            "value": {
+                                "format": "int32",
+                                "type": "integer",
+                            },
+                        },
+                        "required": [
+                            "sensor",
+                            "value",
+                        ],
+                        "type": "object",
+                    },
+                    "ShortNote": {
+                        "description": "Short note, limited in size",
+                        "properties": {
+                            "text": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "text",
+                        ],
+                        "type": "object",
+                    },
+                    "Status": {
+                        "enum": [
+                            "New",
+                            "Closed",
+                        ],
+                        "type": "string",
+                    },
+                    "StatusFilter": {
+                        "properties": {
+                            "status": {
+                                "items": {
+                                    "$ref": "#/components/schemas/Status",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": [
+                            "status",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/bytes_body": {
+                    "post": {
+                        "operationId": ("rqConsBytesBody"),
+                        "requestBody": {
+                            "content": {
+                                "application/octet-stream": {
+                                    "schema": {
+                                        "format": "binary",
+                                        "type": "string",
+                                    },
+                                },
+                            },
+                            "required": true,
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                        "summary": "Request consumption: Bytes body",
+                    },
+                },
+                "/image_body": {
+                    "post": {
+                        "operationId": ("rqConsImageBody"),
+                        "requestBody": {
+                            "content": {
+                                "image/jpeg": {
+                                    "schema": {
+                                        "format": "binary",
+                                        "type": "string",
+                                    },
+                                },
+                            },
+                            "required": true,
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                        "summary": "Request consumption: ImageJpeg body",
+                    },
+                },
+                "/limited": {
+                    "post": {
+                        "operationId": "rqConsLimited",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/ShortNote",
+                                    },
+                                },
+                            },
+                            "description": "Short note, limited in size",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body is larger than 64B",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                        "x-max-body-size": 64,
+                    },
+                },
+                "/multi_format": {
+                    "post": {
+                        "operationId": ("rqConsMultiFormatBody"),
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatRequestBody"
+                                    },
+                                },
+                                "application/x-www-form-urlencoded": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatRequestBody",
+                                    },
+                                },
+                            },
+                            "description": "Request body as a named struct.",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                        "summary": "Accepts data in JSON or URL-encoded",
+                    },
+                    "patch": {
+                        "operationId": "rqPatchMultiFormatBody",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatRequestBody",
+                                    },
+                                },
+                                "application/x-www-form-urlencoded": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatRequestBody",
+                                    },
+                                },
+                            },
+                            "description": "Request body as a named struct.",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                        "summary": "Partially updates data in JSON or URL-encoded",
+                    },
+                },
+                "/multi_format_dto": {
+                    "post": {
+                        "operationId": ("rqConsMultiFormatBodyDto"),
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatDto",
+                                    },
+                                },
+                                "application/x-www-form-urlencoded": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MultiFormatDto",
+                                    },
+                                },
+                            },
+                            "description": "Request body as an unnamed struct that wraps around a DTO",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                    },
+                },
+                "/cbor": {
+                    "post": {
+                        "operationId": "rqConsCbor",
+                        "requestBody": {
+                            "content": {
+                                "application/cbor": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/SensorReading",
+                                    },
+                                },
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/SensorReading",
+                                    },
+                                },
+                            },
+                            "description": "Sensor reading from a constrained device",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                    },
+                },
+                "/msgpack": {
+                    "post": {
+                        "operationId": "rqConsMsgpack",
+                        "requestBody": {
+                            "content": {
+                                "application/msgpack": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/MeterReading",
+                                    },
+                                },
+                            },
+                            "description": "Meter reading sent by a gateway",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                    },
+                },
+                "/multipart": {
+                    "post": {
+                        "operationId": "rqConsMultipart",
+                        "requestBody": {
                             "content": {
-                                "application/json": {
+                                "multipart/form-data": {
+                                    "encoding": {
+                                        "picture": {
+                                            "contentType": "application/octet-stream",
+                                        },
+                                        "thumbnails": {
+                                            "contentType": "application/octet-stream",
+                                        },
+                                        "title": {
+                                            "contentType": "text/plain",
+                                        },
+                                        "width": {
+                                            "contentType": "text/plain",
+                                        },
+                                    },
                                     "schema": {
-                                        "$ref": "#/components/schemas/MultiFormatRequestBody"
+                                        "$ref": "#/components/schemas/AvatarUpload",
                                     },
                                 },
-                                "application/x-www-form-urlencoded": {
+                            },
+                            "description": "Avatar upload",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                    },
+                },
+                "/plain_text": {
+                    "post": {
+                        "operationId": "rqConsPlainText",
+                        "requestBody": {
+                            "content": {
+                                "text/plain": {
                                     "schema": {
-                                        "$ref": "#/components/schemas/MultiFormatRequestBody",
+                                        "type": "string",
                                     },
                                 },
                             },
-                            "description": "Request body as a named struct.",
+                            "description": "Temperature in degrees Celsius",
                             "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
                         },
                         "responses": {
                             "200": {
@@ -676,7 +2052,26 @@ pub fn test_openapi() {
                                 "description": "Something in the request is not ok",
                             },
                             "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
                                 "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
                                 "content": {
                                     "text/plain; charset=utf-8": {
                                         "schema": {
@@ -684,29 +2079,40 @@ pub fn test_openapi() {
                                         },
                                     },
                                 },
+                                "description": "The request body content type or content encoding is not supported",
                             },
                         },
-                        "summary": "Accepts data in JSON or URL-encoded",
                     },
                 },
-                "/multi_format_dto": {
+                "/plain_text_multi_format": {
                     "post": {
-                        "operationId": ("rqConsMultiFormatBodyDto"),
+                        "operationId": "rqConsPlainTextMultiFormat",
                         "requestBody": {
                             "content": {
                                 "application/json": {
                                     "schema": {
-                                        "$ref": "#/components/schemas/MultiFormatDto",
+                                        "$ref": "#/components/schemas/GeoPoint",
                                     },
                                 },
                                 "application/x-www-form-urlencoded": {
                                     "schema": {
-                                        "$ref": "#/components/schemas/MultiFormatDto",
+                                        "$ref": "#/components/schemas/GeoPoint",
+                                    },
+                                },
+                                "text/plain": {
+                                    "schema": {
+                                        "type": "string",
                                     },
                                 },
                             },
-                            "description": "Request body as an unnamed struct that wraps around a DTO",
+                            "description": "Location as JSON, a form or `lat,lon` text",
                             "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
                         },
                         "responses": {
                             "200": {
@@ -730,7 +2136,36 @@ pub fn test_openapi() {
                                 "description": "Something in the request is not ok",
                             },
                             "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
                                 "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
                                 "content": {
                                     "text/plain; charset=utf-8": {
                                         "schema": {
@@ -738,6 +2173,7 @@ pub fn test_openapi() {
                                         },
                                     },
                                 },
+                                "description": "The request body does not match the expected schema",
                             },
                         },
                     },
@@ -802,6 +2238,12 @@ pub fn test_openapi() {
                                 },
                             },
                             "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
                         },
                         "responses": {
                             "200": {
@@ -834,6 +2276,36 @@ pub fn test_openapi() {
                                     },
                                 },
                             },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
                         },
                     },
                 },
@@ -849,6 +2321,116 @@ pub fn test_openapi() {
                                 },
                             },
                             "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Everything is ok",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Something in the request is not ok",
+                            },
+                            "406": {
+                                "description": "The requested content type is not supported",
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema",
+                            },
+                        },
+                    },
+                },
+                "/xml": {
+                    "post": {
+                        "operationId": "rqConsXml",
+                        "requestBody": {
+                            "content": {
+                                "application/xml": {
+                                    "schema": {
+                                        "description": "Partner order in XML",
+                                        "properties": {
+                                            "amount": {
+                                                "description": "Amount of items",
+                                                "format": "int32",
+                                                "minimum": 0,
+                                                "type": "integer",
+                                            },
+                                            "item": {
+                                                "description": "Ordered item",
+                                                "type": "string",
+                                            },
+                                        },
+                                        "required": [
+                                            "item",
+                                            "amount",
+                                        ],
+                                        "type": "object",
+                                        "xml": {
+                                            "name": "PartnerOrder",
+                                        },
+                                    },
+                                },
+                            },
+                            "description": "Partner order in XML",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
                         },
                         "responses": {
                             "200": {
@@ -872,7 +2454,36 @@ pub fn test_openapi() {
                                 "description": "Something in the request is not ok",
                             },
                             "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
                                 "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
                                 "content": {
                                     "text/plain; charset=utf-8": {
                                         "schema": {
@@ -880,6 +2491,7 @@ pub fn test_openapi() {
                                         },
                                     },
                                 },
+                                "description": "The request body does not match the expected schema",
                             },
                         },
                     },
@@ -0,0 +1,186 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, ReqBody, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::extract::{GroomExtractor, LineError, NdJsonStream};
+    use groom::response::Response;
+    use groom_macros::{DTO, Response};
+
+    #[Response(format(plain_text))]
+    pub enum ImportResponse {
+        /// Import summary
+        #[Response()]
+        Ok(String),
+    }
+
+    /// Task to import
+    #[DTO(request)]
+    pub struct NewTask {
+        #[validate(non_empty)]
+        title: String,
+        done: bool,
+    }
+
+    #[Route(method = "post", path = "/tasks/import")]
+    async fn import_tasks(tasks: NdJsonStream<NewTask>) -> ImportResponse {
+        let mut tasks = tasks.with_max_line_length(64);
+        let mut report = Vec::new();
+
+        while let Some(task) = tasks.next().await {
+            report.push(match task {
+                Ok(task) => format!("{}:{}", task.title, task.done),
+                Err(LineError::Invalid { line, errors }) => format!("line {line}: {}", errors.errors[0].message),
+                Err(LineError::Deserialize { line, .. }) => format!("line {line}: malformed"),
+                Err(LineError::TooLong { line, .. }) => format!("line {line}: too long"),
+                Err(LineError::Read(e)) => format!("read: {e}"),
+            });
+        }
+
+        ImportResponse::Ok(report.join("; "))
+    }
+}
+
+/// Tests that every line is yielded on its own, with bad lines reported in place
+#[tokio::test]
+pub async fn test_import() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let body = concat!(
+        "{\"title\": \"write docs\", \"done\": false}\n",
+        "\n",
+        "{\"title\": \"\", \"done\": true}\r\n",
+        "{\"title\": 1}\n",
+        "{\"title\": \"a title longer than the maximum length of a line\", \"done\": true}\n",
+        "{\"title\": \"ship\", \"done\": true}",
+    );
+
+    Req::post("/tasks/import")
+        .with_body(ReqBody::new(body).with_content_type("application/x-ndjson"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("write docs:false; line 3: must not be empty; line 4: malformed; line 5: too long; ship:true")
+    ;
+
+    Req::post("/tasks/import")
+        .with_body(ReqBody::new("").with_content_type("application/ndjson"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("")
+    ;
+}
+
+/// Tests that other content types are answered with 415
+#[tokio::test]
+pub async fn test_unsupported_media_type() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/tasks/import")
+        .with_body(ReqBody::new("[]").with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(415)
+        .assert_header("accept-post", "application/x-ndjson")
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "NewTask": {
+                        "description": "Task to import",
+                        "properties": {
+                            "done": {
+                                "type": "boolean",
+                            },
+                            "title": {
+                                "minLength": 1,
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "title",
+                            "done",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/tasks/import": {
+                    "post": {
+                        "operationId": "importTasks",
+                        "requestBody": {
+                            "content": {
+                                "application/x-ndjson": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/NewTask",
+                                    },
+                                },
+                            },
+                            "description": "Newline-delimited JSON, one `NewTask` per line",
+                            "required": true,
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Import summary",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
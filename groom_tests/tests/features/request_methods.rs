@@ -1,9 +1,12 @@
+#![allow(clippy::enum_variant_names)]
 
 use crate::{
     groom_macros::Controller,
     features::test_utils::{Req, assert_openapi_doc}
 };
 
+use groom::{extract::ComponentsRegistry, router::{GroomRouter, RouterValidationError}};
+use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItemBuilder};
 use serde_json::json;
 
 #[Controller()]
@@ -55,6 +58,16 @@ mod controller {
     pub async fn root_patch() -> RootResponse {
         RootResponse::OkPatch
     }
+
+    #[Route(method("put", "patch"), path="/tasks")]
+    pub async fn update_task() -> RootResponse {
+        RootResponse::OkPut
+    }
+
+    #[Route(method("get", "connect"), path="/tunnel")]
+    pub async fn tunnel() -> RootResponse {
+        RootResponse::OkGet
+    }
 }
 
 
@@ -118,6 +131,49 @@ pub async fn test_put() {
     ;
 }
 
+/// Tests that one handler serves all methods of its route
+#[tokio::test]
+pub async fn test_multiple_methods() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::put("/tasks").call(&r).await
+        .assert_status(202)
+    ;
+
+    Req::patch("/tasks").call(&r).await
+        .assert_status(202)
+    ;
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(405)
+    ;
+}
+
+/// Tests that each method of a multi-method route is checked for shadowing
+#[test]
+pub fn test_multiple_methods_shadowing() {
+    // axum itself refuses overlapping routes on merge, so the shadowing route is documented only
+    let patch_task = PathItemBuilder::new()
+        .operation(HttpMethod::Patch, OperationBuilder::new().operation_id(Some("patchTask")).build())
+        .build();
+
+    let shadowing = GroomRouter::from_controller_parts(
+        axum::Router::new(),
+        ComponentsRegistry::new(),
+        vec![("/tasks".to_owned(), patch_task)],
+    );
+
+    let result = controller::into_router()
+        .merge(shadowing)
+        .unwrap()
+        .validate();
+
+    assert!(matches!(
+        result,
+        Err(RouterValidationError::RouteShadow { path, method }) if path == "/tasks" && method == axum::http::Method::PATCH
+    ));
+}
+
 /// Tests that openapi definition is correctly generated
 #[test]
 pub fn test_openapi() {
@@ -184,6 +240,40 @@ pub fn test_openapi() {
                             "204":{"description":""}
                         }
                     }
+                },
+                "/tasks":{
+                    "put":{
+                        "operationId": ("updateTaskPut"),
+                        "responses":{
+                            "200":{"description":""},
+                            "201":{"description":""},
+                            "202":{"description":""},
+                            "203":{"description":""},
+                            "204":{"description":""}
+                        }
+                    },
+                    "patch":{
+                        "operationId": ("updateTaskPatch"),
+                        "responses":{
+                            "200":{"description":""},
+                            "201":{"description":""},
+                            "202":{"description":""},
+                            "203":{"description":""},
+                            "204":{"description":""}
+                        }
+                    }
+                },
+                "/tunnel":{
+                    "get":{
+                        "operationId": ("tunnel"),
+                        "responses":{
+                            "200":{"description":""},
+                            "201":{"description":""},
+                            "202":{"description":""},
+                            "203":{"description":""},
+                            "204":{"description":""}
+                        }
+                    }
                 }
             },
             "components": {},
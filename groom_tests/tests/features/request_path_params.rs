@@ -1,3 +1,4 @@
+use groom::router::{GroomRouter, RouterValidationError};
 use serde_json::json;
 
 use crate::{
@@ -50,6 +51,33 @@ use utoipa::ToSchema;
     }
 }
 
+#[Controller()]
+mod mismatched {
+    use axum::{extract::Path, response::IntoResponse};
+
+    use groom::{
+        response::Response,
+        extract::GroomExtractor
+    };
+    use groom_macros::{DTO,Response};
+
+    #[DTO(parameters)]
+    pub struct TaskParams {
+        pub task_id: i32,
+    }
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[Route(method = "get", path = "/tasks/{id}")]
+    async fn get_task(Path(params): Path<TaskParams>) -> TextResponse {
+        TextResponse::Ok(params.task_id.to_string())
+    }
+}
+
 /// Test that Path parameters are correctly read
 #[tokio::test]
 pub async fn test_path_params() {
@@ -156,3 +184,26 @@ pub fn test_openapi() {
         })
     );
 }
+
+/// Tests that path parameters missing from the template or from `Path<T>` are reported
+#[test]
+pub fn test_path_params_mismatch() {
+    match mismatched::into_router().validate().err() {
+        Some(RouterValidationError::PathParameterMismatch { path, handler, not_extracted, not_in_path }) => {
+            assert_eq!(path, "/tasks/{id}");
+            assert_eq!(handler, "get_task");
+            assert_eq!(not_extracted, ["id"]);
+            assert_eq!(not_in_path, ["task_id"]);
+        },
+        other => panic!("expected PathParameterMismatch, got {:?}", other.map(|e| e.to_string())),
+    }
+
+    let nested = GroomRouter::new().nest("/orgs/{org_id}", controller::into_router()).unwrap();
+    assert!(
+        matches!(
+            nested.validate().err(),
+            Some(RouterValidationError::PathParameterMismatch { not_extracted, .. }) if not_extracted == ["org_id"]
+        ),
+        "parameters of the nesting path are checked too"
+    );
+}
@@ -0,0 +1,616 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, ReqBody, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::{extract::{Path, Query}, response::IntoResponse};
+
+    use groom::{
+        response::Response,
+        extract::GroomExtractor
+    };
+    use groom_macros::{DTO, RequestBody, Response};
+
+    // ---
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    // ---
+
+    /// New user
+    #[DTO(request)]
+    pub struct NewUser {
+        #[validate(length(min = 1, max = 20))]
+        name: String,
+
+        #[validate(email)]
+        email: String,
+
+        #[validate(range(min = 18, max = 130))]
+        age: Option<u8>,
+
+        #[validate(non_empty)]
+        roles: Vec<String>,
+    }
+
+    #[RequestBody(format(json))]
+    pub struct CreateUser(NewUser);
+
+    #[Route(method = "post", path = "/users")]
+    async fn create_user(CreateUser(user): CreateUser) -> TextResponse {
+        TextResponse::Ok(format!("{} <{}> {:?} {}", user.name, user.email, user.age, user.roles.join(",")))
+    }
+
+    // ---
+
+    /// Rename request
+    #[RequestBody(format(json, url_encoded))]
+    pub struct Rename {
+        #[validate(pattern = "^[a-z][a-z0-9-]*$", length(max = 16))]
+        slug: String,
+    }
+
+    #[Route(method = "post", path = "/rename")]
+    async fn rename(body: Rename) -> TextResponse {
+        TextResponse::Ok(body.slug)
+    }
+
+    // ---
+
+    #[DTO(parameters)]
+    pub struct Paging {
+        #[validate(range(min = 1, max = 100))]
+        limit: Option<u32>,
+
+        #[validate(range(min = -90.0, max = 90.0))]
+        lat: Option<f64>,
+    }
+
+    #[DTO(parameters)]
+    pub struct TeamPath {
+        #[validate(length(min = 3))]
+        team: String,
+    }
+
+    #[Route(method = "get", path = "/teams/{team}/users")]
+    async fn list_users(Path(path): Path<TeamPath>, Query(paging): Query<Paging>) -> TextResponse {
+        TextResponse::Ok(format!("{} {:?}", path.team, paging.limit))
+    }
+}
+
+#[Controller()]
+mod nested_controller {
+    use axum::response::IntoResponse;
+
+    use groom::{
+        response::Response,
+        extract::GroomExtractor
+    };
+    use groom_macros::{DTO, RequestBody, Response};
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[DTO(request)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Address {
+        #[validate(length(min = 1))]
+        street_name: String,
+
+        #[serde(rename = "zip")]
+        #[validate(pattern = "^[0-9]{5}$")]
+        postal_code: String,
+    }
+
+    #[DTO(request)]
+    #[serde(rename_all = "camelCase")]
+    pub struct NewOrder {
+        #[validate(nested)]
+        billing_address: Address,
+
+        #[validate(nested)]
+        shipping_address: Option<Address>,
+
+        #[validate(nested, length(max = 2))]
+        gift_addresses: Vec<Address>,
+    }
+
+    #[RequestBody(format(json))]
+    pub struct CreateOrder(NewOrder);
+
+    #[Route(method = "post", path = "/orders")]
+    async fn create_order(CreateOrder(order): CreateOrder) -> TextResponse {
+        let addresses = [&order.billing_address].into_iter()
+            .chain(order.shipping_address.as_ref())
+            .chain(&order.gift_addresses)
+            .map(|address| format!("{} {}", address.street_name, address.postal_code))
+            .collect::<Vec<_>>();
+
+        TextResponse::Ok(addresses.join(", "))
+    }
+}
+
+/// Tests that a valid wrapped DTO reaches the handler
+#[tokio::test]
+pub async fn test_valid_body() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/users")
+        .with_body(ReqBody::new(r#"{"name": "Ann", "email": "ann@example.com", "age": 30, "roles": ["admin"]}"#).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("Ann <ann@example.com> Some(30) admin")
+    ;
+}
+
+/// Tests that every violated constraint of a DTO is reported
+#[tokio::test]
+pub async fn test_invalid_body() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let result = Req::post("/users")
+        .with_body(ReqBody::new(r#"{"name": "", "email": "ann", "age": 12, "roles": []}"#).with_content_type("application/json"))
+        .call(&r)
+        .await;
+
+    result
+        .assert_status(422)
+        .assert_content_type("application/json")
+    ;
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&result.body).unwrap(),
+        json!({
+            "errors": [
+                {"field": "name", "code": "length", "message": "length must be at least 1"},
+                {"field": "email", "code": "email", "message": "must be an email address"},
+                {"field": "age", "code": "range", "message": "must be at least 18"},
+                {"field": "roles", "code": "non_empty", "message": "must not be empty"},
+            ]
+        })
+    );
+}
+
+/// Tests constraints on a named RequestBody in every format
+#[tokio::test]
+pub async fn test_named_request_body() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/rename")
+        .with_body(ReqBody::new("slug=my-app").with_content_type("application/x-www-form-urlencoded"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("my-app")
+    ;
+
+    Req::post("/rename")
+        .with_body(ReqBody::new(r#"{"slug": "My App"}"#).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(422)
+        .assert_body(r#"{"errors":[{"field":"slug","code":"pattern","message":"must match `^[a-z][a-z0-9-]*$`"}]}"#)
+    ;
+}
+
+/// Tests that errors name fields as serde does and that nested DTOs are checked
+#[tokio::test]
+pub async fn test_nested_and_renamed_fields() {
+    let r = nested_controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/orders")
+        .with_body(ReqBody::new(r#"{"billingAddress": {"streetName": "Main St", "zip": "12345"}, "giftAddresses": []}"#).with_content_type("application/json"))
+        .call(&r)
+        .await
+        .assert_status(200)
+        .assert_body("Main St 12345")
+    ;
+
+    let result = Req::post("/orders")
+        .with_body(ReqBody::new(concat!(
+            r#"{"billingAddress": {"streetName": "", "zip": "12345"}, "#,
+            r#""shippingAddress": {"streetName": "Main St", "zip": "1234"}, "#,
+            r#""giftAddresses": [{"streetName": "Side St", "zip": "12345"}, {"streetName": "", "zip": "x"}]}"#,
+        )).with_content_type("application/json"))
+        .call(&r)
+        .await;
+
+    result.assert_status(422);
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&result.body).unwrap(),
+        json!({
+            "errors": [
+                {"field": "billingAddress.streetName", "code": "length", "message": "length must be at least 1"},
+                {"field": "shippingAddress.zip", "code": "pattern", "message": "must match `^[0-9]{5}$`"},
+                {"field": "giftAddresses[1].streetName", "code": "length", "message": "length must be at least 1"},
+                {"field": "giftAddresses[1].zip", "code": "pattern", "message": "must match `^[0-9]{5}$`"},
+            ]
+        })
+    );
+}
+
+/// Tests constraints on Path and Query parameters
+#[tokio::test]
+pub async fn test_parameters() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/teams/core/users?limit=10").call(&r).await
+        .assert_status(200)
+        .assert_body("core Some(10)")
+    ;
+
+    Req::get("/teams/ab/users?limit=500&lat=-91.5").call(&r).await
+        .assert_status(422)
+        .assert_body(concat!(
+            r#"{"errors":[{"field":"team","code":"length","message":"length must be at least 3"},"#,
+            r#"{"field":"limit","code":"range","message":"must be at most 100"},"#,
+            r#"{"field":"lat","code":"range","message":"must be at least -90"}]}"#,
+        ))
+    ;
+}
+
+/// Tests that constraints are documented in the OpenAPI schema
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "FieldError": {
+                        "description": "A constraint violated by a single field.",
+                        "properties": {
+                            "code": {
+                                "description": "Name of the violated constraint (`length`, `range`, `pattern`, `email` or `non_empty`).",
+                                "type": "string",
+                            },
+                            "field": {
+                                "description": "Name of the field as it is deserialized; fields of nested DTOs are prefixed with the\nname of their parent (`address.city`, `items[0].name`).",
+                                "type": "string",
+                            },
+                            "message": {
+                                "type": "string",
+                            },
+                        },
+                        "required": ["field", "code", "message"],
+                        "type": "object",
+                    },
+                    "NewUser": {
+                        "description": "New user",
+                        "properties": {
+                            "age": {
+                                "format": "int32",
+                                "maximum": 130,
+                                "minimum": 18,
+                                "type": ["integer", "null"],
+                            },
+                            "email": {
+                                "format": "email",
+                                "type": "string",
+                            },
+                            "name": {
+                                "maxLength": 20,
+                                "minLength": 1,
+                                "type": "string",
+                            },
+                            "roles": {
+                                "items": {
+                                    "type": "string",
+                                },
+                                "minItems": 1,
+                                "type": "array",
+                            },
+                        },
+                        "required": ["name", "email", "roles"],
+                        "type": "object",
+                    },
+                    "Rename": {
+                        "description": "Rename request",
+                        "properties": {
+                            "slug": {
+                                "maxLength": 16,
+                                "pattern": "^[a-z][a-z0-9-]*$",
+                                "type": "string",
+                            },
+                        },
+                        "required": ["slug"],
+                        "type": "object",
+                    },
+                    "ValidationErrors": {
+                        "description": "All constraint violations found in a request, answered as\n`422 {\"errors\": [{\"field\": ..., \"code\": ..., \"message\": ...}]}`.",
+                        "properties": {
+                            "errors": {
+                                "items": {
+                                    "$ref": "#/components/schemas/FieldError",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": ["errors"],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/rename": {
+                    "post": {
+                        "operationId": "rename",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/Rename",
+                                    },
+                                },
+                                "application/x-www-form-urlencoded": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/Rename",
+                                    },
+                                },
+                            },
+                            "description": "Rename request",
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body is malformed",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/ValidationErrors",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema. The request does not satisfy the constraints of its fields",
+                            },
+                        },
+                    },
+                },
+                "/teams/{team}/users": {
+                    "get": {
+                        "operationId": "listUsers",
+                        "parameters": [
+                            {
+                                "in": "path",
+                                "name": "team",
+                                "required": true,
+                                "schema": {
+                                    "minLength": 3,
+                                    "type": "string",
+                                },
+                            },
+                            {
+                                "in": "query",
+                                "name": "limit",
+                                "required": false,
+                                "schema": {
+                                    "format": "int32",
+                                    "maximum": 100,
+                                    "minimum": 1,
+                                    "type": ["integer", "null"],
+                                },
+                            },
+                            {
+                                "in": "query",
+                                "name": "lat",
+                                "required": false,
+                                "schema": {
+                                    "format": "double",
+                                    "maximum": 90,
+                                    "minimum": -90,
+                                    "type": ["number", "null"],
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/ValidationErrors",
+                                        },
+                                    },
+                                },
+                                "description": "The request does not satisfy the constraints of its fields",
+                            },
+                        },
+                    },
+                },
+                "/users": {
+                    "post": {
+                        "operationId": "createUser",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/NewUser",
+                                    },
+                                },
+                            },
+                            "required": true,
+                            "x-content-encoding": [
+                                "gzip",
+                                "deflate",
+                                "br",
+                                "zstd",
+                            ],
+                        },
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "400": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body is malformed",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "413": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The decoded request body is larger than 2MiB",
+                            },
+                            "415": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body content type or content encoding is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/ValidationErrors",
+                                        },
+                                    },
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The request body does not match the expected schema. The request does not satisfy the constraints of its fields",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
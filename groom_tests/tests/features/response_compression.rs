@@ -0,0 +1,598 @@
+use std::io::Read;
+
+use groom::response::Compression;
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::{DTO, Response};
+
+    /// Report of a board
+    #[DTO(response)]
+    pub struct Report {
+        pub text: String,
+    }
+
+    #[Response(format(json), compress)]
+    pub enum ReportResponse {
+        /// Report
+        #[Response()]
+        Ok(Report),
+
+        /// No such board
+        #[Response(code = 404)]
+        NotFound,
+    }
+
+    #[Route(method = "get", path = "/report")]
+    async fn report() -> ReportResponse {
+        ReportResponse::Ok(Report { text: "done ".repeat(500) })
+    }
+
+    #[Route(method = "get", path = "/missing-report")]
+    async fn missing_report() -> ReportResponse {
+        ReportResponse::NotFound
+    }
+
+    #[Route(method = "get", path = "/short-report")]
+    async fn short_report() -> ReportResponse {
+        ReportResponse::Ok(Report { text: "done".into() })
+    }
+
+    /// Greeting
+    #[Response(format(plain_text), compress(min_size = "16B"))]
+    pub struct Greeting(String);
+
+    #[Route(method = "get", path = "/greeting")]
+    async fn greeting() -> Greeting {
+        Greeting("Hello, world! Hello, world!".into())
+    }
+}
+
+#[Controller()]
+mod layered {
+    use axum::{body::Bytes, response::IntoResponse};
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response(format(plain_text))]
+    pub enum TextResponse {
+        /// Text
+        #[Response()]
+        Ok(String),
+    }
+
+    #[Route(method = "get", path = "/text")]
+    async fn text() -> TextResponse {
+        TextResponse::Ok("lorem ipsum ".repeat(10))
+    }
+
+    #[Response(format(binary = "image/png"))]
+    pub enum ImageResponse {
+        /// Image
+        #[Response()]
+        Ok(Bytes),
+    }
+
+    #[Route(method = "get", path = "/image")]
+    async fn image() -> ImageResponse {
+        ImageResponse::Ok(Bytes::from(vec![7; 200]))
+    }
+}
+
+#[Controller()]
+mod binary {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::{DTO, Response};
+
+    #[DTO(response)]
+    pub struct Numbers {
+        pub values: Vec<u32>,
+    }
+
+    #[Response(format(cbor), compress(min_size = "16B", binary_formats))]
+    pub struct NumbersResponse(Numbers);
+
+    #[Route(method = "get", path = "/numbers")]
+    async fn numbers() -> NumbersResponse {
+        NumbersResponse(Numbers { values: vec![7; 200] })
+    }
+}
+
+fn decode(coding: &str, body: &[u8]) -> String {
+    let mut decoded = String::new();
+
+    match coding {
+        "gzip" => flate2::read::GzDecoder::new(body).read_to_string(&mut decoded).unwrap(),
+        "deflate" => flate2::read::ZlibDecoder::new(body).read_to_string(&mut decoded).unwrap(),
+        "br" => brotli::Decompressor::new(body, 4096).read_to_string(&mut decoded).unwrap(),
+        "zstd" => zstd::Decoder::new(body).unwrap().read_to_string(&mut decoded).unwrap(),
+        _ => unreachable!(),
+    };
+
+    decoded
+}
+
+/// Tests that `#[Response(compress)]` bodies are compressed with the negotiated coding
+#[tokio::test]
+pub async fn test_compress() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+    let report = format!("{{\"text\":\"{}\"}}", "done ".repeat(500));
+
+    for coding in ["gzip", "deflate", "br", "zstd"] {
+        let response = Req::get("/report").with_headers([("accept-encoding", coding)]).call(&r).await;
+
+        response
+            .assert_status(200)
+            .assert_content_type("application/json")
+            .assert_header("content-encoding", coding)
+            .assert_header("vary", "accept-encoding")
+        ;
+
+        assert!(response.bytes.len() < report.len());
+        assert_eq!(decode(coding, &response.bytes), report);
+    }
+
+    Req::get("/report").with_headers([("accept-encoding", "gzip;q=0.5, br")]).call(&r).await
+        .assert_header("content-encoding", "br")
+    ;
+
+    Req::get("/greeting").with_headers([("accept-encoding", "gzip")]).call(&r).await
+        .assert_status(200)
+        .assert_header("content-encoding", "gzip")
+    ;
+}
+
+/// Tests that bodies encoded on the blocking pool are compressed as well
+#[tokio::test]
+pub async fn test_compress_large_body() {
+    use axum::response::IntoResponse;
+
+    let text = "lorem ipsum ".repeat(10_000);
+
+    for coding in ["gzip", "deflate", "br", "zstd"] {
+        let response = Compression::new().compress(Some(coding), text.clone().into_response()).await;
+
+        assert_eq!(response.headers()["content-encoding"], coding);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(bytes.len() < text.len());
+        assert_eq!(decode(coding, &bytes), text);
+    }
+}
+
+/// Tests that `Cache-Control: no-transform` and non-text content types are not compressed, and
+/// binary formats only when enabled
+#[tokio::test]
+pub async fn test_uncompressed_types() {
+    use axum::{http::header::{CACHE_CONTROL, CONTENT_TYPE}, response::IntoResponse};
+
+    let text = "lorem ipsum ".repeat(200);
+
+    let response = Compression::new().compress(Some("gzip"), ([(CACHE_CONTROL, "no-transform")], text.clone()).into_response()).await;
+    assert!(!response.headers().contains_key("content-encoding"));
+
+    for content_type in ["application/octet-stream", "application/pdf", "application/cbor"] {
+        let response = Compression::new().compress(Some("gzip"), ([(CONTENT_TYPE, content_type)], text.clone()).into_response()).await;
+        assert!(!response.headers().contains_key("content-encoding"), "{content_type}");
+    }
+
+    let response = Compression::new().binary_formats(true)
+        .compress(Some("gzip"), ([(CONTENT_TYPE, "application/cbor")], text.clone()).into_response()).await;
+    assert_eq!(response.headers()["content-encoding"], "gzip");
+
+    let r = binary::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/numbers").with_headers([("accept-encoding", "gzip")]).call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/cbor")
+        .assert_header("content-encoding", "gzip")
+    ;
+}
+
+/// Tests responses that are not compressed
+#[tokio::test]
+pub async fn test_uncompressed() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let response = Req::get("/report").call(&r).await;
+    response
+        .assert_status(200)
+        .assert_header("vary", "accept-encoding")
+        .assert_body(format!("{{\"text\":\"{}\"}}", "done ".repeat(500)))
+    ;
+    assert!(!response.headers.contains_key("content-encoding"));
+
+    let response = Req::get("/report").with_headers([("accept-encoding", "compress, identity")]).call(&r).await;
+    assert!(!response.headers.contains_key("content-encoding"));
+
+    let response = Req::get("/short-report").with_headers([("accept-encoding", "gzip")]).call(&r).await;
+    response
+        .assert_status(200)
+        .assert_body("{\"text\":\"done\"}")
+    ;
+    assert!(!response.headers.contains_key("content-encoding"));
+
+    let response = Req::get("/missing-report").with_headers([("accept-encoding", "gzip")]).call(&r).await;
+    response
+        .assert_status(404)
+        .assert_no_body()
+    ;
+    assert!(!response.headers.contains_key("vary"));
+}
+
+/// Tests `Compression` as a router spec layer
+#[tokio::test]
+pub async fn test_router_layer() {
+    let r = layered::into_router()
+        .layer_with_spec(Compression::new().min_size(64))
+        .validate().unwrap()
+        .to_axum_router();
+
+    let response = Req::get("/text").with_headers([("accept-encoding", "gzip")]).call(&r).await;
+    response
+        .assert_status(200)
+        .assert_header("content-encoding", "gzip")
+        .assert_header("vary", "accept-encoding")
+    ;
+    assert_eq!(decode("gzip", &response.bytes), "lorem ipsum ".repeat(10));
+
+    // already compressed formats are sent as is
+    let response = Req::get("/image").with_headers([("accept-encoding", "gzip")]).call(&r).await;
+    response
+        .assert_status(200)
+        .assert_body_bytes([7; 200])
+    ;
+    assert!(!response.headers.contains_key("content-encoding"));
+    assert!(!response.headers.contains_key("vary"));
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "Report": {
+                        "description": "Report of a board",
+                        "properties": {
+                            "text": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "text",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/greeting": {
+                    "get": {
+                        "operationId": "greeting",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Greeting",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 16 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/missing-report": {
+                    "get": {
+                        "operationId": "missingReport",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Report",
+                                        },
+                                    },
+                                },
+                                "description": "Report",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 1024 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/report": {
+                    "get": {
+                        "operationId": "report",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Report",
+                                        },
+                                    },
+                                },
+                                "description": "Report",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 1024 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/short-report": {
+                    "get": {
+                        "operationId": "shortReport",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Report",
+                                        },
+                                    },
+                                },
+                                "description": "Report",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 1024 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
+
+#[test]
+pub fn test_openapi_router_layer() {
+    assert_openapi_doc(
+        |api| layered::into_router()
+            .layer_with_spec(Compression::new().min_size(64))
+            .validate().unwrap()
+            .to_openapi(api),
+        json!({
+            "components": {
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/image": {
+                    "get": {
+                        "operationId": "image",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "image/png": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Image",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 64 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/text": {
+                    "get": {
+                        "operationId": "text",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Text",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 64 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                                "headers": {
+                                    "Content-Encoding": {
+                                        "description": "Compression of the body, negotiated from `Accept-Encoding` for bodies of at least 64 bytes",
+                                        "schema": {
+                                            "enum": [
+                                                "zstd",
+                                                "br",
+                                                "gzip",
+                                                "deflate",
+                                            ],
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
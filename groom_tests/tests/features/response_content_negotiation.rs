@@ -22,7 +22,6 @@ mod controller {
     };
     use groom_macros::{DTO,Response};
 
-    use utoipa::PartialSchema;
 
     // ---
 
@@ -34,11 +33,11 @@ mod controller {
 
     impl DataObject {
         pub fn default_json_str() -> &'static str {
-            concat!("{\"status\":\"ok\",\"status_timestamp\":1726070400}")
+            "{\"status\":\"ok\",\"status_timestamp\":1726070400}"
         }
 
         pub fn default_html_str() -> &'static str {
-            concat!("status: <b>ok</b> (since 1726070400)")
+            "status: <b>ok</b> (since 1726070400)"
         }
     }
 
@@ -330,9 +329,9 @@ mod weights_controller {
     #[DTO(response)]
     pub struct PageData(String);
 
-    impl Into<String> for PageData {
-        fn into(self) -> String {
-            self.0
+    impl From<PageData> for String {
+        fn from(val: PageData) -> Self {
+            val.0
         }
     }
     html_format!(PageData, self {
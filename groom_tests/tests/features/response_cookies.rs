@@ -0,0 +1,325 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::{extract::GroomExtractor, response::Response};
+    use groom_macros::{DTO, Response};
+
+    #[Response()]
+    pub enum LoginResponse {
+        /// Logged in
+        #[Response(code = 204)]
+        LoggedIn {
+            /// Session token
+            #[Response(cookie(name = "session", path = "/", http_only, secure, same_site = "strict", max_age = 3600))]
+            session: String,
+
+            #[Response(cookie(name = "theme", same_site = "lax"))]
+            theme: Option<&'static str>,
+        },
+
+        /// Logged out
+        #[Response(code = 205)]
+        LoggedOut(
+            #[Response(cookie(name = "session", path = "/", max_age = 0))]
+            &'static str,
+        ),
+    }
+
+    #[Route(method = "post", path = "/login")]
+    async fn login() -> LoginResponse {
+        LoginResponse::LoggedIn { session: "abc123".into(), theme: Some("dark") }
+    }
+
+    #[Route(method = "post", path = "/logout")]
+    async fn logout() -> LoginResponse {
+        LoginResponse::LoggedOut("")
+    }
+
+    #[Route(method = "post", path = "/login-broken")]
+    async fn login_broken() -> LoginResponse {
+        LoginResponse::LoggedIn { session: "abc; Domain=example.com".into(), theme: None }
+    }
+
+    #[DTO(cookies)]
+    pub struct Session {
+        /// Session token
+        #[validate(length(min = 6))]
+        pub session: String,
+
+        pub theme: Option<String>,
+    }
+
+    #[Response(format(plain_text))]
+    pub enum AdminResponse {
+        #[Response()]
+        Ok(String),
+    }
+
+    #[Route(method = "get", path = "/admin")]
+    async fn admin(session: Session) -> AdminResponse {
+        AdminResponse::Ok(format!("{} {}", session.session, session.theme.unwrap_or_default()))
+    }
+}
+
+/// Tests that cookie fields are sent as separate `Set-Cookie` headers
+#[tokio::test]
+pub async fn test_set_cookies() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let response = Req::post("/login").call(&r).await;
+    response.assert_status(204).assert_no_body();
+
+    let cookies: Vec<_> = response.headers.get_all("set-cookie").iter().collect();
+    assert_eq!(cookies, [
+        "session=abc123; Path=/; Max-Age=3600; Secure; HttpOnly; SameSite=Strict",
+        "theme=dark; SameSite=Lax",
+    ]);
+
+    Req::post("/logout").call(&r).await
+        .assert_status(205)
+        .assert_header("set-cookie", "session=; Path=/; Max-Age=0")
+    ;
+}
+
+/// Tests that a cookie value can't add attributes to the cookie
+#[tokio::test]
+pub async fn test_invalid_cookie_value() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let response = Req::post("/login-broken").call(&r).await;
+    response.assert_status(500);
+    assert!(!response.headers.contains_key("set-cookie"));
+}
+
+/// Tests `#[DTO(cookies)]` extractors
+#[tokio::test]
+pub async fn test_cookies_extractor() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/admin").with_headers([("cookie", "theme=dark; session=abc123")]).call(&r).await
+        .assert_status(200)
+        .assert_body("abc123 dark")
+    ;
+
+    Req::get("/admin").with_headers([("cookie", "theme=dark")]).call(&r).await
+        .assert_status(400)
+    ;
+
+    Req::get("/admin").with_headers([("cookie", "session=abc")]).call(&r).await
+        .assert_status(422)
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "FieldError": {
+                        "description": "A constraint violated by a single field.",
+                        "properties": {
+                            "code": {
+                                "description": "Name of the violated constraint (`length`, `range`, `pattern`, `email` or `non_empty`).",
+                                "type": "string",
+                            },
+                            "field": {
+                                "description": "Name of the field as it is deserialized; fields of nested DTOs are prefixed with the\nname of their parent (`address.city`, `items[0].name`).",
+                                "type": "string",
+                            },
+                            "message": {
+                                "type": "string",
+                            },
+                        },
+                        "required": ["field", "code", "message"],
+                        "type": "object",
+                    },
+                    "ValidationErrors": {
+                        "description": "All constraint violations found in a request, answered as\n`422 {\"errors\": [{\"field\": ..., \"code\": ..., \"message\": ...}]}`.",
+                        "properties": {
+                            "errors": {
+                                "items": {
+                                    "$ref": "#/components/schemas/FieldError",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": ["errors"],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/admin": {
+                    "get": {
+                        "operationId": "admin",
+                        "parameters": [
+                            {
+                                "description": "Session token",
+                                "in": "cookie",
+                                "name": "session",
+                                "required": true,
+                                "schema": {
+                                    "minLength": 6,
+                                    "type": "string",
+                                },
+                            },
+                            {
+                                "in": "cookie",
+                                "name": "theme",
+                                "required": false,
+                                "schema": {
+                                    "type": [
+                                        "string",
+                                        "null",
+                                    ],
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                            "422": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/ValidationErrors",
+                                        },
+                                    },
+                                },
+                                "description": "The request does not satisfy the constraints of its fields",
+                            },
+                        },
+                    },
+                },
+                "/login": {
+                    "post": {
+                        "operationId": "login",
+                        "responses": {
+                            "204": {
+                                "description": "Logged in",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=3600; Secure; HttpOnly; SameSite=Strict`: Session token\n\n`theme=...; SameSite=Lax`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "205": {
+                                "description": "Logged out",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=0`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/login-broken": {
+                    "post": {
+                        "operationId": "loginBroken",
+                        "responses": {
+                            "204": {
+                                "description": "Logged in",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=3600; Secure; HttpOnly; SameSite=Strict`: Session token\n\n`theme=...; SameSite=Lax`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "205": {
+                                "description": "Logged out",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=0`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/logout": {
+                    "post": {
+                        "operationId": "logout",
+                        "responses": {
+                            "204": {
+                                "description": "Logged in",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=3600; Secure; HttpOnly; SameSite=Strict`: Session token\n\n`theme=...; SameSite=Lax`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "205": {
+                                "description": "Logged out",
+                                "headers": {
+                                    "Set-Cookie": {
+                                        "description": "`session=...; Path=/; Max-Age=0`",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
@@ -0,0 +1,478 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::{extract::Query, http::Uri, response::IntoResponse};
+
+    use groom::{extract::GroomExtractor, response::Response};
+    use groom_macros::{DTO, Response};
+
+    /// Task
+    #[DTO(response)]
+    pub struct Task {
+        pub id: u32,
+        pub title: String,
+    }
+
+    /// Tasks of a page
+    #[DTO(response)]
+    pub struct TaskPage {
+        pub tasks: Vec<Task>,
+    }
+
+    #[DTO(parameters)]
+    pub struct PageQuery {
+        pub page: u32,
+    }
+
+    #[Response(format(json))]
+    pub enum TasksResponse {
+        /// Task is created
+        #[Response(code = 201)]
+        Created {
+            /// URL of the new task
+            #[Response(header(name = "Location"))]
+            location: String,
+
+            task: Task,
+        },
+
+        /// Page of tasks
+        #[Response(code = 200)]
+        Page(
+            /// Number of tasks on all pages
+            #[Response(header(name = "X-Total-Count"))]
+            u64,
+
+            TaskPage,
+
+            /// Next page, if any
+            #[Response(header(name = "Link"))]
+            Option<String>,
+        ),
+    }
+
+    #[Route(method = "post", path = "/tasks")]
+    async fn create_task() -> TasksResponse {
+        TasksResponse::Created {
+            location: "/tasks/7".into(),
+            task: Task { id: 7, title: "ship".into() },
+        }
+    }
+
+    #[Route(method = "get", path = "/tasks")]
+    async fn list_tasks(Query(query): Query<PageQuery>) -> TasksResponse {
+        let next = (query.page == 1).then(|| "</tasks?page=2>; rel=\"next\"".to_owned());
+
+        TasksResponse::Page(3, TaskPage { tasks: vec![Task { id: query.page, title: "ship".into() }] }, next)
+    }
+
+    #[Response()]
+    pub enum LimitedResponse {
+        /// Too many requests
+        #[Response(code = 429)]
+        TooManyRequests {
+            /// Seconds to wait
+            #[Response(header(name = "Retry-After"))]
+            retry_after: u32,
+        },
+    }
+
+    #[Route(method = "get", path = "/limited")]
+    async fn limited() -> LimitedResponse {
+        LimitedResponse::TooManyRequests { retry_after: 120 }
+    }
+
+    /// Note is created
+    #[Response(format(json), code = 201)]
+    pub struct NoteCreated {
+        /// URL of the new note
+        #[Response(header(name = "Location"))]
+        location: Uri,
+
+        id: u32,
+    }
+
+    #[Route(method = "post", path = "/notes")]
+    async fn create_note() -> NoteCreated {
+        NoteCreated { location: Uri::from_static("/notes/1"), id: 1 }
+    }
+
+    /// Moved
+    #[Response(code = 303)]
+    pub struct SeeOther {
+        #[Response(header(name = "Location"))]
+        location: String,
+    }
+
+    #[Route(method = "get", path = "/old")]
+    async fn old() -> SeeOther {
+        SeeOther { location: "/new".into() }
+    }
+
+    #[Route(method = "get", path = "/broken")]
+    async fn broken() -> SeeOther {
+        SeeOther { location: "/new\r\nSet-Cookie: a=b".into() }
+    }
+}
+
+/// Tests header fields of named and tuple variants
+#[tokio::test]
+pub async fn test_variant_headers() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/tasks").call(&r).await
+        .assert_status(201)
+        .assert_header("location", "/tasks/7")
+        .assert_content_type("application/json")
+        .assert_body("{\"id\":7,\"title\":\"ship\"}")
+    ;
+
+    Req::get("/tasks?page=1").call(&r).await
+        .assert_status(200)
+        .assert_header("x-total-count", "3")
+        .assert_header("link", "</tasks?page=2>; rel=\"next\"")
+        .assert_body("{\"tasks\":[{\"id\":1,\"title\":\"ship\"}]}")
+    ;
+
+    let last_page = Req::get("/tasks?page=2").call(&r).await;
+    last_page.assert_status(200).assert_header("x-total-count", "3");
+    assert!(!last_page.headers.contains_key("link"));
+
+    Req::get("/limited").call(&r).await
+        .assert_status(429)
+        .assert_header("retry-after", "120")
+        .assert_no_body()
+    ;
+}
+
+/// Tests that header fields of structs are not a part of the body
+#[tokio::test]
+pub async fn test_struct_headers() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::post("/notes").call(&r).await
+        .assert_status(201)
+        .assert_header("location", "/notes/1")
+        .assert_body("{\"id\":1}")
+    ;
+
+    Req::get("/old").call(&r).await
+        .assert_status(303)
+        .assert_header("location", "/new")
+        .assert_no_body()
+    ;
+}
+
+/// Tests that an invalid header value is answered with 500
+#[tokio::test]
+pub async fn test_invalid_header_value() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let response = Req::get("/broken").call(&r).await;
+    response.assert_status(500);
+    assert!(!response.headers.contains_key("location"));
+    assert!(!response.headers.contains_key("set-cookie"));
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "NoteCreated": {
+                        "description": "Note is created",
+                        "properties": {
+                            "id": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                        },
+                        "required": [
+                            "id",
+                        ],
+                        "type": "object",
+                    },
+                    "Task": {
+                        "description": "Task",
+                        "properties": {
+                            "id": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                            "title": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "id",
+                            "title",
+                        ],
+                        "type": "object",
+                    },
+                    "TaskPage": {
+                        "description": "Tasks of a page",
+                        "properties": {
+                            "tasks": {
+                                "items": {
+                                    "$ref": "#/components/schemas/Task",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": [
+                            "tasks",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/broken": {
+                    "get": {
+                        "operationId": "broken",
+                        "responses": {
+                            "303": {
+                                "description": "Moved",
+                                "headers": {
+                                    "Location": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/limited": {
+                    "get": {
+                        "operationId": "limited",
+                        "responses": {
+                            "429": {
+                                "description": "Too many requests",
+                                "headers": {
+                                    "Retry-After": {
+                                        "description": "Seconds to wait",
+                                        "schema": {
+                                            "format": "int32",
+                                            "minimum": 0,
+                                            "type": "integer",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/notes": {
+                    "post": {
+                        "operationId": "createNote",
+                        "responses": {
+                            "201": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/NoteCreated",
+                                        },
+                                    },
+                                },
+                                "description": "Note is created",
+                                "headers": {
+                                    "Location": {
+                                        "description": "URL of the new note",
+                                        "schema": {
+                                            "format": "uri-reference",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/old": {
+                    "get": {
+                        "operationId": "old",
+                        "responses": {
+                            "303": {
+                                "description": "Moved",
+                                "headers": {
+                                    "Location": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "/tasks": {
+                    "get": {
+                        "operationId": "listTasks",
+                        "parameters": [
+                            {
+                                "in": "query",
+                                "name": "page",
+                                "required": true,
+                                "schema": {
+                                    "format": "int32",
+                                    "minimum": 0,
+                                    "type": "integer",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskPage",
+                                        },
+                                    },
+                                },
+                                "description": "Page of tasks",
+                                "headers": {
+                                    "Link": {
+                                        "description": "Next page, if any",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "X-Total-Count": {
+                                        "description": "Number of tasks on all pages",
+                                        "schema": {
+                                            "format": "int64",
+                                            "minimum": 0,
+                                            "type": "integer",
+                                        },
+                                    },
+                                },
+                            },
+                            "201": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Task is created",
+                                "headers": {
+                                    "Location": {
+                                        "description": "URL of the new task",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                    "post": {
+                        "operationId": "createTask",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskPage",
+                                        },
+                                    },
+                                },
+                                "description": "Page of tasks",
+                                "headers": {
+                                    "Link": {
+                                        "description": "Next page, if any",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                    "X-Total-Count": {
+                                        "description": "Number of tasks on all pages",
+                                        "schema": {
+                                            "format": "int64",
+                                            "minimum": 0,
+                                            "type": "integer",
+                                        },
+                                    },
+                                },
+                            },
+                            "201": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Task is created",
+                                "headers": {
+                                    "Location": {
+                                        "description": "URL of the new task",
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
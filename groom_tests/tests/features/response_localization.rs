@@ -0,0 +1,332 @@
+use groom::locale::Localization;
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+const LANGUAGES: Localization = Localization::new(&["en", "de"]);
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::{extract::GroomExtractor, html_format, locale::Locale, response::Response};
+    use groom_macros::{DTO, Response};
+
+    #[Response(format(plain_text))]
+    pub enum GreetingResponse {
+        /// Greeting in the language of the client
+        #[Response()]
+        Ok(String),
+
+        /// Nothing to greet
+        #[Response(code = 204)]
+        Empty,
+    }
+
+    #[Route(method = "get", path = "/greeting")]
+    async fn greeting(locale: Locale) -> GreetingResponse {
+        match locale.as_str() {
+            "de" => GreetingResponse::Ok("Hallo".into()),
+            _ => GreetingResponse::Ok("Hello".into()),
+        }
+    }
+
+    #[Route(method = "get", path = "/empty")]
+    async fn empty() -> GreetingResponse {
+        GreetingResponse::Empty
+    }
+
+    /// Status of a board
+    #[DTO(response)]
+    pub struct Status {
+        pub open: u32,
+    }
+
+    html_format!(Status, self, ctx {
+        match ctx.locale().map(|locale| locale.as_str()) {
+            Some("de") => format!("<p>Offen: {}</p>", self.open),
+            _ => format!("<p>Open: {}</p>", self.open),
+        }
+    });
+
+    #[Response(format(html))]
+    pub enum StatusResponse {
+        /// Status
+        #[Response()]
+        Ok(Status),
+    }
+
+    #[Route(method = "get", path = "/status")]
+    async fn status() -> StatusResponse {
+        StatusResponse::Ok(Status { open: 3 })
+    }
+
+    #[Response(format(plain_text))]
+    pub struct Language(String);
+
+    #[Route(method = "get", path = "/language")]
+    async fn language(locale: Option<Locale>) -> Language {
+        Language(locale.map_or("none".into(), |locale| locale.to_string()))
+    }
+}
+
+/// Tests that handlers see the negotiated locale, and the language headers of responses
+#[tokio::test]
+pub async fn test_negotiation() {
+    let r = controller::into_router().layer_with_spec(LANGUAGES).validate().unwrap().to_axum_router();
+
+    Req::get("/greeting").with_headers([("accept-language", "de-CH, en;q=0.5")]).call(&r).await
+        .assert_status(200)
+        .assert_header("content-language", "de")
+        .assert_header("vary", "accept-language")
+        .assert_body("Hallo")
+    ;
+
+    Req::get("/greeting").with_headers([("accept-language", "fr, en-GB;q=0.8, de;q=0.5")]).call(&r).await
+        .assert_header("content-language", "en")
+        .assert_body("Hello")
+    ;
+
+    // the first language is the default
+    Req::get("/greeting").with_headers([("accept-language", "fr")]).call(&r).await
+        .assert_header("content-language", "en")
+        .assert_body("Hello")
+    ;
+
+    Req::get("/greeting").call(&r).await
+        .assert_header("content-language", "en")
+        .assert_body("Hello")
+    ;
+
+    let response = Req::get("/empty").with_headers([("accept-language", "de")]).call(&r).await;
+    response
+        .assert_status(204)
+        .assert_header("vary", "accept-language")
+    ;
+    assert!(!response.headers.contains_key("content-language"));
+}
+
+/// Tests that HTML templates are rendered in the negotiated locale
+#[tokio::test]
+pub async fn test_render_context() {
+    let r = controller::into_router().layer_with_spec(LANGUAGES).validate().unwrap().to_axum_router();
+
+    Req::get("/status").with_headers([("accept-language", "de")]).call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/html; charset=utf-8")
+        .assert_header("content-language", "de")
+        .assert_body("<p>Offen: 3</p>")
+    ;
+
+    Req::get("/status").call(&r).await
+        .assert_body("<p>Open: 3</p>")
+    ;
+}
+
+/// Tests routers without the `Localization` layer
+#[tokio::test]
+pub async fn test_without_layer() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/greeting").with_headers([("accept-language", "de")]).call(&r).await
+        .assert_status(500)
+    ;
+
+    let response = Req::get("/status").with_headers([("accept-language", "de")]).call(&r).await;
+    response
+        .assert_status(200)
+        .assert_body("<p>Open: 3</p>")
+    ;
+    assert!(!response.headers.contains_key("content-language"));
+
+    Req::get("/language").call(&r).await
+        .assert_body("none")
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().layer_with_spec(LANGUAGES).validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/empty": {
+                    "get": {
+                        "operationId": "empty",
+                        "parameters": [
+                            {
+                                "description": "Preferred languages of the response; supported: `en`, `de`",
+                                "in": "header",
+                                "name": "Accept-Language",
+                                "required": false,
+                                "schema": {
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Greeting in the language of the client",
+                            },
+                            "204": {
+                                "description": "Nothing to greet",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/greeting": {
+                    "get": {
+                        "operationId": "greeting",
+                        "parameters": [
+                            {
+                                "description": "Preferred languages of the response; supported: `en`, `de`",
+                                "in": "header",
+                                "name": "Accept-Language",
+                                "required": false,
+                                "schema": {
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Greeting in the language of the client",
+                            },
+                            "204": {
+                                "description": "Nothing to greet",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/language": {
+                    "get": {
+                        "operationId": "language",
+                        "parameters": [
+                            {
+                                "description": "Preferred languages of the response; supported: `en`, `de`",
+                                "in": "header",
+                                "name": "Accept-Language",
+                                "required": false,
+                                "schema": {
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/status": {
+                    "get": {
+                        "operationId": "status",
+                        "parameters": [
+                            {
+                                "description": "Preferred languages of the response; supported: `en`, `de`",
+                                "in": "header",
+                                "name": "Accept-Language",
+                                "required": false,
+                                "schema": {
+                                    "type": "string",
+                                },
+                            },
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/html; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Status",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
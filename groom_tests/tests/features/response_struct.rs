@@ -20,7 +20,6 @@ mod controller {
         Response
     };
 
-    use utoipa::PartialSchema; // required for String type in response
 
     /// Named struct as a response
     #[Response(format(plain_text, html, json), default_format="plain_text", code=418)]
@@ -0,0 +1,390 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::{body::Bytes, response::IntoResponse};
+
+    use groom::response::{Binary, BinaryFormat, Response};
+    use groom_macros::{DTO, Response};
+
+    #[Response(format(binary))]
+    pub enum BytesResponse {
+        /// Raw bytes
+        #[Response()]
+        Ok(Bytes),
+
+        /// Nothing to download
+        #[Response(code = 404)]
+        NotFound,
+    }
+
+    #[Route(method = "get", path = "/bytes")]
+    async fn bytes() -> BytesResponse {
+        BytesResponse::Ok(Bytes::from_static(&[0, 159, 146, 150]))
+    }
+
+    #[Route(method = "get", path = "/missing")]
+    async fn missing() -> BytesResponse {
+        BytesResponse::NotFound
+    }
+
+    #[Response(format(binary = "application/pdf"))]
+    pub enum ReportResponse {
+        /// Report as PDF
+        #[Response()]
+        Ok(Binary),
+    }
+
+    #[Route(method = "get", path = "/report")]
+    async fn report() -> ReportResponse {
+        ReportResponse::Ok(Binary::new("%PDF-1.7").attachment("report 2024.pdf"))
+    }
+
+    #[Response(format(binary = "text/plain"))]
+    pub enum FileResponse {
+        /// The manifest of this crate
+        #[Response()]
+        Ok(tokio::fs::File),
+    }
+
+    #[Route(method = "get", path = "/file")]
+    async fn file() -> FileResponse {
+        FileResponse::Ok(tokio::fs::File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).await.unwrap())
+    }
+
+    /// Tasks as CSV
+    #[Response(format(binary = "text/csv"))]
+    pub struct CsvExport(Vec<u8>);
+
+    #[Route(method = "get", path = "/tasks.csv")]
+    async fn csv() -> CsvExport {
+        CsvExport(b"title,done\nship,true\n".to_vec())
+    }
+
+    /// Invoice
+    #[DTO(response)]
+    pub struct Invoice {
+        pub number: u32,
+    }
+
+    impl BinaryFormat for Invoice {
+        fn into_binary(self) -> Binary {
+            Binary::new(format!("%PDF invoice {}", self.number)).inline(format!("invoice-{}.pdf", self.number))
+        }
+    }
+
+    #[Response(format(json, binary = "application/pdf"), default_format = "json")]
+    pub enum InvoiceResponse {
+        /// Invoice
+        #[Response()]
+        Ok(Invoice),
+    }
+
+    #[Route(method = "get", path = "/invoice")]
+    async fn invoice() -> InvoiceResponse {
+        InvoiceResponse::Ok(Invoice { number: 42 })
+    }
+}
+
+/// Tests that bytes are sent as `application/octet-stream` by default
+#[tokio::test]
+pub async fn test_bytes() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/bytes").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/octet-stream")
+        .assert_body_bytes([0, 159, 146, 150])
+    ;
+
+    Req::get("/missing").call(&r).await
+        .assert_status(404)
+        .assert_no_body()
+    ;
+}
+
+/// Tests custom mime and `Content-Disposition`
+#[tokio::test]
+pub async fn test_attachment() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/report").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/pdf")
+        .assert_header("content-disposition", "attachment; filename=\"report 2024.pdf\"")
+        .assert_body("%PDF-1.7")
+    ;
+
+    Req::get("/report").accept("text/html").call(&r).await
+        .assert_status(406)
+    ;
+}
+
+/// Tests that files are streamed
+#[tokio::test]
+pub async fn test_file() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/file").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/plain")
+        .assert_body(std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap())
+    ;
+}
+
+/// Tests binary tuple structs
+#[tokio::test]
+pub async fn test_struct() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/tasks.csv").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/csv")
+        .assert_body("title,done\nship,true\n")
+    ;
+}
+
+/// Tests negotiation between a serialized and a binary representation
+#[tokio::test]
+pub async fn test_negotiation() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/invoice").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/json")
+        .assert_body("{\"number\":42}")
+    ;
+
+    Req::get("/invoice").accept("application/pdf").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/pdf")
+        .assert_header("content-disposition", "inline; filename=\"invoice-42.pdf\"")
+        .assert_body("%PDF invoice 42")
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "Invoice": {
+                        "description": "Invoice",
+                        "properties": {
+                            "number": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                        },
+                        "required": [
+                            "number",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/bytes": {
+                    "get": {
+                        "operationId": "bytes",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/octet-stream": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Raw bytes",
+                            },
+                            "404": {
+                                "description": "Nothing to download",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/file": {
+                    "get": {
+                        "operationId": "file",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/plain": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The manifest of this crate",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/invoice": {
+                    "get": {
+                        "operationId": "invoice",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Invoice",
+                                        },
+                                    },
+                                    "application/pdf": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Invoice",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/missing": {
+                    "get": {
+                        "operationId": "missing",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/octet-stream": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Raw bytes",
+                            },
+                            "404": {
+                                "description": "Nothing to download",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/report": {
+                    "get": {
+                        "operationId": "report",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/pdf": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Report as PDF",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/tasks.csv": {
+                    "get": {
+                        "operationId": "csv",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/csv": {
+                                        "schema": {
+                                            "format": "binary",
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Tasks as CSV",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
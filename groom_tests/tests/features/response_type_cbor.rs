@@ -0,0 +1,192 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::{
+        DTO,
+        Response
+    };
+
+
+    // ---
+
+    #[DTO(response)]
+    pub struct DataObject {
+        pub status: &'static str,
+        pub status_timestamp: Option<u64>,
+    }
+
+    // ---
+
+    #[Response(format(cbor))]
+    pub enum CborStructResponse {
+        #[Response()]
+        Ok(DataObject),
+    }
+
+    #[Route(method="get", path="/cbor_struct")]
+    pub async fn cbor_struct() -> CborStructResponse {
+        CborStructResponse::Ok(DataObject { status: "ok", status_timestamp: Some(1726070400) })
+    }
+
+    // ---
+
+    /// Status in either format
+    #[Response(format(json, cbor), default_format = "json")]
+    pub struct Status {
+        pub status: &'static str,
+    }
+
+    #[Route(method="get", path="/status")]
+    pub async fn status() -> Status {
+        Status { status: "ok" }
+    }
+
+    // ---
+}
+
+fn decode(bytes: &[u8]) -> serde_json::Value {
+    ciborium::from_reader(bytes).expect("expected a valid cbor body")
+}
+
+/// Tests that cbor struct is returned correctly
+#[tokio::test]
+pub async fn cbor_struct() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    let result = Req::get("/cbor_struct").call(&r).await;
+
+    result
+        .assert_status(200)
+        .assert_content_type("application/cbor")
+    ;
+
+    assert_eq!(decode(&result.bytes), json!({"status": "ok", "status_timestamp": 1726070400}));
+}
+
+/// Tests that cbor is negotiated through `Accept`
+#[tokio::test]
+pub async fn cbor_negotiation() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/status").call(&r).await
+        .assert_status(200)
+        .assert_body("{\"status\":\"ok\"}")
+        .assert_content_type("application/json")
+    ;
+
+    let result = Req::get("/status").accept("application/cbor").call(&r).await;
+
+    result
+        .assert_status(200)
+        .assert_content_type("application/cbor")
+    ;
+
+    assert_eq!(decode(&result.bytes), json!({"status": "ok"}));
+
+    Req::get("/status").accept("text/html").call(&r).await
+        .assert_status(406)
+        .assert_body("Supported content types: application/json, application/cbor")
+    ;
+}
+
+/// Tests that openapi definition is correctly generated
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "info": {
+                "contact": {"email": "mail@example.com","name": "name"},
+                "description": "d",
+                "license": {"name": "n"},
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "components": {
+                "schemas": {
+                    "DataObject": {
+                        "properties": {
+                            "status": {"type": "string"},
+                            "status_timestamp": {
+                                "format": "int64",
+                                "minimum": 0,
+                                "type": ["integer", "null"],
+                            },
+                        },
+                        "required": ["status"],
+                        "type": "object",
+                    },
+                    "Status": {
+                        "description": "Status in either format",
+                        "properties": {
+                            "status": {"type": "string"},
+                        },
+                        "required": ["status"],
+                        "type": "object",
+                    },
+                },
+            },
+            "paths": {
+                "/cbor_struct": {
+                    "get": {
+                        "operationId": "cborStruct",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/cbor": {
+                                        "schema": {"$ref": "#/components/schemas/DataObject"},
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "406": {
+                            "content": {
+                                "text/plain; charset=utf-8": {
+                                    "schema": {"type": "string"},
+                                },
+                            },
+                            "description": "The requested content type is not supported",
+                        },
+                        },
+                    },
+                },
+                "/status": {
+                    "get": {
+                        "operationId": "status",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"$ref": "#/components/schemas/Status"},
+                                    },
+                                    "application/cbor": {
+                                        "schema": {"$ref": "#/components/schemas/Status"},
+                                    },
+                                },
+                                "description": "Status in either format",
+                            },
+                            "406": {
+                            "content": {
+                                "text/plain; charset=utf-8": {
+                                    "schema": {"type": "string"},
+                                },
+                            },
+                            "description": "The requested content type is not supported",
+                        },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
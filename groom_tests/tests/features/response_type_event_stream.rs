@@ -0,0 +1,321 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use std::time::Duration;
+
+    use axum::response::IntoResponse;
+    use futures_util::stream;
+
+    use groom::response::{Event, EventStreamFormat, Response, Sse};
+    use groom_macros::{DTO, Response};
+
+    /// Change of a task
+    #[DTO(response)]
+    pub struct TaskEvent {
+        pub id: u32,
+        pub title: String,
+    }
+
+    #[Response(format(event_stream))]
+    pub enum TaskEventsResponse {
+        /// Changes of tasks
+        #[Response()]
+        Ok(Sse<TaskEvent>),
+
+        /// No such board
+        #[Response(code = 404)]
+        NotFound,
+    }
+
+    #[Route(method = "get", path = "/events")]
+    async fn events() -> TaskEventsResponse {
+        TaskEventsResponse::Ok(Sse::new(stream::iter([
+            Event::new(TaskEvent { id: 1, title: "ship".into() })
+                .event("created")
+                .id("1")
+                .retry(Duration::from_secs(3)),
+            TaskEvent { id: 1, title: "ship it".into() }.into(),
+        ])))
+    }
+
+    #[Route(method = "get", path = "/missing")]
+    async fn missing() -> TaskEventsResponse {
+        TaskEventsResponse::NotFound
+    }
+
+    /// Tasks of a board
+    #[DTO(response)]
+    pub struct TaskList {
+        pub tasks: Vec<TaskEvent>,
+    }
+
+    impl EventStreamFormat for TaskList {
+        type Data = TaskEvent;
+
+        fn into_event_stream(self) -> axum::response::Response {
+            Sse::new(stream::iter(self.tasks.into_iter().map(Event::from))).into_event_stream()
+        }
+    }
+
+    #[Response(format(json, event_stream), default_format = "json")]
+    pub enum TasksResponse {
+        /// Tasks of a board
+        #[Response()]
+        Ok(TaskList),
+    }
+
+    #[Route(method = "get", path = "/tasks")]
+    async fn tasks() -> TasksResponse {
+        TasksResponse::Ok(TaskList { tasks: vec![TaskEvent { id: 2, title: "test".into() }] })
+    }
+
+    /// Log lines
+    #[Response(format(event_stream))]
+    pub struct LogStream(Sse<String>);
+
+    #[Route(method = "get", path = "/log")]
+    async fn log() -> LogStream {
+        LogStream(Sse::new(stream::iter([Event::new("started".to_owned()).event("log")])))
+    }
+}
+
+/// Tests that events are sent with their name, id and retry
+#[tokio::test]
+pub async fn test_events() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/events").accept("text/event-stream").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/event-stream")
+        .assert_header("cache-control", "no-cache")
+        .assert_body(concat!(
+            "event: created\nid: 1\nretry: 3000\ndata: {\"id\":1,\"title\":\"ship\"}\n\n",
+            "data: {\"id\":1,\"title\":\"ship it\"}\n\n",
+        ))
+    ;
+
+    Req::get("/missing").call(&r).await
+        .assert_status(404)
+        .assert_no_body()
+    ;
+}
+
+/// Tests negotiation between JSON and an event stream
+#[tokio::test]
+pub async fn test_negotiation() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/json")
+        .assert_body("{\"tasks\":[{\"id\":2,\"title\":\"test\"}]}")
+    ;
+
+    Req::get("/tasks").accept("text/event-stream").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/event-stream")
+        .assert_body("data: {\"id\":2,\"title\":\"test\"}\n\n")
+    ;
+
+    Req::get("/tasks").accept("text/html").call(&r).await
+        .assert_status(406)
+    ;
+}
+
+/// Tests event stream tuple structs
+#[tokio::test]
+pub async fn test_struct() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/log").call(&r).await
+        .assert_status(200)
+        .assert_content_type("text/event-stream")
+        .assert_body("event: log\ndata: \"started\"\n\n")
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "TaskEvent": {
+                        "description": "Change of a task",
+                        "properties": {
+                            "id": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                            "title": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "id",
+                            "title",
+                        ],
+                        "type": "object",
+                    },
+                    "TaskList": {
+                        "description": "Tasks of a board",
+                        "properties": {
+                            "tasks": {
+                                "items": {
+                                    "$ref": "#/components/schemas/TaskEvent",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": [
+                            "tasks",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/events": {
+                    "get": {
+                        "operationId": "events",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/event-stream": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskEvent",
+                                        },
+                                    },
+                                },
+                                "description": "Changes of tasks",
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/log": {
+                    "get": {
+                        "operationId": "log",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/event-stream": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Log lines",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/missing": {
+                    "get": {
+                        "operationId": "missing",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "text/event-stream": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskEvent",
+                                        },
+                                    },
+                                },
+                                "description": "Changes of tasks",
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/tasks": {
+                    "get": {
+                        "operationId": "tasks",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskList",
+                                        },
+                                    },
+                                    "text/event-stream": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskEvent",
+                                        },
+                                    },
+                                },
+                                "description": "Tasks of a board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
@@ -15,7 +15,6 @@ mod controller {
         Response
     };
 
-    use utoipa::{PartialSchema, openapi::RefOr}; // required to send unnamed struct or enum variant with unnamed contents as JSON
 
     // ---
 
@@ -27,11 +26,11 @@ mod controller {
 
     impl DataObject {
         pub fn default_str() -> &'static str {
-            concat!("{\"status\":\"ok\",\"status_timestamp\":1726070400}")
+            "{\"status\":\"ok\",\"status_timestamp\":1726070400}"
         }
 
         pub fn unknown_time_str() -> &'static str {
-            concat!("{\"status\":\"ok\",\"status_timestamp\":null}")
+            "{\"status\":\"ok\",\"status_timestamp\":null}"
         }
 
         pub fn unknown_time() -> Self {
@@ -0,0 +1,317 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+    use futures_util::stream;
+
+    use groom::response::{JsonLines, NdJsonFormat, Response};
+    use groom_macros::{DTO, Response};
+
+    /// Task of a board
+    #[DTO(response)]
+    pub struct Task {
+        pub id: u32,
+        pub title: String,
+    }
+
+    #[Response(format(ndjson))]
+    pub enum TaskStreamResponse {
+        /// Tasks of a board, one per line
+        #[Response()]
+        Ok(JsonLines<Task>),
+
+        /// No such board
+        #[Response(code = 404)]
+        NotFound,
+    }
+
+    #[Route(method = "get", path = "/stream")]
+    async fn stream_tasks() -> TaskStreamResponse {
+        TaskStreamResponse::Ok(JsonLines::new(stream::iter([
+            Task { id: 1, title: "ship".into() },
+            Task { id: 2, title: "ship it".into() },
+        ])))
+    }
+
+    #[Route(method = "get", path = "/missing")]
+    async fn missing() -> TaskStreamResponse {
+        TaskStreamResponse::NotFound
+    }
+
+    /// Tasks of a board
+    #[DTO(response)]
+    pub struct TaskList {
+        pub tasks: Vec<Task>,
+    }
+
+    impl NdJsonFormat for TaskList {
+        type Item = Task;
+
+        fn into_ndjson(self) -> axum::response::Response {
+            self.tasks.into_ndjson()
+        }
+    }
+
+    #[Response(format(json, ndjson), default_format = "json")]
+    pub enum TasksResponse {
+        /// Tasks of a board
+        #[Response()]
+        Ok(TaskList),
+    }
+
+    #[Route(method = "get", path = "/tasks")]
+    async fn tasks() -> TasksResponse {
+        TasksResponse::Ok(TaskList { tasks: vec![
+            Task { id: 2, title: "test".into() },
+            Task { id: 3, title: "more".into() },
+        ] })
+    }
+
+    /// Log lines
+    #[Response(format(ndjson))]
+    pub struct LogLines(Vec<String>);
+
+    #[Route(method = "get", path = "/log")]
+    async fn log() -> LogLines {
+        LogLines(vec!["started".into(), "stopped".into()])
+    }
+}
+
+/// Tests that stream items are sent one per line
+#[tokio::test]
+pub async fn test_stream() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/stream").accept("application/x-ndjson").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/x-ndjson")
+        .assert_body("{\"id\":1,\"title\":\"ship\"}\n{\"id\":2,\"title\":\"ship it\"}\n")
+    ;
+
+    Req::get("/missing").call(&r).await
+        .assert_status(404)
+        .assert_no_body()
+    ;
+}
+
+/// Tests negotiation between JSON and NDJSON
+#[tokio::test]
+pub async fn test_negotiation() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/json")
+        .assert_body("{\"tasks\":[{\"id\":2,\"title\":\"test\"},{\"id\":3,\"title\":\"more\"}]}")
+    ;
+
+    for accept in ["application/x-ndjson", "application/ndjson"] {
+        Req::get("/tasks").accept(accept).call(&r).await
+            .assert_status(200)
+            .assert_content_type("application/x-ndjson")
+            .assert_body("{\"id\":2,\"title\":\"test\"}\n{\"id\":3,\"title\":\"more\"}\n")
+        ;
+    }
+
+    Req::get("/tasks").accept("text/html").call(&r).await
+        .assert_status(406)
+    ;
+}
+
+/// Tests NDJSON tuple structs
+#[tokio::test]
+pub async fn test_struct() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/log").call(&r).await
+        .assert_status(200)
+        .assert_content_type("application/x-ndjson")
+        .assert_body("\"started\"\n\"stopped\"\n")
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+                "schemas": {
+                    "Task": {
+                        "description": "Task of a board",
+                        "properties": {
+                            "id": {
+                                "format": "int32",
+                                "minimum": 0,
+                                "type": "integer",
+                            },
+                            "title": {
+                                "type": "string",
+                            },
+                        },
+                        "required": [
+                            "id",
+                            "title",
+                        ],
+                        "type": "object",
+                    },
+                    "TaskList": {
+                        "description": "Tasks of a board",
+                        "properties": {
+                            "tasks": {
+                                "items": {
+                                    "$ref": "#/components/schemas/Task",
+                                },
+                                "type": "array",
+                            },
+                        },
+                        "required": [
+                            "tasks",
+                        ],
+                        "type": "object",
+                    },
+                },
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/log": {
+                    "get": {
+                        "operationId": "log",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/x-ndjson": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "Log lines",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/missing": {
+                    "get": {
+                        "operationId": "missing",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/x-ndjson": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Tasks of a board, one per line",
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/stream": {
+                    "get": {
+                        "operationId": "streamTasks",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/x-ndjson": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Tasks of a board, one per line",
+                            },
+                            "404": {
+                                "description": "No such board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/tasks": {
+                    "get": {
+                        "operationId": "tasks",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/TaskList",
+                                        },
+                                    },
+                                    "application/x-ndjson": {
+                                        "schema": {
+                                            "$ref": "#/components/schemas/Task",
+                                        },
+                                    },
+                                },
+                                "description": "Tasks of a board",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {
+                                            "type": "string",
+                                        },
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
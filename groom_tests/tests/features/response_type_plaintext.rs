@@ -13,7 +13,7 @@ mod controller {
     use groom_macros::Response;
 
     /// https://twitter.com/stahnma/status/634849376343429120
-    pub const SOME_TEXT: &'static str = 
+    pub const SOME_TEXT: &str = 
         "Everybody has a testing environment. \
          Some people are lucky enough enough to have a totally separate environment \
          to run production in";
@@ -14,9 +14,6 @@ mod controller {
         extract::GroomExtractor
     };
     use groom_macros::{DTO,Response};
-    use serde::Deserialize;
-    use utoipa::ToSchema;
-    use utoipa::PartialSchema;
 
     #[DTO(parameters)]
     pub struct Req {
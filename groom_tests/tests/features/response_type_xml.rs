@@ -0,0 +1,260 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller()]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::{
+        DTO,
+        Response
+    };
+
+
+    // ---
+
+    #[DTO(response)]
+    pub struct DataObject {
+        pub status: &'static str,
+        pub status_timestamp: Option<u64>,
+    }
+
+    // ---
+
+    #[Response(format(xml))]
+    pub enum XmlStructResponse {
+        #[Response()]
+        Ok(DataObject),
+
+        #[Response(code = 404)]
+        NotFound,
+    }
+
+    #[Route(method="get", path="/xml_struct")]
+    pub async fn xml_struct() -> XmlStructResponse {
+        XmlStructResponse::Ok(DataObject { status: "ok", status_timestamp: Some(1726070400) })
+    }
+
+    #[Route(method="get", path="/xml_struct/missing")]
+    pub async fn xml_struct_missing() -> XmlStructResponse {
+        XmlStructResponse::NotFound
+    }
+
+    // ---
+
+    /// Status in either format
+    #[Response(format(json, xml), default_format = "json")]
+    pub struct Status {
+        pub status: &'static str,
+    }
+
+    #[Route(method="get", path="/status")]
+    pub async fn status() -> Status {
+        Status { status: "ok" }
+    }
+
+    // ---
+}
+
+/// Tests that xml struct is returned correctly
+#[tokio::test]
+pub async fn xml_struct() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/xml_struct").call(&r).await
+        .assert_status(200)
+        .assert_body("<DataObject><status>ok</status><status_timestamp>1726070400</status_timestamp></DataObject>")
+        .assert_content_type("application/xml")
+    ;
+
+    Req::get("/xml_struct/missing").call(&r).await
+        .assert_status(404)
+        .assert_no_body()
+    ;
+}
+
+/// Tests that xml is negotiated for `application/xml`, `text/xml` and `+xml` suffixes
+#[tokio::test]
+pub async fn xml_negotiation() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/status").call(&r).await
+        .assert_status(200)
+        .assert_body("{\"status\":\"ok\"}")
+        .assert_content_type("application/json")
+    ;
+
+    Req::get("/status").accept("application/xml").call(&r).await
+        .assert_status(200)
+        .assert_body("<Status><status>ok</status></Status>")
+        .assert_content_type("application/xml")
+    ;
+
+    Req::get("/status").accept("text/xml").call(&r).await
+        .assert_status(200)
+        .assert_body("<Status><status>ok</status></Status>")
+        .assert_content_type("text/xml")
+    ;
+
+    Req::get("/status").accept("application/vnd.partner+xml").call(&r).await
+        .assert_status(200)
+        .assert_body("<Status><status>ok</status></Status>")
+        .assert_content_type("application/xml")
+    ;
+
+    Req::get("/status").accept("text/html").call(&r).await
+        .assert_status(406)
+        .assert_body("Supported content types: application/json, application/xml, text/xml")
+    ;
+}
+
+/// Tests that openapi definition is correctly generated
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "info": {
+                "contact": {"email": "mail@example.com","name": "name"},
+                "description": "d",
+                "license": {"name": "n"},
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "components": {
+                "schemas": {
+                    "Status": {
+                        "description": "Status in either format",
+                        "properties": {
+                            "status": {"type": "string"},
+                        },
+                        "required": ["status"],
+                        "type": "object",
+                    },
+                },
+            },
+            "paths": {
+                "/status": {
+                    "get": {
+                        "operationId": "status",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"$ref": "#/components/schemas/Status"},
+                                    },
+                                    "application/xml": {
+                                        "schema": {
+                                            "description": "Status in either format",
+                                            "properties": {
+                                                "status": {"type": "string"},
+                                            },
+                                            "required": ["status"],
+                                            "type": "object",
+                                            "xml": {"name": "Status"},
+                                        },
+                                    },
+                                },
+                                "description": "Status in either format",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {"type": "string"},
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/xml_struct": {
+                    "get": {
+                        "operationId": "xmlStruct",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/xml": {
+                                        "schema": {
+                                            "properties": {
+                                                "status": {
+                                                    "type": "string",
+                                                },
+                                                "status_timestamp": {
+                                                    "format": "int64",
+                                                    "minimum": 0,
+                                                    "type": ["integer", "null"],
+                                                },
+                                            },
+                                            "required": ["status"],
+                                            "type": "object",
+                                            "xml": {"name": "DataObject"},
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "404": {
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {"type": "string"},
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+                "/xml_struct/missing": {
+                    "get": {
+                        "operationId": "xmlStructMissing",
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/xml": {
+                                        "schema": {
+                                            "properties": {
+                                                "status": {
+                                                    "type": "string",
+                                                },
+                                                "status_timestamp": {
+                                                    "format": "int64",
+                                                    "minimum": 0,
+                                                    "type": ["integer", "null"],
+                                                },
+                                            },
+                                            "required": ["status"],
+                                            "type": "object",
+                                            "xml": {"name": "DataObject"},
+                                        },
+                                    },
+                                },
+                                "description": "",
+                            },
+                            "404": {
+                                "description": "",
+                            },
+                            "406": {
+                                "content": {
+                                    "text/plain; charset=utf-8": {
+                                        "schema": {"type": "string"},
+                                    },
+                                },
+                                "description": "The requested content type is not supported",
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+}
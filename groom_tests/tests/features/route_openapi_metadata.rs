@@ -0,0 +1,156 @@
+use serde_json::json;
+
+use crate::{
+    groom_macros::Controller,
+    features::test_utils::{Req, assert_openapi_doc}
+};
+
+#[Controller(tag = "tasks")]
+mod controller {
+    use axum::response::IntoResponse;
+
+    use groom::response::Response;
+    use groom_macros::Response;
+
+    #[Response()]
+    pub enum TaskResponse {
+        #[Response(code = 204)]
+        Done,
+    }
+
+    #[Route(
+        method = "get",
+        path = "/tasks",
+        operation_id = "listTasks",
+        tags("public"),
+        external_docs(url = "https://example.com/tasks", description = "Task lifecycle"),
+        security(("oauth" = ["read:tasks"]), ("api_key")),
+        extensions("x-rate-limit" = 100, "x-internal" = false),
+    )]
+    async fn get_all_tasks_v2() -> TaskResponse {
+        TaskResponse::Done
+    }
+
+    #[Route(method("put", "patch"), path = "/tasks", operation_id = "saveTask", deprecated)]
+    async fn update_task() -> TaskResponse {
+        TaskResponse::Done
+    }
+
+    #[Route(method = "get", path = "/health", security())]
+    async fn health() -> TaskResponse {
+        TaskResponse::Done
+    }
+}
+
+/// Tests that route metadata does not change request handling
+#[tokio::test]
+pub async fn test_handlers() {
+    let r = controller::into_router().validate().unwrap().to_axum_router();
+
+    Req::get("/tasks").call(&r).await
+        .assert_status(204)
+    ;
+
+    Req::patch("/tasks").call(&r).await
+        .assert_status(204)
+    ;
+}
+
+#[test]
+pub fn test_openapi() {
+    assert_openapi_doc(
+        |api| controller::into_router().validate().unwrap().to_openapi(api),
+        json!({
+            "components": {
+            },
+            "info": {
+                "contact": {
+                    "email": "mail@example.com",
+                    "name": "name",
+                },
+                "description": "d",
+                "license": {
+                    "name": "n",
+                },
+                "title": "t",
+                "version": "0.0.0",
+            },
+            "openapi": "3.1.0",
+            "paths": {
+                "/health": {
+                    "get": {
+                        "operationId": "health",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "security": [],
+                        "tags": [
+                            "tasks",
+                        ],
+                    },
+                },
+                "/tasks": {
+                    "get": {
+                        "externalDocs": {
+                            "description": "Task lifecycle",
+                            "url": "https://example.com/tasks",
+                        },
+                        "operationId": "listTasks",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "security": [
+                            {
+                                "oauth": [
+                                    "read:tasks",
+                                ],
+                            },
+                            {
+                                "api_key": [],
+                            },
+                        ],
+                        "tags": [
+                            "tasks",
+                            "public",
+                        ],
+                        "x-internal": false,
+                        "x-rate-limit": 100,
+                    },
+                    "patch": {
+                        "deprecated": true,
+                        "operationId": "saveTaskPatch",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "tags": [
+                            "tasks",
+                        ],
+                    },
+                    "put": {
+                        "deprecated": true,
+                        "operationId": "saveTaskPut",
+                        "responses": {
+                            "204": {
+                                "description": "",
+                            },
+                        },
+                        "tags": [
+                            "tasks",
+                        ],
+                    },
+                },
+            },
+            "tags": [
+                {
+                    "name": "tasks",
+                },
+            ],
+        })
+    );
+}
@@ -17,6 +17,7 @@ pub struct RequestResult {
     pub status:  StatusCode,
     pub headers: HeaderMap,
     pub body:    String,
+    pub bytes:   Vec<u8>,
 }
 
 impl RequestResult {
@@ -26,6 +27,13 @@ impl RequestResult {
         assert_eq!(self.body, expected.into());
         self
     }
+    /// Assert raw Body bytes in response (for binary formats)
+    #[allow(dead_code)]
+    pub fn assert_body_bytes<T: AsRef<[u8]>>(&self, expected: T) -> &Self {
+        assert_eq!(self.bytes, expected.as_ref());
+        self
+    }
+
     /// Assert Body value in response
     #[allow(dead_code)]
     pub fn assert_no_body(&self) -> &Self {
@@ -51,6 +59,17 @@ impl RequestResult {
         self
     }
 
+    /// Assert a header value in response
+    #[allow(dead_code)]
+    pub fn assert_header(&self, name: &str, expected: &str) -> &Self {
+        assert_eq!(
+            self.headers.get(name).unwrap_or_else(|| panic!("should respond with {name} header")),
+            expected
+        );
+
+        self
+    }
+
     /// Assert that Content-Type is missing from response
     #[allow(dead_code)]
     pub fn assert_no_content_type(&self) -> &Self {
@@ -94,6 +113,14 @@ impl Req {
         Self::new(http::Method::PATCH, url)
     }
 
+    pub fn head<T: Into<String>>(url: T) -> Self {
+        Self::new(http::Method::HEAD, url)
+    }
+
+    pub fn options<T: Into<String>>(url: T) -> Self {
+        Self::new(http::Method::OPTIONS, url)
+    }
+
     fn new<T: Into<String>>(method: http::Method, url: T) -> Self {
         Self { method, url: url.into(), accept: None, headers: None, body: None }
     }
@@ -116,8 +143,8 @@ impl Req {
     pub async fn call(self, r: &Router) -> RequestResult {
         let mut request = Request::builder().uri(self.url).method(self.method);
 
-        if self.accept.is_some() {
-            request = request.header("accept", self.accept.unwrap());
+        if let Some(accept) = self.accept {
+            request = request.header("accept", accept);
         }
 
         if let Some(headers) = self.headers {
@@ -127,8 +154,8 @@ impl Req {
         }
 
         let request = if let Some(b) = self.body {
-            if b.content_type.is_some() {
-                request = request.header("content-type", b.content_type.unwrap());
+            if let Some(content_type) = b.content_type {
+                request = request.header("content-type", content_type);
             }
 
             request.body(b.body).unwrap()
@@ -144,10 +171,10 @@ impl Req {
 
         let status = response.status();
         let headers = response.headers().to_owned();
-        let body = response.into_body().collect().await.unwrap().to_bytes();
-        let body = std::str::from_utf8(&body).unwrap().to_owned();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes().to_vec();
+        let body = String::from_utf8_lossy(&bytes).into_owned();
 
-        RequestResult{ status, headers, body }
+        RequestResult{ status, headers, body, bytes }
     }
 }
 
@@ -9,15 +9,13 @@ use crate::{
 mod controller {
     use axum::response::IntoResponse;
 
-    use groom::{
-        html_format,
-        response::Response,
-    };
+    use groom::response::Response;
     use groom_macros::{
         DTO,
         Response
     };
 
+    #[allow(clippy::enum_variant_names)]
     #[DTO(response)]
     pub enum EnumValueObject {
         UnitVariant,
@@ -30,6 +28,7 @@ mod controller {
     pub mod submod {
         use groom_macros::DTO;
 
+        #[allow(dead_code, clippy::enum_variant_names)]
         #[DTO(response)]
         pub enum EnumValueObject {
             UnitVariant,